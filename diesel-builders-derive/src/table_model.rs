@@ -19,9 +19,9 @@ use std::collections::HashMap;
 use accumulated_traits::generate_accumulated_traits;
 use attribute_parsing::{
     extract_discretionary_table, extract_field_default_value, extract_mandatory_table,
-    extract_primary_key_columns, extract_same_as_columns, extract_table_model_attributes,
-    extract_table_module, is_field_discretionary, is_field_infallible, is_field_mandatory,
-    validate_field_attributes,
+    extract_max_chars, extract_primary_key_columns, extract_range, extract_same_as_columns,
+    extract_table_model_attributes, extract_table_module, extract_timestamp,
+    is_field_discretionary, is_field_infallible, is_field_mandatory, validate_field_attributes,
 };
 use foreign_keys::{
     generate_explicit_foreign_key_impls, generate_foreign_key_impls,
@@ -32,8 +32,8 @@ use primary_key::generate_indexed_column_impls;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, Ident, spanned::Spanned};
-use table_generation::generate_table_macro;
-use typed_column::generate_typed_column_impls;
+use table_generation::{generate_column_enum, generate_table_macro};
+use typed_column::{generate_column_meta_impls, generate_typed_column_impls};
 use vertical_same_as::generate_vertical_same_as_impls;
 
 use crate::utils::{format_as_nested_tuple, is_option};
@@ -49,6 +49,14 @@ struct ProcessedFields {
     new_record_columns: Vec<syn::Path>,
     /// Records that are infallible (index, path).
     infallible_records: Vec<syn::Path>,
+    /// Records with a `max_chars` limit, alongside the limit.
+    max_chars_records: Vec<(syn::Path, syn::LitInt)>,
+    /// Records with a `range` of allowed values, alongside the range.
+    range_records: Vec<(syn::Path, syn::ExprRange)>,
+    /// Records marked as `timestamp(created)` or `timestamp(updated)`.
+    timestamp_records: Vec<syn::Path>,
+    /// Records marked as `timestamp(updated)`.
+    updated_timestamp_records: Vec<syn::Path>,
     /// Default values for fields.
     default_values: Vec<proc_macro2::TokenStream>,
     /// Warnings to be emitted.
@@ -65,6 +73,10 @@ fn process_fields(
 ) -> syn::Result<ProcessedFields> {
     let mut new_record_columns = Vec::new();
     let mut infallible_records = Vec::new();
+    let mut max_chars_records = Vec::new();
+    let mut range_records = Vec::new();
+    let mut timestamp_records = Vec::new();
+    let mut updated_timestamp_records = Vec::new();
     let mut default_values = Vec::new();
     let mut warnings = Vec::new();
 
@@ -97,6 +109,32 @@ fn process_fields(
             continue;
         }
 
+        let timestamp = extract_timestamp(field)?;
+        if let Some(kind) = &timestamp {
+            if extract_field_default_value(field).is_some() {
+                return Err(syn::Error::new_spanned(
+                    kind,
+                    "Timestamp columns cannot have a `default` value",
+                ));
+            }
+            if extract_max_chars(field)?.is_some() {
+                return Err(syn::Error::new_spanned(
+                    kind,
+                    "Timestamp columns cannot be limited by `max_chars`",
+                ));
+            }
+            if extract_range(field)?.is_some() {
+                return Err(syn::Error::new_spanned(
+                    kind,
+                    "Timestamp columns cannot be limited by a `range`",
+                ));
+            }
+            timestamp_records.push(syn::parse_quote!(#table_module::#field_name));
+            if kind == "updated" {
+                updated_timestamp_records.push(syn::parse_quote!(#table_module::#field_name));
+            }
+        }
+
         new_record_columns.push(syn::parse_quote!(#table_module::#field_name));
 
         if is_field_infallible(field) && attributes.error.is_none() {
@@ -137,16 +175,59 @@ fn process_fields(
             });
         }
 
-        if is_field_infallible(field) || attributes.error.is_none() {
+        if is_field_infallible(field) || timestamp.is_some() || attributes.error.is_none() {
             infallible_records.push(syn::parse_quote!(#table_module::#field_name));
         }
 
+        if let Some(max_chars) = extract_max_chars(field)? {
+            if attributes.error.is_none() {
+                return Err(syn::Error::new_spanned(
+                    max_chars,
+                    "`max_chars` requires the `TableModel` to specify an error type implementing \
+                     `From<diesel_builders::MaxCharsExceeded>`",
+                ));
+            }
+            if is_field_infallible(field) {
+                return Err(syn::Error::new_spanned(
+                    max_chars,
+                    "Field cannot be both `#[infallible]` and limited by `max_chars`",
+                ));
+            }
+            max_chars_records.push((syn::parse_quote!(#table_module::#field_name), max_chars));
+        }
+
+        if let Some(range) = extract_range(field)? {
+            if attributes.error.is_none() {
+                return Err(syn::Error::new_spanned(
+                    range,
+                    "`range` requires the `TableModel` to specify an error type implementing \
+                     `From<diesel_builders::OutOfRange>`",
+                ));
+            }
+            if is_field_infallible(field) {
+                return Err(syn::Error::new_spanned(
+                    range,
+                    "Field cannot be both `#[infallible]` and limited by a `range`",
+                ));
+            }
+            if extract_max_chars(field)?.is_some() {
+                return Err(syn::Error::new_spanned(
+                    range,
+                    "Field cannot be limited by both `max_chars` and a `range`",
+                ));
+            }
+            range_records.push((syn::parse_quote!(#table_module::#field_name), range));
+        }
+
         // Default value logic
         let user_default = extract_field_default_value(field);
         let is_nullable = is_option(&field.ty);
 
+        let field_type = &field.ty;
         let default_val = if let Some(def) = user_default {
             quote::quote! { Some((#def).to_owned().into()) }
+        } else if timestamp.is_some() {
+            quote::quote! { Some(<#field_type as ::diesel_builders::Timestamp>::now()) }
         } else if is_nullable {
             quote::quote! { Some(None) }
         } else {
@@ -155,7 +236,16 @@ fn process_fields(
         default_values.push(default_val);
     }
 
-    Ok(ProcessedFields { new_record_columns, infallible_records, default_values, warnings })
+    Ok(ProcessedFields {
+        new_record_columns,
+        infallible_records,
+        max_chars_records,
+        range_records,
+        timestamp_records,
+        updated_timestamp_records,
+        default_values,
+        warnings,
+    })
 }
 
 /// Collect mandatory and discretionary triangular relation columns.
@@ -282,8 +372,9 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
 
     // Parse attributes
     let table_module_opt = extract_table_module(input);
-    let primary_key_columns = extract_primary_key_columns(input);
     let attributes = extract_table_model_attributes(input)?;
+    let primary_key_columns =
+        extract_primary_key_columns(input, attributes.primary_key.as_deref())?;
 
     let table_module = if let Some(module) = table_module_opt {
         module
@@ -311,6 +402,34 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
                 return Err(syn::Error::new_spanned(ancestor, "Duplicate `ancestor` in hierarchy"));
             }
         }
+
+        // Ancestors are listed from the root, so the last one is a direct
+        // parent: when the foreign keys of the primary key are declared, the
+        // last ancestor must be one of the tables they reference. Otherwise,
+        // the order is checked against the hierarchies of the ancestors by
+        // the generated `Descendant` implementation.
+        if let [primary_key] = primary_key_columns.as_slice()
+            && let Some(last_ancestor) = ancestors.last()
+        {
+            let parents: Vec<String> = attributes
+                .foreign_keys
+                .iter()
+                .filter(|fk| fk.host_columns.len() == 1 && fk.host_columns[0] == *primary_key)
+                .filter_map(|fk| fk.referenced_columns.first())
+                .filter_map(crate::utils::extract_table_path_from_column)
+                .map(|table| tokens_to_string(&table))
+                .collect();
+
+            if !parents.is_empty() && !parents.contains(&tokens_to_string(last_ancestor)) {
+                return Err(syn::Error::new_spanned(
+                    last_ancestor,
+                    format!(
+                        "The last `ancestor` must be the parent table `{}` referenced by the primary key `{primary_key}`, as ancestors are listed from the root",
+                        parents.join("` or `")
+                    ),
+                ));
+            }
+        }
     }
 
     if attributes.surrogate_key && primary_key_columns.len() > 1 {
@@ -366,8 +485,22 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
 
     // Generate all components
     let table_macro = generate_table_macro(input, &table_module, &primary_key_columns)?;
+    let column_enum =
+        attributes.column_enum.then(|| generate_column_enum(input, fields, &table_module));
     let typed_column_impls =
         generate_typed_column_impls(fields, &table_module, struct_ident, &primary_key_columns);
+    let defaulted_columns: Vec<Ident> = attributes
+        .struct_defaults
+        .iter()
+        .filter_map(|(col_path, _)| {
+            let mut segments = col_path.segments.iter().rev();
+            let column = segments.next()?;
+            (segments.next()?.ident == table_module).then(|| column.ident.clone())
+        })
+        .chain(primary_key_columns.iter().filter(|_| attributes.surrogate_key).cloned())
+        .collect();
+    let column_meta_impls =
+        generate_column_meta_impls(fields, &table_module, &primary_key_columns, &defaulted_columns);
     let get_column_impls = generate_get_column_impls(fields, &table_module, struct_ident);
     let accumulated_traits_impls = generate_accumulated_traits(
         fields,
@@ -382,8 +515,16 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
         primary_key_columns.iter().map(|col| quote::quote! { #table_module::#col }),
     );
 
-    let ProcessedFields { new_record_columns, infallible_records, default_values, warnings } =
-        process_fields(fields, &table_module, &primary_key_columns, &attributes)?;
+    let ProcessedFields {
+        new_record_columns,
+        infallible_records,
+        max_chars_records,
+        range_records,
+        timestamp_records,
+        updated_timestamp_records,
+        default_values,
+        warnings,
+    } = process_fields(fields, &table_module, &primary_key_columns, &attributes)?;
 
     // Collect triangular relation columns for BundlableTable implementation
     let (mandatory_columns, discretionary_columns) =
@@ -494,6 +635,53 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
         ));
     let may_get_column_impls =
         may_get_columns::generate_may_get_column_impls(&new_record_columns, &table_module);
+    let maybe_set_managed_values = (!timestamp_records.is_empty()).then(|| {
+        quote::quote! {
+            fn set_managed_values(new_values: &mut Self::NewValues) {
+                #(
+                    <Self::NewValues as ::diesel_builders::SetColumn<#timestamp_records>>::set_column(
+                        new_values,
+                        <<#timestamp_records as ::diesel_builders::ColumnTyped>::ColumnType as ::diesel_builders::Timestamp>::now(),
+                    );
+                )*
+            }
+        }
+    });
+
+    let nested_updated_columns = format_as_nested_tuple(&updated_timestamp_records);
+    let managed_changeset = if updated_timestamp_records.is_empty() {
+        quote::quote! {
+            type ManagedChangeset<C> = C;
+
+            fn managed_changeset<C>(changeset: C) -> Self::ManagedChangeset<C> {
+                changeset
+            }
+        }
+    } else {
+        quote::quote! {
+            type ManagedChangeset<C> = (
+                C,
+                #(
+                    ::diesel::dsl::Eq<
+                        #updated_timestamp_records,
+                        <#updated_timestamp_records as ::diesel_builders::ColumnTyped>::ColumnType,
+                    >,
+                )*
+            );
+
+            fn managed_changeset<C>(changeset: C) -> Self::ManagedChangeset<C> {
+                (
+                    changeset,
+                    #(
+                        ::diesel::ExpressionMethods::eq(
+                            #updated_timestamp_records,
+                            <<#updated_timestamp_records as ::diesel_builders::ColumnTyped>::ColumnType as ::diesel_builders::Timestamp>::now(),
+                        ),
+                    )*
+                )
+            }
+        }
+    };
 
     let infallible_validate_column_impls =
         set_columns::generate_infallible_validate_column_impls(&infallible_records, &table_module);
@@ -507,6 +695,17 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
         .map(|t| quote::quote! { #t })
         .unwrap_or(quote::quote! { std::convert::Infallible });
 
+    let max_chars_validate_column_impls = set_columns::generate_max_chars_validate_column_impls(
+        &max_chars_records,
+        &table_module,
+        &error_type,
+    );
+    let range_validate_column_impls = set_columns::generate_range_validate_column_impls(
+        &range_records,
+        &table_module,
+        &error_type,
+    );
+
     // Generate Root/Descendant implementations
     // If ancestors are specified, generate Descendant; otherwise generate Root
     let descendant_impls = if let Some(ref ancestors) = attributes.ancestors {
@@ -517,12 +716,32 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
         let root: &syn::Type = ancestor_tables.first().unwrap();
         let aux_impls =
             crate::descendant::generate_auxiliary_descendant_impls(&table_type, &ancestor_tables);
+        // Each ancestor must be listed after all of its own ancestors, so the
+        // ancestor at position `i` has at most `i` ancestors. For a single
+        // inheritance chain, this only admits the order from the root.
+        let ancestors_order_message = syn::LitStr::new(
+            &format!(
+                "The `ancestors` of `{table_module}` must be listed from the root, each table after its own ancestors"
+            ),
+            table_module.span(),
+        );
 
         quote! {
             impl ::diesel_builders::Descendant for #table_type {
                 type Ancestors = (#(#ancestor_tables,)*);
                 type Root = #root;
             }
+
+            const _: () = {
+                let ancestor_depths =
+                    [#(<#ancestor_tables as ::diesel_builders::Descendant>::HIERARCHY_DEPTH),*];
+                let mut i = 0;
+                while i < ancestor_depths.len() {
+                    assert!(ancestor_depths[i] <= i + 1, #ancestors_order_message);
+                    i += 1;
+                }
+            };
+
             #aux_impls
         }
     } else {
@@ -910,12 +1129,16 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
         #(#warnings)*
         #table_macro
         #typed_column_impls
+        #column_meta_impls
+        #column_enum
         #get_column_impls
         #accumulated_traits_impls
         #(#indexed_column_impls)*
         #may_get_column_impls
         #set_column_impls
         #infallible_validate_column_impls
+        #max_chars_validate_column_impls
+        #range_validate_column_impls
         #descendant_impls
         #bundlable_table_impl
         #buildable_table_impl
@@ -948,10 +1171,15 @@ pub fn derive_table_model_impl(input: &DeriveInput) -> syn::Result<TokenStream>
             type Model = #struct_ident;
             type NestedPrimaryKeyColumns = #nested_primary_keys;
             type Error = #error_type;
+            type NestedUpdatedColumns = #nested_updated_columns;
 
             fn default_new_values() -> Self::NewValues {
                 #default_new_record
             }
+
+            #maybe_set_managed_values
+
+            #managed_changeset
         }
     })
 }
@@ -14,6 +14,10 @@ pub struct TableModelAttributes {
     pub struct_defaults: Vec<(syn::Path, syn::Expr)>,
     /// Foreign keys defined on the table.
     pub foreign_keys: Vec<ForeignKeyAttribute>,
+    /// Primary key columns from `#[table_model(primary_key(...))]`.
+    pub primary_key: Option<Vec<Ident>>,
+    /// Whether to generate an enum of the columns of the table.
+    pub column_enum: bool,
 }
 
 /// Definition of a foreign key.
@@ -47,38 +51,52 @@ pub fn extract_table_module(input: &DeriveInput) -> Option<syn::Ident> {
     })
 }
 
-/// Extract primary key columns from `#[diesel(primary_key = ...)]` attribute.
-/// Defaults to "id" if not specified.
-pub fn extract_primary_key_columns(input: &DeriveInput) -> Vec<Ident> {
-    input
-        .attrs
-        .iter()
-        .find_map(|attr| {
-            if !attr.path().is_ident("diesel") {
-                return None;
+/// Extract primary key columns from `#[diesel(primary_key(...))]` or its
+/// `#[table_model(primary_key(...))]` alias, which must agree when both are
+/// present. Defaults to "id" if neither is specified.
+pub fn extract_primary_key_columns(
+    input: &DeriveInput,
+    table_model_primary_key: Option<&[Ident]>,
+) -> syn::Result<Vec<Ident>> {
+    let diesel_primary_key = input.attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("diesel") {
+            return None;
+        }
+
+        let mut pk_columns = Vec::new();
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("primary_key") {
+                // Parse primary_key(col1, col2, ...)
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let punct: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]> =
+                    syn::punctuated::Punctuated::parse_terminated(&content)?;
+                pk_columns.extend(punct);
+                Ok(())
+            } else {
+                Ok(())
             }
+        });
 
-            let mut pk_columns = Vec::new();
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("primary_key") {
-                    // Parse primary_key(col1, col2, ...)
-                    let content;
-                    syn::parenthesized!(content in meta.input);
-                    let punct: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]> =
-                        syn::punctuated::Punctuated::parse_terminated(&content)?;
-                    pk_columns.extend(punct);
-                    Ok(())
-                } else {
-                    Ok(())
-                }
-            });
+        if pk_columns.is_empty() { None } else { Some(pk_columns) }
+    });
 
-            if pk_columns.is_empty() { None } else { Some(pk_columns) }
-        })
-        .unwrap_or_else(|| {
-            // Default: if no primary_key attribute, assume "id" is the primary key
-            vec![syn::Ident::new("id", proc_macro2::Span::call_site())]
-        })
+    match (diesel_primary_key, table_model_primary_key) {
+        (Some(diesel_columns), Some(table_model_columns)) => {
+            if diesel_columns != table_model_columns {
+                return Err(syn::Error::new(
+                    table_model_columns[0].span(),
+                    "`#[table_model(primary_key(...))]` disagrees with \
+                     `#[diesel(primary_key(...))]`",
+                ));
+            }
+            Ok(diesel_columns)
+        }
+        (Some(columns), None) => Ok(columns),
+        (None, Some(columns)) => Ok(columns.to_vec()),
+        // Default: if no primary_key attribute, assume "id" is the primary key
+        (None, None) => Ok(vec![syn::Ident::new("id", proc_macro2::Span::call_site())]),
+    }
 }
 
 /// Extract attributes from `#[table_model(...)]`.
@@ -90,12 +108,17 @@ pub fn extract_primary_key_columns(input: &DeriveInput) -> Vec<Ident> {
 ///   `std::convert::Infallible` if not present.
 /// - `surrogate_key`: Marks the primary key as a surrogate key (generated by
 ///   DB), excluding it from `NewRecord`.
+/// - `primary_key(col1, col2, ...)`: Alias for `#[diesel(primary_key(...))]`.
+/// - `column_enum`: Generates a `#[non_exhaustive]` `{StructName}Column` enum
+///   with a variant per column.
 pub fn extract_table_model_attributes(input: &DeriveInput) -> syn::Result<TableModelAttributes> {
     let mut error = None;
     let mut surrogate_key = false;
     let mut ancestors = None;
     let mut struct_defaults = Vec::new();
     let mut foreign_keys = Vec::new();
+    let mut primary_key = None;
+    let mut column_enum = false;
     let mut parse_errors: Option<syn::Error> = None;
 
     for attr in &input.attrs {
@@ -110,6 +133,17 @@ pub fn extract_table_model_attributes(input: &DeriveInput) -> syn::Result<TableM
                 error = Some(ty);
             } else if meta.path.is_ident("surrogate_key") {
                 surrogate_key = true;
+            } else if meta.path.is_ident("column_enum") {
+                column_enum = true;
+            } else if meta.path.is_ident("primary_key") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let punct: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]> =
+                    syn::punctuated::Punctuated::parse_terminated(&content)?;
+                if punct.is_empty() {
+                    return Err(meta.error("Expected at least one primary key column"));
+                }
+                primary_key = Some(punct.into_iter().collect());
             } else if meta.path.is_ident("ancestors") {
                 if meta.input.peek(syn::token::Paren) {
                     let content;
@@ -177,7 +211,15 @@ pub fn extract_table_model_attributes(input: &DeriveInput) -> syn::Result<TableM
         return Err(e);
     }
 
-    Ok(TableModelAttributes { error, surrogate_key, ancestors, struct_defaults, foreign_keys })
+    Ok(TableModelAttributes {
+        error,
+        surrogate_key,
+        ancestors,
+        struct_defaults,
+        foreign_keys,
+        primary_key,
+        column_enum,
+    })
 }
 
 /// Check if a field is marked as infallible via `#[table_model(infallible)]` or
@@ -198,6 +240,17 @@ pub fn is_field_infallible(field: &syn::Field) -> bool {
     })
 }
 
+/// Check if a field is marked as stored as JSON via `#[table_model(json)]`.
+pub fn is_field_json(field: &syn::Field) -> bool {
+    count_nested_attribute(field, "json") > 0
+}
+
+/// Check if a field is marked as an auto-managed timestamp via
+/// `#[table_model(timestamp(...))]`.
+pub fn is_field_timestamp(field: &syn::Field) -> bool {
+    count_nested_attribute(field, "timestamp") > 0
+}
+
 /// Check if a field is marked as mandatory via `#[mandatory(...)]`.
 pub fn is_field_mandatory(field: &syn::Field) -> bool {
     field.attrs.iter().any(|attr| attr.path().is_ident("mandatory"))
@@ -297,6 +350,83 @@ pub fn extract_sql_name(field: &syn::Field) -> Option<String> {
     sql_name
 }
 
+/// Extract the value of the `#[table_model(name ...)]` option named `name` on
+/// a field, parsed by `parser`, skipping the other options.
+fn extract_field_option<T>(
+    field: &syn::Field,
+    name: &str,
+    mut parser: impl FnMut(&syn::meta::ParseNestedMeta<'_>) -> syn::Result<T>,
+) -> syn::Result<Option<T>> {
+    let mut value = None;
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("table_model") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                if value.is_some() {
+                    return Err(meta
+                        .error(format!("Multiple `{name}` options specified for the same field")));
+                }
+                value = Some(parser(&meta)?);
+            } else if meta.input.peek(syn::Token![=]) {
+                // Skip the values of the other options.
+                let _: syn::Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                // Skip the arguments of the other options.
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(value)
+}
+
+/// Extract the maximum number of characters from
+/// `#[table_model(max_chars = ...)]` attribute on a field.
+pub fn extract_max_chars(field: &syn::Field) -> syn::Result<Option<syn::LitInt>> {
+    extract_field_option(field, "max_chars", |meta| {
+        let lit: syn::LitInt = meta.value()?.parse()?;
+        lit.base10_parse::<usize>()?;
+        Ok(lit)
+    })
+}
+
+/// Extract the kind of timestamp, either `created` or `updated`, from
+/// `#[table_model(timestamp(...))]` attribute on a field.
+pub fn extract_timestamp(field: &syn::Field) -> syn::Result<Option<syn::Ident>> {
+    extract_field_option(field, "timestamp", |meta| {
+        let mut timestamp = None;
+        meta.parse_nested_meta(|kind| {
+            if kind.path.is_ident("created") || kind.path.is_ident("updated") {
+                timestamp = kind.path.get_ident().cloned();
+                Ok(())
+            } else {
+                Err(kind.error("Expected `timestamp(created)` or `timestamp(updated)`"))
+            }
+        })?;
+        timestamp.ok_or_else(|| meta.error("Expected `timestamp(created)` or `timestamp(updated)`"))
+    })
+}
+
+/// Extract the range of the allowed values from
+/// `#[table_model(range(...))]` attribute on a field, such as `0..=120` or
+/// `18..`.
+pub fn extract_range(field: &syn::Field) -> syn::Result<Option<syn::ExprRange>> {
+    extract_field_option(field, "range", |meta| {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let expr: syn::ExprRange = content.parse()?;
+        if expr.start.is_none() && expr.end.is_none() {
+            return Err(syn::Error::new_spanned(expr, "Expected a range with at least one bound"));
+        }
+        Ok(expr)
+    })
+}
+
 /// Count occurrences of a specific attribute on a field.
 fn count_attribute(field: &syn::Field, attr_name: &str) -> usize {
     field.attrs.iter().filter(|attr| attr.path().is_ident(attr_name)).count()
@@ -378,6 +508,38 @@ pub fn validate_field_attributes(field: &syn::Field) -> syn::Result<()> {
         ));
     }
 
+    // Check for multiple character limits
+    if count_nested_attribute(field, "max_chars") > 1 {
+        return Err(syn::Error::new_spanned(
+            field,
+            "Multiple `max_chars` limits specified for the same field",
+        ));
+    }
+
+    // Check for multiple ranges
+    if count_nested_attribute(field, "range") > 1 {
+        return Err(syn::Error::new_spanned(
+            field,
+            "Multiple `range` bounds specified for the same field",
+        ));
+    }
+
+    // Check for multiple timestamp markers
+    if count_nested_attribute(field, "timestamp") > 1 {
+        return Err(syn::Error::new_spanned(
+            field,
+            "Multiple `timestamp` markers specified for the same field",
+        ));
+    }
+
+    // Check for duplicate json markers
+    if count_nested_attribute(field, "json") > 1 {
+        return Err(syn::Error::new_spanned(
+            field,
+            "Duplicate `#[table_model(json)]` attribute found",
+        ));
+    }
+
     // Check for unsupported diesel attributes
     for attr in &field.attrs {
         if attr.path().is_ident("diesel") {
@@ -37,3 +37,47 @@ pub(super) fn generate_infallible_validate_column_impls(
         }
     }).collect()
 }
+
+/// Generate `ValidateColumn` implementations checking the `max_chars` limit of
+/// text records.
+pub(super) fn generate_max_chars_validate_column_impls(
+    max_chars_records: &[(syn::Path, syn::LitInt)],
+    table_module: &syn::Ident,
+    error_type: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    max_chars_records.iter().map(|(record, max_chars)| {
+        quote::quote! {
+            impl ::diesel_builders::ValidateColumn<#record> for <#table_module::table as ::diesel_builders::TableExt>::NewValues {
+                type Error = #error_type;
+
+                #[inline]
+                fn validate_column(value: &<#record as ::diesel_builders::ValueTyped>::ValueType) -> Result<(), Self::Error> {
+                    ::diesel_builders::validate_max_chars::<#record, _>(value, #max_chars)?;
+                    Ok(())
+                }
+            }
+        }
+    }).collect()
+}
+
+/// Generate `ValidateColumn` implementations checking that the values of
+/// records lie within their `range`.
+pub(super) fn generate_range_validate_column_impls(
+    range_records: &[(syn::Path, syn::ExprRange)],
+    table_module: &syn::Ident,
+    error_type: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    range_records.iter().map(|(record, range)| {
+        quote::quote! {
+            impl ::diesel_builders::ValidateColumn<#record> for <#table_module::table as ::diesel_builders::TableExt>::NewValues {
+                type Error = #error_type;
+
+                #[inline]
+                fn validate_column(value: &<#record as ::diesel_builders::ValueTyped>::ValueType) -> Result<(), Self::Error> {
+                    ::diesel_builders::validate_range::<#record, _>(value, #range)?;
+                    Ok(())
+                }
+            }
+        }
+    }).collect()
+}
@@ -5,7 +5,10 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, Field, Ident, Type};
 
-use crate::{table_model::attribute_parsing::extract_sql_name, utils::is_option};
+use crate::{
+    table_model::attribute_parsing::{extract_sql_name, is_field_json},
+    utils::{is_option, snake_to_camel_case},
+};
 
 /// Extracts the first generic type argument from a type path, if it exists.
 fn extract_first_generic_arg(ty: &Type) -> Option<&Type> {
@@ -77,7 +80,7 @@ fn infer_sql_type(ty: &Type) -> Option<TokenStream> {
 }
 
 /// Extracts the SQL type from the `#[diesel(sql_type = ...)]` attribute or
-/// infers it.
+/// infers it, defaulting to `Text` for `#[table_model(json)]` fields.
 fn get_column_sql_type(field: &Field) -> syn::Result<TokenStream> {
     let mut found_sql_type = None;
 
@@ -108,6 +111,14 @@ fn get_column_sql_type(field: &Field) -> syn::Result<TokenStream> {
         return Ok(sql_type);
     }
 
+    // JSON columns are stored as text unless an explicit SQL type is provided
+    if is_field_json(field) {
+        if is_option(&field.ty) {
+            return Ok(quote! { ::diesel::sql_types::Nullable<::diesel::sql_types::Text> });
+        }
+        return Ok(quote! { ::diesel::sql_types::Text });
+    }
+
     // Try to infer
     if let Some(sql_type) = infer_sql_type(&field.ty) {
         Ok(sql_type)
@@ -179,3 +190,69 @@ pub fn generate_table_macro(
         }
     })
 }
+
+/// Generates the `{StructName}Column` enum, with a variant per column of the
+/// table, enabled by `#[table_model(column_enum)]`.
+///
+/// The enum is `#[non_exhaustive]`, so that matches over it in downstream
+/// crates keep compiling when a column is added to the table.
+pub fn generate_column_enum(
+    input: &DeriveInput,
+    fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>,
+    table_module: &Ident,
+) -> TokenStream {
+    let visibility = &input.vis;
+    let enum_ident = quote::format_ident!("{}Column", input.ident);
+    let enum_doc = format!("The columns of the `{table_module}` table.");
+
+    let (variants, columns): (Vec<Ident>, Vec<&Ident>) = fields
+        .iter()
+        .filter_map(|field| {
+            let field_name = field.ident.as_ref()?;
+            let variant =
+                Ident::new(&snake_to_camel_case(&field_name.to_string()), field_name.span());
+            Some((variant, field_name))
+        })
+        .unzip();
+    let variant_docs = columns.iter().map(|column| format!("The `{column}` column."));
+
+    quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[non_exhaustive]
+        #visibility enum #enum_ident {
+            #(
+                #[doc = #variant_docs]
+                #variants,
+            )*
+        }
+
+        impl #enum_ident {
+            /// Every column of the table, in declaration order.
+            pub const ALL: &'static [Self] = &[#(Self::#variants),*];
+
+            /// Returns the name of the column.
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                self.meta().name
+            }
+
+            /// Returns the schema facts about the column.
+            #[must_use]
+            pub const fn meta(self) -> ::diesel_builders::ColumnMeta {
+                match self {
+                    #(
+                        Self::#variants => <#table_module::#columns as ::diesel_builders::HasColumnMeta>::META,
+                    )*
+                }
+            }
+        }
+
+        impl From<#enum_ident> for ::diesel_builders::ColumnMeta {
+            #[inline]
+            fn from(column: #enum_ident) -> Self {
+                column.meta()
+            }
+        }
+    }
+}
@@ -32,6 +32,46 @@ pub fn generate_typed_column_impls(
         .collect()
 }
 
+/// Generate `HasColumnMeta` implementations for all fields.
+///
+/// The `defaulted_columns` are the columns which receive a value when left
+/// unset, beside those carrying a field-level `default` attribute.
+pub fn generate_column_meta_impls(
+    fields: &Punctuated<Field, Token![,]>,
+    table_module: &syn::Ident,
+    primary_key_columns: &[Ident],
+    defaulted_columns: &[Ident],
+) -> TokenStream {
+    use crate::{
+        table_model::attribute_parsing::{extract_field_default_value, is_field_timestamp},
+        utils::is_option,
+    };
+
+    fields
+        .iter()
+        .filter_map(|field| {
+            let field_name = field.ident.as_ref()?;
+            let is_nullable = is_option(&field.ty);
+            let is_primary_key = primary_key_columns.contains(field_name);
+            let has_default = extract_field_default_value(field).is_some()
+                || is_field_timestamp(field)
+                || defaulted_columns.contains(field_name);
+
+            Some(quote! {
+                impl ::diesel_builders::HasColumnMeta for #table_module::#field_name {
+                    const META: ::diesel_builders::ColumnMeta = ::diesel_builders::ColumnMeta {
+                        name: <Self as ::diesel::Column>::NAME,
+                        table: <#table_module::table as ::diesel_builders::TableExt>::TABLE_NAME,
+                        is_nullable: #is_nullable,
+                        is_primary_key: #is_primary_key,
+                        has_default: #has_default,
+                    };
+                }
+            })
+        })
+        .collect()
+}
+
 /// Generate all trait implementations for a single field.
 fn generate_field_traits(
     field: &Field,
@@ -41,7 +81,9 @@ fn generate_field_traits(
     struct_ident: &Ident,
     primary_key_columns: &[Ident],
 ) -> TokenStream {
-    use crate::table_model::attribute_parsing::{is_field_discretionary, is_field_mandatory};
+    use crate::table_model::attribute_parsing::{
+        is_field_discretionary, is_field_json, is_field_mandatory, is_field_timestamp,
+    };
 
     let field_name_str = field_name.to_string();
     let clean_field_name = field_name_str.trim_start_matches("r#");
@@ -84,22 +126,33 @@ fn generate_field_traits(
             None
         };
 
-    let set_trait = generate_set_trait(
-        field_name,
-        &method_name_str,
-        &method_name_ident,
-        table_module,
-        struct_ident,
-        &camel_cased_field_name,
-    );
-    let try_set_trait = generate_try_set_trait(
-        field_name,
-        &method_name_str,
-        table_module,
-        struct_ident,
-        &camel_cased_field_name,
-    );
+    // Timestamp columns are set by the table itself, so no setters are exposed.
+    let is_timestamp = is_field_timestamp(field);
+    let set_trait = (!is_timestamp).then(|| {
+        generate_set_trait(
+            field_name,
+            &method_name_str,
+            &method_name_ident,
+            table_module,
+            struct_ident,
+            &camel_cased_field_name,
+        )
+    });
+    let try_set_trait = (!is_timestamp).then(|| {
+        generate_try_set_trait(
+            field_name,
+            &method_name_str,
+            table_module,
+            struct_ident,
+            &camel_cased_field_name,
+        )
+    });
     let typed_impl = generate_typed_impl(field_name, field_type, table_module);
+    let maybe_json_impl = is_field_json(field).then(|| {
+        quote! {
+            impl ::diesel_builders::JsonColumn for #table_module::#field_name {}
+        }
+    });
 
     quote! {
         #maybe_getter_impl
@@ -107,6 +160,7 @@ fn generate_field_traits(
         #set_trait
         #try_set_trait
         #typed_impl
+        #maybe_json_impl
     }
 }
 
@@ -1,17 +1,17 @@
 //! Submodule with utilities for the diesel-builders macros.
 
-use std::{
-    collections::HashSet,
-    hash::{Hash, Hasher},
-    sync::{Mutex, OnceLock},
-};
+use std::sync::{Mutex, OnceLock};
 
 use quote::ToTokens;
 
 /// Static lookup struct to track which table pairs have already had
 /// `diesel::allow_tables_to_appear_in_same_query!` generated.
 /// This prevents duplicate macro invocations which would cause compile errors.
-static GENERATED_LINKS: OnceLock<Mutex<HashSet<u64>>> = OnceLock::new();
+static GENERATED_LINKS: OnceLock<Mutex<Vec<(TablePath, TablePath)>>> = OnceLock::new();
+
+/// The segments of a path to a table module, as normalized by
+/// [`normalized_table_path`].
+type TablePath = Vec<String>;
 
 /// Convert a `snake_case` string to `CamelCase`.
 ///
@@ -81,6 +81,31 @@ pub(crate) fn camel_to_snake_case(s: &str) -> String {
     result
 }
 
+/// Returns the segments of the path to the table module a path refers to.
+///
+/// A trailing `table` segment, naming the table struct rather than its module,
+/// and a leading `crate` or `self` segment are dropped, so that
+/// `crate::schema::animals::table` is normalized to `schema::animals`.
+fn normalized_table_path(path: &syn::Path) -> Option<TablePath> {
+    let mut segments: TablePath = path.segments.iter().map(|seg| seg.ident.to_string()).collect();
+    if segments.last().is_some_and(|last| last == "table") {
+        segments.pop();
+    }
+    if segments.first().is_some_and(|first| first == "crate" || first == "self") {
+        segments.remove(0);
+    }
+    (!segments.is_empty()).then_some(segments)
+}
+
+/// Returns whether two normalized paths refer to the same table module.
+///
+/// The same table may be referenced as `animals` or `schema::animals`, so
+/// paths of different lengths match when the shorter one is a suffix of the
+/// longer one, while `a::users` and `b::users` remain distinct tables.
+fn same_table(left: &[String], right: &[String]) -> bool {
+    if left.len() <= right.len() { right.ends_with(left) } else { left.ends_with(right) }
+}
+
 /// Helper to determine if we should generate
 /// `allow_tables_to_appear_in_same_query`.
 ///
@@ -91,31 +116,30 @@ pub(crate) fn should_generate_allow_tables_to_appear_in_same_query(
     t2: &syn::Path,
 ) -> bool {
     // Initialize the static map if needed
-    let map = GENERATED_LINKS.get_or_init(|| Mutex::new(HashSet::new()));
+    let map = GENERATED_LINKS.get_or_init(|| Mutex::new(Vec::new()));
 
-    let Some(s1) = t1.segments.last().map(|seg| &seg.ident) else {
+    let Some(p1) = normalized_table_path(t1) else {
         return false;
     };
-    let Some(s2) = t2.segments.last().map(|seg| &seg.ident) else {
+    let Some(p2) = normalized_table_path(t2) else {
         return false;
     };
 
     // Same table, no need to generate
-    if s1 == s2 {
+    if same_table(&p1, &p2) {
         return false;
     }
 
-    // Sort to handle symmetry (A, B) == (B, A)
-    let pair = if s1 < s2 { (s1, s2) } else { (s2, s1) };
-
-    let hash = {
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        pair.hash(&mut hasher);
-        hasher.finish()
-    };
-
     let mut lock = map.lock().unwrap();
-    lock.insert(hash)
+    // Check both orders to handle symmetry (A, B) == (B, A)
+    let already_generated = lock.iter().any(|(a, b)| {
+        (same_table(a, &p1) && same_table(b, &p2)) || (same_table(a, &p2) && same_table(b, &p1))
+    });
+    if already_generated {
+        return false;
+    }
+    lock.push((p1, p2));
+    true
 }
 
 /// Extracts the table path from a column path.
@@ -130,3 +154,64 @@ pub(crate) fn extract_table_path_from_column(path: &syn::Path) -> Option<syn::Pa
     table_path.segments.pop_punct();
     Some(table_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::should_generate_allow_tables_to_appear_in_same_query as should_generate;
+
+    #[test]
+    fn test_single_and_multi_segment_paths_of_the_same_table() {
+        assert!(!should_generate(
+            &syn::parse_quote!(utils_test_animals),
+            &syn::parse_quote!(crate::schema::utils_test_animals)
+        ));
+        assert!(!should_generate(
+            &syn::parse_quote!(crate::schema::utils_test_animals::table),
+            &syn::parse_quote!(utils_test_animals)
+        ));
+    }
+
+    #[test]
+    fn test_pairs_are_generated_once_regardless_of_path_form() {
+        assert!(should_generate(
+            &syn::parse_quote!(crate::schema::utils_test_dogs),
+            &syn::parse_quote!(utils_test_pets)
+        ));
+        assert!(!should_generate(
+            &syn::parse_quote!(utils_test_dogs),
+            &syn::parse_quote!(crate::schema::utils_test_pets)
+        ));
+        assert!(!should_generate(
+            &syn::parse_quote!(utils_test_pets::table),
+            &syn::parse_quote!(utils_test_dogs)
+        ));
+    }
+
+    #[test]
+    fn test_same_module_name_in_different_schemas() {
+        assert!(should_generate(
+            &syn::parse_quote!(a::utils_test_users),
+            &syn::parse_quote!(b::utils_test_users)
+        ));
+        assert!(should_generate(
+            &syn::parse_quote!(a::utils_test_users),
+            &syn::parse_quote!(utils_test_posts)
+        ));
+        assert!(should_generate(
+            &syn::parse_quote!(b::utils_test_users::table),
+            &syn::parse_quote!(utils_test_posts)
+        ));
+        assert!(!should_generate(
+            &syn::parse_quote!(crate::a::utils_test_users),
+            &syn::parse_quote!(utils_test_posts::table)
+        ));
+    }
+
+    #[test]
+    fn test_paths_to_table_structs_of_different_tables() {
+        assert!(should_generate(
+            &syn::parse_quote!(schema::utils_test_cats::table),
+            &syn::parse_quote!(schema::utils_test_mice::table)
+        ));
+    }
+}
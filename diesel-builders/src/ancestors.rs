@@ -1,21 +1,32 @@
 //! Submodule defining the `Descendant` trait.
 
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
 use diesel::{
-    AsChangeset, Identifiable, Insertable, QueryResult, RunQueryDsl, Table,
+    AsChangeset, Connection, Expression, ExpressionMethods, Identifiable, Insertable, QueryResult,
+    RunQueryDsl, Table,
     associations::HasTable,
     connection::LoadConnection,
-    query_builder::{DeleteStatement, InsertStatement, IntoUpdateTarget},
+    expression::AsInExpression,
+    query_builder::{AsQuery, DeleteStatement, InsertStatement, IntoUpdateTarget, UpdateStatement},
     query_dsl::{
         DoUpdateDsl, OnConflictDsl,
-        methods::{ExecuteDsl, FindDsl, LoadQuery, SetUpdateDsl},
+        methods::{ExecuteDsl, FilterDsl, FindDsl, LoadQuery, SelectDsl, SetUpdateDsl},
     },
 };
 use tuplities::prelude::{FlattenNestedTuple, NestTuple, NestedTupleInto, NestedTuplePushBack};
 use typenum::Unsigned;
 
 use crate::{
-    GetNestedColumns, NestedBundlableTables, NestedColumns, TableExt, Tables, TypedColumn,
-    TypedNestedTuple, columns::TupleEqAll, get_model::GetModel, load_query_builder::LoadFirst,
+    ColumnTyped, GetColumn, GetNestedColumns, HasPrimaryKeyColumn, NestedBundlableTables,
+    NestedColumns, TableExt, Tables, TypedColumn, TypedNestedTuple,
+    builder_error::{LoadHierarchyError, MissingHierarchyRecordError},
+    columns::{NonEmptyProjection, TupleEqAll, TupleEqAllExcept, TupleEqExcluded},
+    get_model::GetModel,
+    load_query_builder::{LoadFirst, LoadQueryBuilder},
     tables::NestedTables,
 };
 
@@ -143,10 +154,155 @@ pub trait ModelDescendantExt<Conn> {
     {
         <Self as ModelDelete<Conn>>::delete(self, conn)
     }
+
+    /// Moves the records of this descendant model under the root record with
+    /// the provided primary key, as by [`ModelReparent::reparent`].
+    ///
+    /// # Arguments
+    ///
+    /// * `new_root_id` - The primary key of the new root record.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if any of
+    ///   the queries fails.
+    fn reparent(
+        &self,
+        new_root_id: PrimaryKeyValue<<Self as HasTable>::Table>,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<usize>
+    where
+        Self: ModelReparent<Conn>,
+    {
+        <Self as ModelReparent<Conn>>::reparent(self, new_root_id, conn)
+    }
+
+    /// Returns whether a record of the descendant table `T` sharing the
+    /// primary key of this model exists, e.g. whether an animal is a dog, as
+    /// by [`IsA::is_a`].
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn is<T>(&self, conn: &mut Conn) -> diesel::QueryResult<bool>
+    where
+        Self: ModelAncestorOf<Conn, T> + HasTable<Table: Descendant>,
+        T: DescendantOf<Self::Table>,
+    {
+        <Self as ModelAncestorOf<Conn, T>>::is_descendant(self, conn)
+    }
+
+    /// Returns the number of records of the descendant table `C` sharing the
+    /// primary key of this model, as by [`ModelCountChildren`].
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn count_children<C>(&self, conn: &mut Conn) -> diesel::QueryResult<i64>
+    where
+        Self: ModelCountChildren<Conn, C> + HasTable<Table: Descendant>,
+        C: DescendantOf<Self::Table>,
+    {
+        <Self as ModelCountChildren<Conn, C>>::count_children(self, conn)
+    }
 }
 
 impl<M, Conn> ModelDescendantExt<Conn> for M {}
 
+/// A trait for a model associated to a diesel table which may be extended by
+/// a record of the descendant table `T`.
+pub trait ModelAncestorOf<Conn, T: DescendantOf<Self::Table>>: HasTable<Table: Descendant> {
+    /// Returns whether a record of the descendant table `T` sharing the
+    /// primary key of this model exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn is_descendant(&self, conn: &mut Conn) -> diesel::QueryResult<bool>;
+}
+
+impl<Conn, T, M> ModelAncestorOf<Conn, T> for M
+where
+    M: HasTable<Table: Descendant>
+        + GetNestedColumns<<M::Table as TableExt>::NestedPrimaryKeyColumns>,
+    T: DescendantOf<M::Table>,
+    T::NestedPrimaryKeyColumns: LoadFirst<Conn>,
+    <<M::Table as TableExt>::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleColumnType:
+        NestedTupleInto<<T::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleColumnType>,
+{
+    fn is_descendant(&self, conn: &mut Conn) -> diesel::QueryResult<bool> {
+        <T as IsA<Conn>>::is_a(self.get_nested_columns(), conn)
+    }
+}
+
+/// The query counting the records of `T` with the provided primary key.
+type CountByPrimaryKeyQuery<T> =
+    <<<T as TableExt>::NestedPrimaryKeyColumns as LoadQueryBuilder>::LoadQuery as SelectDsl<
+        diesel::dsl::CountStar,
+    >>::Output;
+
+/// A trait for a model associated to a diesel table counting the records of
+/// the descendant table `C` which extend it.
+pub trait ModelCountChildren<Conn, C: DescendantOf<Self::Table>>:
+    HasTable<Table: Descendant>
+{
+    /// Returns the number of records of the descendant table `C` sharing the
+    /// primary key of this model, as in `SELECT COUNT(*) FROM c WHERE c.id =
+    /// ?`, without loading them.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn count_children(&self, conn: &mut Conn) -> diesel::QueryResult<i64>;
+}
+
+impl<Conn, C, M> ModelCountChildren<Conn, C> for M
+where
+    Conn: LoadConnection,
+    M: HasTable<Table: Descendant>
+        + GetNestedColumns<<M::Table as TableExt>::NestedPrimaryKeyColumns>,
+    C: DescendantOf<M::Table>,
+    C::NestedPrimaryKeyColumns: LoadQueryBuilder<LoadQuery: SelectDsl<diesel::dsl::CountStar>>,
+    <<M::Table as TableExt>::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleColumnType:
+        NestedTupleInto<<C::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType>,
+    CountByPrimaryKeyQuery<C>: RunQueryDsl<Conn>,
+    for<'query> CountByPrimaryKeyQuery<C>: LoadQuery<'query, Conn, i64>,
+{
+    fn count_children(&self, conn: &mut Conn) -> diesel::QueryResult<i64> {
+        let query = SelectDsl::select(
+            <C::NestedPrimaryKeyColumns as LoadQueryBuilder>::load_query(self.get_nested_columns()),
+            diesel::dsl::count_star(),
+        );
+        RunQueryDsl::get_result::<i64>(query, conn)
+    }
+}
+
 impl<Conn, T, M> ModelDescendantOf<Conn, T> for M
 where
     T: Descendant,
@@ -184,6 +340,26 @@ where
         conn: &mut Conn,
     ) -> QueryResult<<Self::Table as TableExt>::Model>;
 
+    /// Finds a model by its ID, returning `None` if no record matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID to search for.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn find_opt(
+        id: <&Self as Identifiable>::Id,
+        conn: &mut Conn,
+    ) -> QueryResult<Option<<Self::Table as TableExt>::Model>> {
+        use diesel::OptionalExtension;
+        Self::find(id, conn).optional()
+    }
+
     /// Returns whether a model with the given ID exists.
     ///
     /// # Arguments
@@ -197,11 +373,44 @@ where
     /// * Returns a `diesel::QueryResult` which may contain an error if the
     ///   query fails.
     fn exists(id: <&Self as Identifiable>::Id, conn: &mut Conn) -> QueryResult<bool> {
-        use diesel::OptionalExtension;
-        match Self::find(id, conn).optional()? {
-            Some(_) => Ok(true),
-            None => Ok(false),
-        }
+        Ok(Self::find_opt(id, conn)?.is_some())
+    }
+
+    /// Finds a model by its ID and locks its row until the end of the current
+    /// transaction, as in `SELECT ... FOR UPDATE`.
+    ///
+    /// Concurrent transactions attempting to update, delete or lock the row
+    /// wait until the current transaction commits or rolls back, so the
+    /// model can be read and then modified without interleaving writes. The
+    /// query must run inside of a transaction, such as the one opened by
+    /// [`Connection::transaction`]: outside of one, the lock is released as
+    /// soon as the query completes. `SQLite` has no row-level locks and does
+    /// not support this clause, hence this method requires the `postgres`
+    /// feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID to search for.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails or if no matching record is found.
+    #[cfg(feature = "postgres")]
+    fn find_for_update(
+        id: <&Self as Identifiable>::Id,
+        conn: &mut Conn,
+    ) -> QueryResult<<Self::Table as TableExt>::Model>
+    where
+        for<'a> Self::Table: FindDsl<<&'a Self as Identifiable>::Id>,
+        for<'a, 'query> sealed::ForUpdate<diesel::dsl::Find<Self::Table, <&'a Self as Identifiable>::Id>>:
+            LoadQuery<'query, Conn, <Self::Table as TableExt>::Model>,
+    {
+        // `QueryDsl::for_update` cannot be called here, see
+        // `sealed::ForUpdate` for the reason.
+        sealed::ForUpdate(FindDsl::find(Self::Table::default(), id)).get_result(conn)
     }
 }
 
@@ -222,6 +431,143 @@ where
     }
 }
 
+/// The value of the single-column primary key of the table `T`.
+type PrimaryKeyValue<T> = <<T as Table>::PrimaryKey as ColumnTyped>::ColumnType;
+
+/// The query selecting the primary keys of `T` among a list of values.
+type MissingIdsQuery<T> = <<T as SelectDsl<<T as Table>::PrimaryKey>>::Output as FilterDsl<
+    diesel::dsl::EqAny<<T as Table>::PrimaryKey, Vec<PrimaryKeyValue<T>>>,
+>>::Output;
+
+/// A trait for checking the existence of many models of a table with a
+/// single-column primary key at once.
+pub trait ModelExistsMany<Conn>: HasTable<Table: HasPrimaryKeyColumn> {
+    /// Returns the provided primary keys for which no record exists, in the
+    /// order in which they are provided.
+    ///
+    /// A single `SELECT ... WHERE id IN (...)` query is issued, whose result
+    /// is compared against the provided primary keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The primary keys to look for.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn missing_ids(
+        ids: &[PrimaryKeyValue<Self::Table>],
+        conn: &mut Conn,
+    ) -> QueryResult<Vec<PrimaryKeyValue<Self::Table>>>;
+
+    /// Returns whether a record exists for each of the provided primary keys,
+    /// as by [`missing_ids`](Self::missing_ids).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The primary keys to look for.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn exists_all(ids: &[PrimaryKeyValue<Self::Table>], conn: &mut Conn) -> QueryResult<bool> {
+        Ok(Self::missing_ids(ids, conn)?.is_empty())
+    }
+}
+
+impl<Conn, M> ModelExistsMany<Conn> for M
+where
+    M: HasTable<Table: HasPrimaryKeyColumn>,
+    Conn: LoadConnection,
+    PrimaryKeyValue<M::Table>: Eq + Hash,
+    <M::Table as Table>::PrimaryKey: ExpressionMethods,
+    Vec<PrimaryKeyValue<M::Table>>:
+        AsInExpression<<<M::Table as Table>::PrimaryKey as Expression>::SqlType>,
+    M::Table: SelectDsl<<M::Table as Table>::PrimaryKey>,
+    <M::Table as SelectDsl<<M::Table as Table>::PrimaryKey>>::Output: FilterDsl<
+        diesel::dsl::EqAny<<M::Table as Table>::PrimaryKey, Vec<PrimaryKeyValue<M::Table>>>,
+    >,
+    MissingIdsQuery<M::Table>: RunQueryDsl<Conn>,
+    for<'query> MissingIdsQuery<M::Table>: LoadQuery<'query, Conn, PrimaryKeyValue<M::Table>>,
+{
+    fn missing_ids(
+        ids: &[PrimaryKeyValue<M::Table>],
+        conn: &mut Conn,
+    ) -> QueryResult<Vec<PrimaryKeyValue<M::Table>>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let table: M::Table = Default::default();
+        let primary_key = table.primary_key();
+        let query: MissingIdsQuery<M::Table> = FilterDsl::filter(
+            SelectDsl::select(table, primary_key),
+            primary_key.eq_any(ids.to_vec()),
+        );
+        let found: HashSet<PrimaryKeyValue<M::Table>> =
+            RunQueryDsl::load::<PrimaryKeyValue<M::Table>>(query, conn)?.into_iter().collect();
+        Ok(ids.iter().filter(|id| !found.contains(*id)).cloned().collect())
+    }
+}
+
+/// A trait for updating the record of a model with a diesel changeset.
+pub trait ModelUpdate<Conn, Changeset>: HasTable<Table: TableExt> {
+    /// Updates the record sharing the primary key of this model with the
+    /// provided changeset, returning the updated model.
+    ///
+    /// The changeset is applied as-is: unlike the builder setters, it does not
+    /// go through the column validators. The columns marked as
+    /// `#[table_model(timestamp(updated))]` are set to the current time
+    /// alongside it, and should therefore not be part of the changeset.
+    ///
+    /// # Arguments
+    ///
+    /// * `changeset` - The changeset to apply to the record.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   update fails or if the record no longer exists.
+    fn update(
+        &self,
+        changeset: Changeset,
+        conn: &mut Conn,
+    ) -> QueryResult<<Self::Table as TableExt>::Model>;
+}
+
+impl<Conn, Changeset, M> ModelUpdate<Conn, Changeset> for M
+where
+    M: HasTable<Table: TableExt>,
+    Conn: LoadConnection,
+    <M::Table as TableExt>::ManagedChangeset<Changeset>: AsChangeset<Target = M::Table>,
+    for<'query> &'query M: Identifiable,
+    M::Table: for<'query> FindDsl<<&'query M as Identifiable>::Id>,
+    for<'query> <M::Table as FindDsl<<&'query M as Identifiable>::Id>>::Output:
+        IntoUpdateTarget<Table = M::Table>,
+    for<'query> UpdateStatement<
+        M::Table,
+        <<M::Table as FindDsl<<&'query M as Identifiable>::Id>>::Output as IntoUpdateTarget>::WhereClause,
+        <<M::Table as TableExt>::ManagedChangeset<Changeset> as AsChangeset>::Changeset,
+    >: AsQuery + LoadQuery<'query, Conn, <M::Table as TableExt>::Model>,
+{
+    fn update(
+        &self,
+        changeset: Changeset,
+        conn: &mut Conn,
+    ) -> QueryResult<<Self::Table as TableExt>::Model> {
+        diesel::update(M::Table::default().find(self.id()))
+            .set(M::Table::managed_changeset(changeset))
+            .get_result(conn)
+    }
+}
+
 /// A trait for deleting a model from its root table, which cascades to all
 /// descendants.
 pub trait ModelDelete<Conn>: HasTable<Table: Descendant> {
@@ -260,6 +606,146 @@ where
     }
 }
 
+/// The assignment moving a record of `T` to the primary key of another root.
+type ReparentAssignment<T> = diesel::dsl::Eq<<T as Table>::PrimaryKey, PrimaryKeyValue<T>>;
+
+/// The flat tuple of assignments copying every column of a record of `T` but
+/// its primary key.
+type ReparentRecord<T> =
+    <<NestedAllColumns<T> as TupleEqAllExcept>::EqAllExcept as FlattenNestedTuple>::Flattened;
+
+/// The tables of the hierarchy of `T` below its root, as a nested tuple.
+type NestedReparentedTables<T> =
+    <<T as DescendantWithSelf>::NestedAncestorsWithSelf as sealed::NestedTail>::Tail;
+
+/// A trait for moving a descendant model under a different root record.
+pub trait ModelReparent<Conn>: HasTable<Table: Descendant + HasPrimaryKeyColumn> {
+    /// Moves the records of this model under the root record with the provided
+    /// primary key, within a transaction.
+    ///
+    /// Every table of the hierarchy below the root, from the child of the root
+    /// down to the table of this model, is moved one level at a time: its
+    /// record is copied to the new primary key, and the previous records are
+    /// deleted once all the levels have been copied, deepest first. The
+    /// foreign keys between the levels therefore do not need to be declared
+    /// with `ON UPDATE CASCADE`. For instance, moving a puppy under another
+    /// animal also moves the dog record of the puppy, while the previous root
+    /// keeps only its animal record.
+    ///
+    /// The operation is only valid when the new root has no record yet in
+    /// the moved tables, e.g. a puppy may only be moved under an animal which
+    /// is not a dog already. Records of tables descending from the table of
+    /// this model are not part of its hierarchy and are not moved: they are
+    /// deleted alongside the previous record if their foreign keys are
+    /// declared with `ON DELETE CASCADE`, and otherwise the deletion fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_root_id` - The primary key of the new root record.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Returns
+    ///
+    /// * The number of records moved, one per level below the root.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if any of
+    ///   the queries fails, for instance when the new root does not exist or
+    ///   already has a record in one of the moved tables, in which case the
+    ///   transaction is rolled back.
+    fn reparent(
+        &self,
+        new_root_id: PrimaryKeyValue<Self::Table>,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<usize>;
+}
+
+impl<Conn, M> ModelReparent<Conn> for M
+where
+    M: HasTable<Table: DescendantWithSelf + HasPrimaryKeyColumn>
+        + GetColumn<<M::Table as Table>::PrimaryKey>,
+    Conn: Connection,
+    <M::Table as DescendantWithSelf>::NestedAncestorsWithSelf: sealed::NestedTail,
+    NestedReparentedTables<M::Table>: ReparentNestedHierarchy<Conn, PrimaryKeyValue<M::Table>>,
+{
+    fn reparent(
+        &self,
+        new_root_id: PrimaryKeyValue<M::Table>,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<usize> {
+        let old_root_id = <M as GetColumn<<M::Table as Table>::PrimaryKey>>::get_column_ref(self);
+        conn.transaction(|conn| {
+            <NestedReparentedTables<M::Table> as ReparentNestedHierarchy<
+                Conn,
+                PrimaryKeyValue<M::Table>,
+            >>::reparent_nested_hierarchy(old_root_id, &new_root_id, conn)
+        })
+    }
+}
+
+/// A nested tuple of tables sharing a primary key whose records may be moved
+/// together to another primary key.
+pub trait ReparentNestedHierarchy<Conn, PK> {
+    /// Copies the record of each table with the primary key `old_id` to the
+    /// primary key `new_id`, from the first table to the last one, and then
+    /// deletes the previous records from the last table to the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_id` - The primary key shared by the records to move.
+    /// * `new_id` - The primary key to move the records to.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Returns
+    ///
+    /// * The number of records moved.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if any of
+    ///   the queries fails.
+    fn reparent_nested_hierarchy(old_id: &PK, new_id: &PK, conn: &mut Conn) -> QueryResult<usize>;
+}
+
+impl<Conn, PK> ReparentNestedHierarchy<Conn, PK> for () {
+    fn reparent_nested_hierarchy(
+        _old_id: &PK,
+        _new_id: &PK,
+        _conn: &mut Conn,
+    ) -> QueryResult<usize> {
+        Ok(0)
+    }
+}
+
+impl<Conn, PK, T> ReparentNestedHierarchy<Conn, PK> for (T,)
+where
+    T: sealed::MoveRecord<Conn, PK>,
+{
+    fn reparent_nested_hierarchy(old_id: &PK, new_id: &PK, conn: &mut Conn) -> QueryResult<usize> {
+        T::copy_record(old_id, new_id, conn)?;
+        T::delete_record(old_id, conn)?;
+        Ok(1)
+    }
+}
+
+impl<Conn, PK, Head, Tail> ReparentNestedHierarchy<Conn, PK> for (Head, Tail)
+where
+    Head: sealed::MoveRecord<Conn, PK>,
+    Tail: ReparentNestedHierarchy<Conn, PK>,
+{
+    fn reparent_nested_hierarchy(old_id: &PK, new_id: &PK, conn: &mut Conn) -> QueryResult<usize> {
+        // The deeper levels reference this one, so they are copied after it
+        // and deleted before it.
+        Head::copy_record(old_id, new_id, conn)?;
+        let moved = Tail::reparent_nested_hierarchy(old_id, new_id, conn)?;
+        Head::delete_record(old_id, conn)?;
+        Ok(moved + 1)
+    }
+}
+
 /// A trait for upserting (insert or update) a model.
 ///
 /// This trait allows inserting a model or updating it if it already exists,
@@ -267,8 +753,9 @@ where
 pub trait ModelUpsert<Conn>: HasTable<Table: TableExt> {
     /// Upserts the model (insert or update on conflict).
     ///
-    /// If a record with the same primary key exists, it is updated.
-    /// Otherwise, a new record is inserted.
+    /// If a record with the same primary key exists, it is updated, setting
+    /// the columns marked as `#[table_model(timestamp(updated))]` to the
+    /// current time. Otherwise, a new record is inserted.
     ///
     /// # Arguments
     ///
@@ -285,6 +772,132 @@ pub trait ModelUpsert<Conn>: HasTable<Table: TableExt> {
     fn upsert(&self, conn: &mut Conn) -> QueryResult<<Self::Table as TableExt>::Model>
     where
         Self: Sized;
+
+    /// Upserts the model, reporting whether it was inserted or updated.
+    ///
+    /// The outcome is determined by looking up the primary key of the model
+    /// before the upsert, which keeps the approach backend-agnostic instead of
+    /// relying on backend-specific markers such as the `xmax` system column of
+    /// Postgres. As the lookup and the upsert are distinct statements, a
+    /// concurrent insertion or deletion of the same record in between may
+    /// cause the wrong outcome to be reported: run the operation in a
+    /// transaction with a suitable isolation level when this matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection.
+    ///
+    /// # Returns
+    ///
+    /// * The inserted or updated model, alongside the [`UpsertOutcome`].
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   lookup or the upsert operation fails.
+    fn upsert_report(
+        &self,
+        conn: &mut Conn,
+    ) -> QueryResult<(<Self::Table as TableExt>::Model, UpsertOutcome)>
+    where
+        Self: Sized + GetNestedColumns<<Self::Table as TableExt>::NestedPrimaryKeyColumns>,
+        <Self::Table as TableExt>::NestedPrimaryKeyColumns: LoadFirst<Conn>,
+    {
+        use diesel::OptionalExtension;
+        let existing =
+            <<Self::Table as TableExt>::NestedPrimaryKeyColumns as LoadFirst<Conn>>::load_first(
+                self.get_nested_columns(),
+                conn,
+            )
+            .optional()?;
+        let model = self.upsert(conn)?;
+        let outcome =
+            if existing.is_some() { UpsertOutcome::Updated } else { UpsertOutcome::Inserted };
+        Ok((model, outcome))
+    }
+
+    /// Upserts the model, keeping the stored values of the projected columns
+    /// when a record with the same primary key already exists.
+    ///
+    /// A single `INSERT ... ON CONFLICT DO UPDATE` statement is issued, whose
+    /// `SET` clause assigns every column of the table but those of `P`, so
+    /// the stored values of the projected columns are never overwritten. As
+    /// with [`upsert`](Self::upsert), all the columns are written when no
+    /// record with the same primary key exists, and the columns marked as
+    /// `#[table_model(timestamp(updated))]` are set to the current time when
+    /// it does, even if they are part of `P`.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection.
+    ///
+    /// # Returns
+    ///
+    /// * The inserted or updated model.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   upsert operation fails.
+    fn upsert_excluding<P>(&self, conn: &mut Conn) -> QueryResult<<Self::Table as TableExt>::Model>
+    where
+        Self: Sized + GetNestedColumns<NestedAllColumns<Self::Table>>,
+        Conn: LoadConnection,
+        P: NonEmptyProjection<Table = Self::Table>,
+        NestedAllColumns<Self::Table>: TupleEqAll<EqAll: FlattenNestedTuple<Flattened: Insertable<Self::Table>>>
+            + TupleEqAllExcept<EqAllExcept: FlattenNestedTuple>,
+        UpsertChangeset<Self::Table>: AsChangeset<Target = Self::Table>,
+        for<'query> InsertStatement<
+            Self::Table,
+            <UpsertRecord<Self::Table> as Insertable<Self::Table>>::Values,
+        >: OnConflictDsl<
+                <Self::Table as Table>::PrimaryKey,
+                Output: DoUpdateDsl<
+                    Output: SetUpdateDsl<
+                        UpsertChangeset<Self::Table>,
+                        Output: LoadQuery<'query, Conn, <Self::Table as TableExt>::Model>,
+                    >,
+                >,
+            >,
+    {
+        let table: Self::Table = Default::default();
+        let columns = NestedAllColumns::<Self::Table>::default();
+        let changeset = columns
+            .eq_all_except(self.get_nested_columns(), |name| {
+                <<P as NestTuple>::Nested as NestedColumns>::column_position(name).is_some()
+                    || is_updated_column::<Self::Table>(name)
+            })
+            .flatten();
+        let results: Vec<<Self::Table as TableExt>::Model> = diesel::insert_into(table)
+            .values(columns.eq_all(self.get_nested_columns()).flatten())
+            .on_conflict(table.primary_key())
+            .do_update()
+            .set(<Self::Table as TableExt>::managed_changeset(changeset))
+            .get_results(conn)?;
+
+        results.into_iter().next().ok_or(diesel::result::Error::NotFound)
+    }
+}
+
+/// Returns whether the column of `T` with the provided name is marked as
+/// `#[table_model(timestamp(updated))]`.
+fn is_updated_column<T: TableExt>(name: &str) -> bool {
+    <T::NestedUpdatedColumns as NestedColumns>::column_position(name).is_some()
+}
+
+/// The flat tuple of assignments updating the columns of `T` to the values
+/// of a record, extended with the assignments of the columns managed by `T`.
+type UpsertChangeset<T> = <T as TableExt>::ManagedChangeset<
+    <<NestedAllColumns<T> as TupleEqAllExcept>::EqAllExcept as FlattenNestedTuple>::Flattened,
+>;
+
+/// The path taken by an upsert operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpsertOutcome {
+    /// No record with the same primary key existed, so a new one was inserted.
+    Inserted,
+    /// A record with the same primary key existed and was updated.
+    Updated,
 }
 
 impl<Conn, M> ModelUpsert<Conn> for M
@@ -293,14 +906,16 @@ where
         + GetNestedColumns<<<M::Table as Table>::AllColumns as NestTuple>::Nested>,
     Conn: LoadConnection,
     <<M::Table as Table>::AllColumns as NestTuple>::Nested:
-        TupleEqAll<EqAll: FlattenNestedTuple<Flattened: Insertable<M::Table> + AsChangeset<Target = M::Table>>>,
+        TupleEqAll<EqAll: FlattenNestedTuple<Flattened: Insertable<M::Table>>>
+            + TupleEqAllExcept<EqAllExcept: FlattenNestedTuple>,
+    UpsertChangeset<M::Table>: AsChangeset<Target = M::Table>,
     for<'query> InsertStatement<
         Self::Table,
         <<<<<M::Table as Table>::AllColumns as NestTuple>::Nested as TupleEqAll>::EqAll as FlattenNestedTuple>::Flattened as Insertable<Self::Table>>::Values,
     >: OnConflictDsl<
         <M::Table as Table>::PrimaryKey,
         Output: DoUpdateDsl<Output: SetUpdateDsl<
-            <<<<M::Table as Table>::AllColumns as NestTuple>::Nested as TupleEqAll>::EqAll as FlattenNestedTuple>::Flattened,
+            UpsertChangeset<M::Table>,
             Output: LoadQuery<'query, Conn, <Self::Table as TableExt>::Model>,
         >>
     >,
@@ -312,11 +927,16 @@ where
         use diesel::Table;
         let table: M::Table = Default::default();
         let columns = <<M::Table as Table>::AllColumns as NestTuple>::Nested::default();
+        // The updated timestamps are assigned by the managed changeset alone, as
+        // Postgres rejects a column assigned twice.
+        let changeset = columns
+            .eq_all_except(self.get_nested_columns(), is_updated_column::<M::Table>)
+            .flatten();
         let results: Vec<<Self::Table as TableExt>::Model> = diesel::insert_into(table)
             .values(columns.eq_all(self.get_nested_columns()).flatten())
             .on_conflict(table.primary_key())
             .do_update()
-            .set(columns.eq_all(self.get_nested_columns()).flatten())
+            .set(<M::Table as TableExt>::managed_changeset(changeset))
             .get_results(conn)?;
 
         if let Some(first) = results.into_iter().next() {
@@ -327,6 +947,306 @@ where
     }
 }
 
+/// Maximum number of bind parameters in a single bulk upsert statement.
+///
+/// This is the default limit of SQLite before version 3.32.0, the strictest
+/// among the supported backends.
+const UPSERT_BATCH_MAX_BIND_PARAMETERS: usize = 999;
+
+/// The nested tuple of all the columns of the table `T`.
+type NestedAllColumns<T> = <<T as Table>::AllColumns as NestTuple>::Nested;
+
+/// The flat tuple of assignments inserting every column of a record of `T`.
+type UpsertRecord<T> =
+    <<NestedAllColumns<T> as TupleEqAll>::EqAll as FlattenNestedTuple>::Flattened;
+
+/// The flat tuple of assignments updating the columns of `T` to their
+/// excluded values, extended with the assignments of the columns managed by
+/// `T`.
+type UpsertExcluded<T> = <T as TableExt>::ManagedChangeset<
+    <<NestedAllColumns<T> as TupleEqExcluded>::EqExcluded as FlattenNestedTuple>::Flattened,
+>;
+
+/// A trait for upserting (insert or update) many models of the same table.
+pub trait ModelUpsertBatch<Conn>: HasTable<Table: TableExt> + Sized {
+    /// Upserts the models with multi-row `INSERT ... ON CONFLICT DO UPDATE`
+    /// statements, all run within a single transaction.
+    ///
+    /// The models are split into as few statements as allowed by the limit
+    /// of 999 bind parameters per statement of SQLite: a table with 4 columns
+    /// is upserted 249 rows at a time. As with [`ModelUpsert::upsert`], only
+    /// the columns of the table itself are written, not those of its
+    /// ancestors, and the columns marked as
+    /// `#[table_model(timestamp(updated))]` of the updated records are set to
+    /// the current time.
+    ///
+    /// Models sharing a primary key are deduplicated before any statement is
+    /// built, the last one in `models` winning: otherwise Postgres would
+    /// reject a statement affecting the same row twice. The returned models
+    /// follow the order in which the backend returns the rows, which is not
+    /// guaranteed to match the order of `models`.
+    ///
+    /// # Arguments
+    ///
+    /// * `models` - The models to upsert.
+    /// * `conn` - A mutable reference to the Diesel connection.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if any of
+    ///   the statements fails, in which case the transaction is rolled back.
+    fn upsert_batch(
+        models: &[Self],
+        conn: &mut Conn,
+    ) -> QueryResult<Vec<<Self::Table as TableExt>::Model>>;
+}
+
+impl<Conn, M> ModelUpsertBatch<Conn> for M
+where
+    M: HasTable<Table: TableExt> + GetNestedColumns<NestedAllColumns<M::Table>>,
+    for<'a> &'a M: Identifiable,
+    Conn: LoadConnection,
+    NestedAllColumns<M::Table>: Default
+        + TupleEqAll<EqAll: FlattenNestedTuple>
+        + TupleEqExcluded<EqExcluded: FlattenNestedTuple>,
+    UpsertExcluded<M::Table>: AsChangeset<Target = M::Table>,
+    Vec<UpsertRecord<M::Table>>: Insertable<M::Table>,
+    for<'query> InsertStatement<M::Table, <Vec<UpsertRecord<M::Table>> as Insertable<M::Table>>::Values>:
+        OnConflictDsl<
+                <M::Table as Table>::PrimaryKey,
+                Output: DoUpdateDsl<
+                    Output: SetUpdateDsl<
+                        UpsertExcluded<M::Table>,
+                        Output: LoadQuery<'query, Conn, <M::Table as TableExt>::Model>,
+                    >,
+                >,
+            >,
+{
+    fn upsert_batch(
+        models: &[Self],
+        conn: &mut Conn,
+    ) -> QueryResult<Vec<<Self::Table as TableExt>::Model>> {
+        let columns = NestedAllColumns::<M::Table>::default();
+        let column_count = (0..)
+            .take_while(|&position| {
+                <NestedAllColumns<M::Table> as NestedColumns>::table_name_at(position).is_some()
+            })
+            .count();
+        // Each statement also binds the current time of the updated timestamps once.
+        let updated_column_count = (0..)
+            .take_while(|&position| {
+                <<M::Table as TableExt>::NestedUpdatedColumns as NestedColumns>::table_name_at(
+                    position,
+                )
+                .is_some()
+            })
+            .count();
+        let rows_per_statement = (UPSERT_BATCH_MAX_BIND_PARAMETERS
+            .saturating_sub(updated_column_count)
+            / column_count.max(1))
+        .max(1);
+
+        // Keep only the last model of each primary key.
+        let mut last_positions = HashMap::with_capacity(models.len());
+        for (position, model) in models.iter().enumerate() {
+            last_positions.insert(Identifiable::id(model), position);
+        }
+        let models: Vec<&M> = models
+            .iter()
+            .enumerate()
+            .filter(|&(position, model)| {
+                last_positions.get(&Identifiable::id(model)) == Some(&position)
+            })
+            .map(|(_, model)| model)
+            .collect();
+
+        conn.transaction(|conn| {
+            let mut upserted = Vec::with_capacity(models.len());
+            for chunk in models.chunks(rows_per_statement) {
+                let records: Vec<UpsertRecord<M::Table>> = chunk
+                    .iter()
+                    .map(|model| columns.eq_all(model.get_nested_columns()).flatten())
+                    .collect();
+                let table: M::Table = Default::default();
+                let rows: Vec<<Self::Table as TableExt>::Model> = diesel::insert_into(table)
+                    .values(records)
+                    .on_conflict(table.primary_key())
+                    .do_update()
+                    .set(<M::Table as TableExt>::managed_changeset(
+                        columns.eq_excluded(is_updated_column::<M::Table>).flatten(),
+                    ))
+                    .get_results(conn)?;
+                upserted.extend(rows);
+            }
+            Ok(upserted)
+        })
+    }
+}
+
+/// A nested tuple of tables sharing a primary key whose records may be loaded
+/// together.
+pub trait LoadNestedHierarchy<Conn, PK> {
+    /// The nested tuple of models loaded from the tables.
+    type NestedModels;
+
+    /// Loads the record of each table with the provided primary key.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary_key` - The nested primary key values shared by the tables.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a [`LoadHierarchyError::MissingRecord`] naming the first table
+    ///   lacking the record.
+    /// * Returns a [`LoadHierarchyError::Diesel`] if any other error is raised
+    ///   by the queries.
+    fn load_nested_hierarchy(
+        primary_key: &PK,
+        conn: &mut Conn,
+    ) -> Result<Self::NestedModels, LoadHierarchyError>;
+}
+
+/// Loads the record of table `T` with the provided primary key, reporting a
+/// missing record as a [`MissingHierarchyRecordError`].
+fn load_hierarchy_record<Conn, PK, T>(
+    primary_key: &PK,
+    conn: &mut Conn,
+) -> Result<T::Model, LoadHierarchyError>
+where
+    T: TableExt<NestedPrimaryKeyColumns: LoadFirst<Conn>>,
+    PK: Clone
+        + NestedTupleInto<<T::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType>,
+{
+    <T::NestedPrimaryKeyColumns as LoadFirst<Conn>>::load_first(primary_key.clone(), conn).map_err(
+        |error| {
+            match error {
+                diesel::result::Error::NotFound => {
+                    MissingHierarchyRecordError { table_name: T::TABLE_NAME }.into()
+                }
+                error => error.into(),
+            }
+        },
+    )
+}
+
+impl<Conn, PK> LoadNestedHierarchy<Conn, PK> for () {
+    type NestedModels = ();
+
+    fn load_nested_hierarchy(
+        _primary_key: &PK,
+        _conn: &mut Conn,
+    ) -> Result<(), LoadHierarchyError> {
+        Ok(())
+    }
+}
+
+impl<Conn, PK, T> LoadNestedHierarchy<Conn, PK> for (T,)
+where
+    T: TableExt<NestedPrimaryKeyColumns: LoadFirst<Conn>>,
+    PK: Clone
+        + NestedTupleInto<<T::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType>,
+{
+    type NestedModels = (T::Model,);
+
+    fn load_nested_hierarchy(
+        primary_key: &PK,
+        conn: &mut Conn,
+    ) -> Result<Self::NestedModels, LoadHierarchyError> {
+        Ok((load_hierarchy_record::<Conn, PK, T>(primary_key, conn)?,))
+    }
+}
+
+impl<Conn, PK, Head, Tail> LoadNestedHierarchy<Conn, PK> for (Head, Tail)
+where
+    Head: TableExt<NestedPrimaryKeyColumns: LoadFirst<Conn>>,
+    PK: Clone
+        + NestedTupleInto<<Head::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType>,
+    Tail: LoadNestedHierarchy<Conn, PK>,
+{
+    type NestedModels = (Head::Model, Tail::NestedModels);
+
+    fn load_nested_hierarchy(
+        primary_key: &PK,
+        conn: &mut Conn,
+    ) -> Result<Self::NestedModels, LoadHierarchyError> {
+        let head = load_hierarchy_record::<Conn, PK, Head>(primary_key, conn)?;
+        Ok((head, Tail::load_nested_hierarchy(primary_key, conn)?))
+    }
+}
+
+/// A trait for loading the records of a table and of all of its ancestors
+/// sharing the same primary key.
+pub trait LoadHierarchy<Conn>: DescendantWithSelf {
+    /// Loads the records of the table and its ancestors with the provided
+    /// primary key, as a nested tuple of models ordered from the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary_key` - The nested primary key values of the records.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a [`LoadHierarchyError::MissingRecord`] naming the first table
+    ///   of the hierarchy, root included, lacking the record.
+    /// * Returns a [`LoadHierarchyError::Diesel`] if any other error is raised
+    ///   by the queries.
+    fn load_hierarchy<PK>(
+        primary_key: PK,
+        conn: &mut Conn,
+    ) -> Result<
+        <Self::NestedAncestorsWithSelf as LoadNestedHierarchy<Conn, PK>>::NestedModels,
+        LoadHierarchyError,
+    >
+    where
+        Self::NestedAncestorsWithSelf: LoadNestedHierarchy<Conn, PK>,
+    {
+        <Self::NestedAncestorsWithSelf as LoadNestedHierarchy<Conn, PK>>::load_nested_hierarchy(
+            &primary_key,
+            conn,
+        )
+    }
+}
+
+impl<T, Conn> LoadHierarchy<Conn> for T where T: DescendantWithSelf {}
+
+/// A trait for checking whether a record of an ancestor table is also a
+/// record of this table.
+pub trait IsA<Conn>: Descendant {
+    /// Returns whether a record of this table with the provided primary key
+    /// exists, e.g. whether the animal with a given primary key is a dog.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary_key` - The nested primary key values of the record, shared
+    ///   with the records of its ancestor tables.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn is_a<PK>(primary_key: PK, conn: &mut Conn) -> QueryResult<bool>
+    where
+        Self::NestedPrimaryKeyColumns: LoadFirst<Conn>,
+        PK: NestedTupleInto<
+            <Self::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType,
+        >,
+    {
+        use diesel::OptionalExtension;
+        Ok(<Self::NestedPrimaryKeyColumns as LoadFirst<Conn>>::load_first(primary_key, conn)
+            .optional()?
+            .is_some())
+    }
+}
+
+impl<T, Conn> IsA<Conn> for T where T: Descendant {}
+
 /// A trait marker for getting the ancestor tables of a descendant table.
 pub trait NestedAncestorsOf<T: Descendant<Ancestors = <Self as FlattenNestedTuple>::Flattened>>:
     NestedTables
@@ -337,7 +1257,9 @@ pub trait NestedAncestorsOf<T: Descendant<Ancestors = <Self as FlattenNestedTupl
 pub trait Descendant: TableExt {
     /// The ancestor tables of this table.
     type Ancestors: Tables<
-        Nested: NestedAncestorsOf<Self, Flattened = Self::Ancestors> + NestedTuplePushBack<Self>,
+        Nested: NestedAncestorsOf<Self, Flattened = Self::Ancestors>
+                    + NestedTuplePushBack<Self>
+                    + NestedHierarchyLevels,
     >;
     /// The root of the ancestor hierarchy. When the current
     /// table is the root, this is itself.
@@ -345,6 +1267,50 @@ pub trait Descendant: TableExt {
         NestedTupleColumnType = <Self::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleColumnType,
         NestedTupleValueType = <Self::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType,
     >>;
+    /// The number of tables in the hierarchy from the root down to this
+    /// table, i.e. the number of ancestors plus one.
+    const HIERARCHY_DEPTH: usize =
+        <<Self::Ancestors as NestTuple>::Nested as NestedHierarchyLevels>::LEN + 1;
+    /// The level of the table in the insertion order of its hierarchy: `0`
+    /// for the root, otherwise one more than the highest level among its
+    /// ancestors. Tables of a hierarchy sharing a level do not depend on each
+    /// other, as is the case for the two branches of a diamond.
+    const INSERT_LEVEL: usize =
+        <<Self::Ancestors as NestTuple>::Nested as NestedHierarchyLevels>::NEXT_INSERT_LEVEL;
+}
+
+/// A trait for nested tuples of ancestor tables, providing the defaults of
+/// [`Descendant::HIERARCHY_DEPTH`] and [`Descendant::INSERT_LEVEL`] for the
+/// tables descending from them.
+pub trait NestedHierarchyLevels {
+    /// The number of tables in the nested tuple.
+    const LEN: usize;
+    /// One more than the highest insert level among the tables, or `0` when
+    /// the nested tuple is empty.
+    const NEXT_INSERT_LEVEL: usize;
+}
+
+impl NestedHierarchyLevels for () {
+    const LEN: usize = 0;
+    const NEXT_INSERT_LEVEL: usize = 0;
+}
+
+impl<A: Descendant> NestedHierarchyLevels for (A,) {
+    const LEN: usize = 1;
+    const NEXT_INSERT_LEVEL: usize = A::INSERT_LEVEL + 1;
+}
+
+impl<Head, Tail> NestedHierarchyLevels for (Head, Tail)
+where
+    Head: Descendant,
+    Tail: NestedHierarchyLevels,
+{
+    const LEN: usize = Tail::LEN + 1;
+    const NEXT_INSERT_LEVEL: usize = if Head::INSERT_LEVEL + 1 > Tail::NEXT_INSERT_LEVEL {
+        Head::INSERT_LEVEL + 1
+    } else {
+        Tail::NEXT_INSERT_LEVEL
+    };
 }
 
 /// A trait for Diesel tables that have ancestor tables, including themselves.
@@ -384,3 +1350,239 @@ where
         + diesel::query_source::TableNotEqual<Head>,
 {
 }
+
+/// A trait computing the nearest common ancestor of two tables of the same
+/// hierarchy, where a table counts as an ancestor of itself.
+///
+/// The ancestor lists of both tables, root first, are walked in lockstep and
+/// the last table they share is selected. With single inheritance this is the
+/// nearest common ancestor: for instance two sibling tables yield their
+/// parent, and a table and one of its descendants yield the table itself.
+///
+/// # Requirements
+///
+/// Tables are compared through Diesel's [`AppearsInFromClause`], so every
+/// pair of tables met at the same depth of the two lists must be allowed to
+/// appear in the same query. The `TableModel` derive declares a table together
+/// with each of its ancestors, but it cannot see the other tables of the
+/// hierarchy: siblings such as `dogs` and `cats`, and cousins such as
+/// `puppies` and `cats`, must be declared with
+/// [`allow_tables_to_appear_in_same_query!`], as the schema generated by the
+/// Diesel CLI already does for all of its tables. Otherwise the bound is not
+/// satisfied and `CommonAncestor` is not implemented for the pair.
+///
+/// ```ignore
+/// diesel::allow_tables_to_appear_in_same_query!(dogs, cats);
+/// ```
+///
+/// With multiple inheritance the ancestor lists are linearizations of a
+/// diamond, so they may diverge before the nearest common ancestor and the
+/// result is then a more distant ancestor. For example, a table extending
+/// both `dogs` and `cats` (listed in this order) shares only the root with
+/// `cats`, even though `cats` is one of its ancestors.
+///
+/// [`AppearsInFromClause`]: diesel::query_source::AppearsInFromClause
+/// [`allow_tables_to_appear_in_same_query!`]: diesel::allow_tables_to_appear_in_same_query
+pub trait CommonAncestor<B: DescendantWithSelf>: DescendantWithSelf {
+    /// The nearest table both `Self` and `B` descend from.
+    type Ancestor: Descendant;
+}
+
+impl<A, B> CommonAncestor<B> for A
+where
+    A: DescendantWithSelf,
+    B: DescendantWithSelf<Root = A::Root>,
+    A::NestedAncestorsWithSelf: NestedTuplePushBack<sealed::End>,
+    B::NestedAncestorsWithSelf: NestedTuplePushBack<sealed::End>,
+    <A::NestedAncestorsWithSelf as NestedTuplePushBack<sealed::End>>::Output:
+        sealed::NestedCommonPrefix<
+                <B::NestedAncestorsWithSelf as NestedTuplePushBack<sealed::End>>::Output,
+                A::Root,
+                Last: Descendant,
+            >,
+{
+    type Ancestor = <<A::NestedAncestorsWithSelf as NestedTuplePushBack<sealed::End>>::Output as sealed::NestedCommonPrefix<
+        <B::NestedAncestorsWithSelf as NestedTuplePushBack<sealed::End>>::Output,
+        A::Root,
+    >>::Last;
+}
+
+/// Sealed module for private traits.
+mod sealed {
+    use diesel::{
+        ExpressionMethods, Insertable, QueryResult, RunQueryDsl, Table,
+        query_builder::{DeleteStatement, InsertStatement, IntoUpdateTarget},
+        query_dsl::methods::{ExecuteDsl, FindDsl},
+        query_source::{AppearsInFromClause, Never, Once},
+    };
+    use tuplities::prelude::{FlattenNestedTuple, NestedTupleInto};
+
+    use super::{NestedAllColumns, PrimaryKeyValue, ReparentAssignment, ReparentRecord};
+    use crate::{
+        GetNestedColumns, HasPrimaryKeyColumn, NestedColumns, TypedNestedTuple,
+        columns::TupleEqAllExcept, load_query_builder::LoadFirst,
+    };
+
+    /// Splits a non-empty nested tuple into its first element and the nested
+    /// tuple of the others.
+    pub trait NestedTail {
+        /// The nested tuple of the elements following the first one.
+        type Tail;
+    }
+
+    impl<Head> NestedTail for (Head,) {
+        type Tail = ();
+    }
+
+    impl<Head, Tail> NestedTail for (Head, Tail) {
+        type Tail = Tail;
+    }
+
+    /// A table whose record may be moved to another primary key.
+    pub trait MoveRecord<Conn, PK> {
+        /// Inserts a copy of the record with the primary key `old_id`, with
+        /// the primary key `new_id` instead.
+        fn copy_record(old_id: &PK, new_id: &PK, conn: &mut Conn) -> QueryResult<usize>;
+
+        /// Deletes the record with the primary key `old_id`.
+        fn delete_record(old_id: &PK, conn: &mut Conn) -> QueryResult<usize>;
+    }
+
+    impl<Conn, T> MoveRecord<Conn, PrimaryKeyValue<T>> for T
+    where
+        T: HasPrimaryKeyColumn,
+        T::NestedPrimaryKeyColumns: LoadFirst<Conn>,
+        T::Model: GetNestedColumns<NestedAllColumns<T>>,
+        (PrimaryKeyValue<T>,):
+            NestedTupleInto<<T::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleValueType>,
+        T::PrimaryKey: ExpressionMethods,
+        PrimaryKeyValue<T>: diesel::expression::AsExpression<
+                <<T as Table>::PrimaryKey as diesel::Expression>::SqlType,
+            >,
+        NestedAllColumns<T>: TupleEqAllExcept<EqAllExcept: FlattenNestedTuple>,
+        (ReparentAssignment<T>, ReparentRecord<T>): Insertable<T>,
+        InsertStatement<T, <(ReparentAssignment<T>, ReparentRecord<T>) as Insertable<T>>::Values>:
+            ExecuteDsl<Conn>,
+        T: FindDsl<PrimaryKeyValue<T>, Output: IntoUpdateTarget<Table = T>>,
+        DeleteStatement<
+            T,
+            <<T as FindDsl<PrimaryKeyValue<T>>>::Output as IntoUpdateTarget>::WhereClause,
+        >: ExecuteDsl<Conn>,
+    {
+        fn copy_record(
+            old_id: &PrimaryKeyValue<T>,
+            new_id: &PrimaryKeyValue<T>,
+            conn: &mut Conn,
+        ) -> QueryResult<usize> {
+            let record = <T::NestedPrimaryKeyColumns as LoadFirst<Conn>>::load_first(
+                (old_id.clone(),),
+                conn,
+            )?;
+            let primary_key: T::PrimaryKey = Default::default();
+            let columns = NestedAllColumns::<T>::default();
+            let values = columns
+                .eq_all_except(record.get_nested_columns(), |name| {
+                    <(T::PrimaryKey,) as NestedColumns>::column_position(name).is_some()
+                })
+                .flatten();
+            diesel::insert_into(T::default())
+                .values((primary_key.eq(new_id.clone()), values))
+                .execute(conn)
+        }
+
+        fn delete_record(old_id: &PrimaryKeyValue<T>, conn: &mut Conn) -> QueryResult<usize> {
+            diesel::delete(FindDsl::find(T::default(), old_id.clone())).execute(conn)
+        }
+    }
+
+    /// Marker closing the ancestor lists, so that every table of a list is
+    /// followed by a tail.
+    pub enum End {}
+
+    /// Walks two root-first nested ancestor lists, each closed by [`End`], in
+    /// lockstep, returning the last table shared by both, or `Candidate` if
+    /// their heads differ.
+    pub trait NestedCommonPrefix<Other, Candidate> {
+        /// The last table shared by the ancestor lists.
+        type Last;
+    }
+
+    impl<Other, Candidate> NestedCommonPrefix<Other, Candidate> for (End,) {
+        type Last = Candidate;
+    }
+
+    impl<Head, Tail, Candidate> NestedCommonPrefix<(End,), Candidate> for (Head, Tail) {
+        type Last = Candidate;
+    }
+
+    impl<Head, Tail, OtherHead, OtherTail, Candidate>
+        NestedCommonPrefix<(OtherHead, OtherTail), Candidate> for (Head, Tail)
+    where
+        Head: AppearsInFromClause<OtherHead, Count: Walk<Tail, OtherTail, Head, Candidate>>,
+    {
+        type Last = <Head::Count as Walk<Tail, OtherTail, Head, Candidate>>::Output;
+    }
+
+    /// Continues the walk of the `Tail` ancestor lists when the heads are the
+    /// same table, i.e. for [`Once`], and stops at `Candidate` otherwise.
+    pub trait Walk<Tail, OtherTail, Head, Candidate> {
+        /// The last table shared by the ancestor lists.
+        type Output;
+    }
+
+    impl<Tail, OtherTail, Head, Candidate> Walk<Tail, OtherTail, Head, Candidate> for Once
+    where
+        Tail: NestedCommonPrefix<OtherTail, Head>,
+    {
+        type Output = Tail::Last;
+    }
+
+    impl<Tail, OtherTail, Head, Candidate> Walk<Tail, OtherTail, Head, Candidate> for Never {
+        type Output = Candidate;
+    }
+
+    /// The query `Q` locking the rows it selects until the end of the current
+    /// transaction, as in `SELECT ... FOR UPDATE`.
+    ///
+    /// Diesel's own `QueryDsl::for_update` requires the query to implement
+    /// `LockingDsl<ForUpdate>`, where the lock type `ForUpdate` lives in the
+    /// `pub(crate)` module `diesel::query_builder::locking_clause`. Generic
+    /// code can therefore not state that bound, and the public helper type
+    /// `diesel::dsl::ForUpdate<Q>` is a projection through that same bound,
+    /// so it cannot prove it either. Diesel only implements `LockingDsl` for
+    /// its select statements, whose types are not public either, so the
+    /// locking clause is appended here instead, writing the same SQL as
+    /// Diesel's implementation for Postgres.
+    #[cfg(feature = "postgres")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct ForUpdate<Q>(pub(super) Q);
+
+    #[cfg(feature = "postgres")]
+    impl<Q: diesel::query_builder::Query> diesel::query_builder::Query for ForUpdate<Q> {
+        type SqlType = Q::SqlType;
+    }
+
+    #[cfg(feature = "postgres")]
+    impl<Q: diesel::query_builder::QueryId> diesel::query_builder::QueryId for ForUpdate<Q> {
+        type QueryId = ForUpdate<Q::QueryId>;
+        const HAS_STATIC_QUERY_ID: bool = Q::HAS_STATIC_QUERY_ID;
+    }
+
+    #[cfg(feature = "postgres")]
+    impl<Q> diesel::query_builder::QueryFragment<diesel::pg::Pg> for ForUpdate<Q>
+    where
+        Q: diesel::query_builder::QueryFragment<diesel::pg::Pg>,
+    {
+        fn walk_ast<'b>(
+            &'b self,
+            mut out: diesel::query_builder::AstPass<'_, 'b, diesel::pg::Pg>,
+        ) -> QueryResult<()> {
+            self.0.walk_ast(out.reborrow())?;
+            out.push_sql(" FOR UPDATE");
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    impl<Q, Conn> RunQueryDsl<Conn> for ForUpdate<Q> {}
+}
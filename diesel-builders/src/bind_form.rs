@@ -0,0 +1,175 @@
+//! Submodule providing the `TryBindForm` trait, binding string-keyed form
+//! values to the columns of a builder.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    AncestorOfIndex, BuildableTable, DescendantWithSelf, NestedTables, TableBuilder,
+    builder_error::DynamicColumnError,
+};
+
+/// Trait attempting to bind a form of string values to the columns of a
+/// builder, which may fail.
+pub trait TryBindForm: Sized {
+    /// Attempt to parse and set each value of the form into the column
+    /// sharing its key.
+    ///
+    /// Values are parsed via [`FromStr`](core::str::FromStr) into the value
+    /// type of the column and are then validated as with any other setter. The
+    /// builder is left unchanged if any of the values cannot be bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `form` - The form values, keyed by column name.
+    ///
+    /// # Errors
+    ///
+    /// * [`DynamicColumnError::UnknownField`] if a key matches no column.
+    /// * [`DynamicColumnError::Parse`] if a value cannot be parsed.
+    /// * [`DynamicColumnError::Validation`] if a parsed value is invalid.
+    fn try_bind_form_ref(
+        &mut self,
+        form: &HashMap<String, String>,
+    ) -> Result<&mut Self, DynamicColumnError>;
+
+    /// Attempt to parse and set each value of the form into the column
+    /// sharing its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `form` - The form values, keyed by column name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the values cannot be bound, as described in
+    /// [`TryBindForm::try_bind_form_ref`].
+    fn try_bind_form(mut self, form: &HashMap<String, String>) -> Result<Self, DynamicColumnError> {
+        self.try_bind_form_ref(form)?;
+        Ok(self)
+    }
+}
+
+impl<T> TryBindForm for TableBuilder<T>
+where
+    Self: Clone
+        + sealed::VariadicTryBindForm<
+            <<T as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >,
+    T: AncestorOfIndex<T> + BuildableTable,
+{
+    #[inline]
+    fn try_bind_form_ref(
+        &mut self,
+        form: &HashMap<String, String>,
+    ) -> Result<&mut Self, DynamicColumnError> {
+        use sealed::VariadicTryBindForm;
+        let mut bound = self.clone();
+        let mut known = HashSet::new();
+        bound.variadic_try_bind_form(form, &mut known)?;
+        if let Some(unknown) = form.keys().find(|key| !known.contains(key.as_str())) {
+            return Err(DynamicColumnError::UnknownField(unknown.clone()));
+        }
+        *self = bound;
+        Ok(self)
+    }
+}
+
+/// Binds the values of a form, keyed by column name, to the given builder.
+///
+/// # Arguments
+///
+/// * `builder` - The builder to bind the form to.
+/// * `form` - The form values, keyed by column name.
+///
+/// # Errors
+///
+/// Returns an error if any of the values cannot be bound, as described in
+/// [`TryBindForm::try_bind_form_ref`].
+pub fn bind_form<B: TryBindForm>(
+    builder: &mut B,
+    form: &HashMap<String, String>,
+) -> Result<(), DynamicColumnError> {
+    builder.try_bind_form_ref(form)?;
+    Ok(())
+}
+
+/// Sealed module for private traits.
+mod sealed {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{NestedColumns, ParseColumnValue, TrySetColumn, builder_error::DynamicColumnError};
+
+    /// Trait binding form values to a nested tuple of columns.
+    pub trait VariadicTryBindForm<Columns: NestedColumns> {
+        /// Parse and set the form values matching the columns, recording the
+        /// names of the matched columns into `known`.
+        fn variadic_try_bind_form(
+            &mut self,
+            form: &HashMap<String, String>,
+            known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError>;
+    }
+
+    impl<M> VariadicTryBindForm<()> for M {
+        #[inline]
+        fn variadic_try_bind_form(
+            &mut self,
+            _form: &HashMap<String, String>,
+            _known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError> {
+            Ok(())
+        }
+    }
+
+    /// Parses and sets the form value associated with the column `C`, if any.
+    fn try_bind_column<M, C>(
+        builder: &mut M,
+        form: &HashMap<String, String>,
+        known: &mut HashSet<&'static str>,
+    ) -> Result<(), DynamicColumnError>
+    where
+        M: TrySetColumn<C>,
+        C: ParseColumnValue,
+    {
+        let Some(raw) = form.get(C::NAME) else {
+            return Ok(());
+        };
+        known.insert(C::NAME);
+        let value = C::parse_value(raw)?;
+        builder.try_set_column(value).map_err(|e| DynamicColumnError::Validation(Box::new(e)))?;
+        Ok(())
+    }
+
+    impl<M, CHead> VariadicTryBindForm<(CHead,)> for M
+    where
+        M: TrySetColumn<CHead>,
+        CHead: ParseColumnValue,
+    {
+        #[inline]
+        fn variadic_try_bind_form(
+            &mut self,
+            form: &HashMap<String, String>,
+            known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError> {
+            try_bind_column::<M, CHead>(self, form, known)
+        }
+    }
+
+    impl<M, CHead, CTail> VariadicTryBindForm<(CHead, CTail)> for M
+    where
+        M: TrySetColumn<CHead> + VariadicTryBindForm<CTail>,
+        CHead: ParseColumnValue,
+        CTail: NestedColumns,
+        (CHead, CTail): NestedColumns,
+    {
+        #[inline]
+        fn variadic_try_bind_form(
+            &mut self,
+            form: &HashMap<String, String>,
+            known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError> {
+            try_bind_column::<M, CHead>(self, form, known)?;
+            <Self as VariadicTryBindForm<CTail>>::variadic_try_bind_form(self, form, known)
+        }
+    }
+}
@@ -6,19 +6,23 @@ use diesel::{Column, associations::HasTable};
 
 mod completed_table_builder_bundle;
 mod serde;
-pub use completed_table_builder_bundle::{CompletedTableBuilderBundle, RecursiveBundleInsert};
+pub use completed_table_builder_bundle::{
+    CompletedTableBuilderBundle, NestedInsertLevels, RecursiveBundleInsert,
+    RecursiveBundleInsertWithId,
+};
 use tuplities::prelude::*;
 
 use crate::{
-    BuildableTable, ColumnTyped, Columns, DiscretionarySameAsIndex, HasNestedTables,
-    HorizontalNestedKeys, MandatorySameAsIndex, MayGetColumn, NestedBuildableTables,
-    NestedTableModels, NestedTables, OptionalRef, SetColumn, SetDiscretionaryBuilder,
-    SetDiscretionarySameAsNestedColumns, SetMandatoryBuilder, SetMandatorySameAsNestedColumns,
-    TableBuilder, TableExt, TrySetColumn, TrySetDiscretionaryBuilder,
-    TrySetDiscretionarySameAsColumn, TrySetDiscretionarySameAsNestedColumns,
-    TrySetMandatoryBuilder, TrySetMandatorySameAsColumn, TrySetMandatorySameAsNestedColumns,
-    TupleGetNestedColumns, TupleMayGetNestedColumns, TypedColumn, TypedNestedTuple, ValidateColumn,
-    columns::NestedColumns, horizontal_same_as_group::HorizontalSameAsGroupExt,
+    BuildableTable, ColumnTyped, Columns, DiscretionarySameAsIndex, HasMandatoryBuilder,
+    HasNestedTables, HorizontalNestedKeys, MandatorySameAsIndex, MayGetColumn,
+    NestedBuildableTables, NestedTableModels, NestedTables, OptionalRef, SetColumn,
+    SetDiscretionaryBuilder, SetDiscretionarySameAsNestedColumns, SetMandatoryBuilder,
+    SetMandatorySameAsNestedColumns, TableBuilder, TableExt, TrySetColumn,
+    TrySetDiscretionaryBuilder, TrySetDiscretionarySameAsColumn,
+    TrySetDiscretionarySameAsNestedColumns, TrySetMandatoryBuilder, TrySetMandatorySameAsColumn,
+    TrySetMandatorySameAsNestedColumns, TupleGetNestedColumns, TupleMayGetNestedColumns,
+    TypedColumn, TypedNestedTuple, ValidateColumn, columns::NestedColumns,
+    horizontal_same_as_group::HorizontalSameAsGroupExt, table_builder::FillMissingNestedOptions,
     tables::NonCompositePrimaryKeyNestedTables,
 };
 
@@ -39,9 +43,8 @@ pub trait BundlableTableExt:
     type CompletedNewValues: FlattenNestedTuple
         + IntoNestedTupleOption<IntoOptions = Self::NewValues>;
     /// Nested mandatory triangular same-as columns.
-    type NestedMandatoryTriangularColumns: NestedColumns<
-        NestedTupleColumnType = Self::NestedMandatoryPrimaryKeyTypes,
-    >;
+    type NestedMandatoryTriangularColumns: HorizontalNestedKeys<Self>
+        + NestedColumns<NestedTupleColumnType = Self::NestedMandatoryPrimaryKeyTypes>;
     /// Nested mandatory tables.
     type NestedMandatoryTables: NonCompositePrimaryKeyNestedTables<
             NestedPrimaryKeyColumns = Self::NestedMandatoryPrimaryKeys,
@@ -78,8 +81,8 @@ pub trait BundlableTableExt:
         + HasNestedTables<NestedTables = Self::NestedMandatoryTables>;
     /// Optional builders for the mandatory associated tables.
     type OptionalMandatoryNestedBuilders: NestedTupleOptionWith<
-            &'static str,
-            SameDepth = <Self::NestedMandatoryTriangularColumns as NestedColumns>::NestedNames,
+            (&'static str, &'static str),
+            SameDepth = <Self::NestedMandatoryTriangularColumns as HorizontalNestedKeys<Self>>::NestedReferencedNames,
             Transposed = Self::MandatoryNestedBuilders,
         > + HasNestedTables<NestedTables = Self::NestedMandatoryTables>;
     /// Builders for the discretionary associated tables.
@@ -141,8 +144,51 @@ where
         <Self::NestedDiscretionaryModels as IntoNestedTupleOption>::IntoOptions;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Trait for nested tuples of optional values, such as the associated
+/// builders or the new values of a bundle, reporting which of them are set.
+///
+/// `Names` is the nested tuple of the names of the columns the values are
+/// associated to.
+pub trait NestedBuilderPresence<Names> {
+    /// Appends to `presence` the name of each associated column alongside
+    /// whether its value is set.
+    fn push_presence(&self, names: Names, presence: &mut Vec<(&'static str, bool)>);
+}
+
+impl NestedBuilderPresence<()> for () {
+    #[inline]
+    fn push_presence(&self, _names: (), _presence: &mut Vec<(&'static str, bool)>) {}
+}
+
+impl<B> NestedBuilderPresence<(&'static str,)> for (Option<B>,) {
+    #[inline]
+    fn push_presence(&self, names: (&'static str,), presence: &mut Vec<(&'static str, bool)>) {
+        presence.push((names.0, self.0.is_some()));
+    }
+}
+
+impl<Head, Tail, TailNames> NestedBuilderPresence<(&'static str, TailNames)>
+    for (Option<Head>, Tail)
+where
+    Tail: NestedBuilderPresence<TailNames>,
+{
+    #[inline]
+    fn push_presence(
+        &self,
+        names: (&'static str, TailNames),
+        presence: &mut Vec<(&'static str, bool)>,
+    ) {
+        presence.push((names.0, self.0.is_some()));
+        self.1.push_presence(names.1, presence);
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(not(feature = "pretty-debug"), derive(Debug))]
 /// A bundle of a table's insertable model and its associated builders.
+///
+/// With the `pretty-debug` feature, its `Debug` output lists the columns of
+/// the table by name.
 pub struct TableBuilderBundle<T: BundlableTableExt> {
     /// The insertable model for the table.
     insertable_model: T::NewValues,
@@ -167,6 +213,69 @@ where
     }
 }
 
+impl<T: BundlableTableExt> TableBuilderBundle<T> {
+    /// Returns a reference to the values of the table columns, where `None`
+    /// marks a column which is not set.
+    #[must_use]
+    pub fn new_values(&self) -> &T::NewValues {
+        &self.insertable_model
+    }
+
+    /// Consumes the bundle and returns the values of the table columns,
+    /// where `None` marks a column which is not set.
+    ///
+    /// The associated builders of the bundle are discarded.
+    #[must_use]
+    pub fn into_new_values(self) -> T::NewValues {
+        self.insertable_model
+    }
+
+    /// Takes the values of the table columns, leaving the defaults in their
+    /// place.
+    pub(crate) fn take_new_values(&mut self) -> T::NewValues {
+        core::mem::replace(&mut self.insertable_model, T::default_new_values())
+    }
+}
+
+impl<T> TableBuilderBundle<T>
+where
+    T: BundlableTableExt,
+    T::OptionalMandatoryNestedBuilders:
+        NestedBuilderPresence<<T::NestedMandatoryTriangularColumns as NestedColumns>::NestedNames>,
+    T::OptionalDiscretionaryNestedBuilders: NestedBuilderPresence<
+        <T::NestedDiscretionaryTriangularColumns as NestedColumns>::NestedNames,
+    >,
+{
+    /// Returns the name of each mandatory and then discretionary triangular
+    /// column alongside whether the bundle holds a builder for it.
+    ///
+    /// A relation whose column was set directly, for instance from an
+    /// existing model, is reported as having no builder.
+    #[must_use]
+    pub fn mandatory_builders_set(&self) -> Vec<(&'static str, bool)> {
+        let mut presence = Vec::new();
+        self.nested_mandatory_associated_builders
+            .push_presence(T::NestedMandatoryTriangularColumns::NESTED_COLUMN_NAMES, &mut presence);
+        self.nested_discretionary_associated_builders.push_presence(
+            T::NestedDiscretionaryTriangularColumns::NESTED_COLUMN_NAMES,
+            &mut presence,
+        );
+        presence
+    }
+}
+
+impl<T> TableBuilderBundle<T>
+where
+    T: BundlableTableExt<NewValues: FillMissingNestedOptions>,
+{
+    /// Fills every unset column of the bundle with its default value, leaving
+    /// the columns which are already set untouched.
+    pub fn apply_defaults(&mut self) -> &mut Self {
+        self.insertable_model.fill_missing_nested_options(T::default_new_values());
+        self
+    }
+}
+
 impl<T> HasTable for TableBuilderBundle<T>
 where
     T: BundlableTableExt,
@@ -326,6 +435,22 @@ where
     }
 }
 
+impl<C, T> HasMandatoryBuilder<C> for TableBuilderBundle<T>
+where
+    T: BundlableTableExt,
+    C: MandatorySameAsIndex,
+    C::ReferencedTable: BuildableTable,
+    T::OptionalMandatoryNestedBuilders: NestedTupleIndex<
+            <C as MandatorySameAsIndex>::Idx,
+            Element = Option<TableBuilder<C::ReferencedTable>>,
+        >,
+{
+    #[inline]
+    fn has_mandatory_builder(&self) -> bool {
+        self.nested_mandatory_associated_builders.nested_index().is_some()
+    }
+}
+
 impl<Key> TrySetMandatoryBuilder<Key> for TableBuilderBundle<Key::Table>
 where
     Key::Table: BundlableTableExt,
@@ -1,11 +1,15 @@
 //! Submodule for the completed table builder bundle and related impls.
 
-use diesel::{Column, Insertable, RunQueryDsl, associations::HasTable};
+use diesel::{
+    Column, Expression, ExpressionMethods, Insertable, RunQueryDsl, Table, associations::HasTable,
+    expression::AsExpression,
+};
 use tuplities::prelude::*;
 
 use crate::{
-    BuildableTable, BuilderError, BuilderResult, DiscretionarySameAsIndex, HasNestedTables,
-    HasTableExt, IncompleteBuilderError, MandatorySameAsIndex, NestedColumns, NestedTables,
+    BuildableTable, BuilderError, BuilderResult, ColumnTyped, DiscretionarySameAsIndex,
+    HasNestedTables, HasPrimaryKeyColumn, HasTableExt, IncompleteBuilderError,
+    MandatorySameAsIndex, MayGetColumn, NestedBuilderPresence, NestedColumns, NestedTables,
     OptionalRef, RecursiveBuilderInsert, TableBuilder, TableBuilderBundle, TableExt,
     TryMaySetNestedColumns, TrySetColumn, TrySetDiscretionarySameAsColumn,
     TrySetDiscretionarySameAsNestedColumns, TrySetMandatorySameAsColumn,
@@ -38,6 +42,18 @@ where
     }
 }
 
+impl<T, C> MayGetColumn<C> for CompletedTableBuilderBundle<T>
+where
+    T: BundlableTableExt,
+    C: ColumnTyped,
+    T::NewValues: MayGetColumn<C>,
+{
+    #[inline]
+    fn may_get_column_ref(&self) -> Option<&C::ColumnType> {
+        self.insertable_model.may_get_column_ref()
+    }
+}
+
 impl<T, C> ValidateColumn<C> for CompletedTableBuilderBundle<T>
 where
     T: BundlableTableExt,
@@ -142,11 +158,12 @@ where
             insertable_model: value.insertable_model,
             nested_mandatory_associated_builders: value
                 .nested_mandatory_associated_builders
-                .transpose_or(T::NestedMandatoryTriangularColumns::NESTED_COLUMN_NAMES)
-                .map_err(|column_name| {
+                .transpose_or(T::NestedMandatoryTriangularColumns::NESTED_REFERENCED_NAMES)
+                .map_err(|(column_name, referenced_table_name)| {
                     IncompleteBuilderError::MissingMandatoryTriangularField {
                         table_name: T::TABLE_NAME,
                         field_name: column_name,
+                        referenced_table_name,
                     }
                 })?,
             nested_discretionary_associated_builders: value
@@ -174,6 +191,127 @@ pub trait RecursiveBundleInsert<Error, Conn>: HasTableExt {
     ) -> BuilderResult<<<Self as HasTable>::Table as TableExt>::Model, Error>;
 }
 
+impl<T: BundlableTableExt> CompletedTableBuilderBundle<T> {
+    /// Returns the mandatory associated builder of the `Key` relationship,
+    /// which a completed bundle is guaranteed to hold.
+    #[must_use]
+    pub fn mandatory_builder<Key>(&self) -> &TableBuilder<Key::ReferencedTable>
+    where
+        Key: MandatorySameAsIndex<Table = T, ReferencedTable: BuildableTable>,
+        T::MandatoryNestedBuilders:
+            NestedTupleIndex<Key::Idx, Element = TableBuilder<Key::ReferencedTable>>,
+    {
+        self.nested_mandatory_associated_builders.nested_index()
+    }
+
+    /// Consumes the bundle and returns it to its editable form, undoing the
+    /// conversion from a [`TableBuilderBundle`].
+    ///
+    /// The values of the new record and the associated builders are kept, so
+    /// converting the returned bundle again yields an equivalent bundle.
+    #[must_use]
+    pub fn into_incomplete(self) -> TableBuilderBundle<T>
+    where
+        T::MandatoryNestedBuilders: NestedTupleInto<T::OptionalMandatoryNestedBuilders>,
+    {
+        TableBuilderBundle {
+            insertable_model: self.insertable_model,
+            nested_mandatory_associated_builders: self
+                .nested_mandatory_associated_builders
+                .nested_tuple_into(),
+            nested_discretionary_associated_builders: self.nested_discretionary_associated_builders,
+        }
+    }
+
+    /// Consumes the bundle and returns its new record as a plain Diesel
+    /// [`Insertable`], to be handed to `diesel::insert_into` outside of the
+    /// builders.
+    ///
+    /// The values managed by the table, such as timestamps, are set as they
+    /// would be right before an insertion, while the associated builders of
+    /// the bundle are discarded: the columns they would set on insertion must
+    /// therefore already be set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncompleteBuilderError::MissingMandatoryField`] if a column of
+    /// the new record is not set, or
+    /// [`IncompleteBuilderError::MissingMandatoryFields`] listing every unset
+    /// column if several are.
+    pub fn into_insertable(mut self) -> Result<impl Insertable<T>, IncompleteBuilderError>
+    where
+        T::NewRecord: TupleEqAll<EqAll: FlattenNestedTuple<Flattened: Insertable<T>>>
+            + TypedNestedTuple<NestedTupleColumnType = T::CompletedNewValues>,
+    {
+        T::set_managed_values(&mut self.insertable_model);
+        let missing_fields = missing_mandatory_fields::<T>(&self.insertable_model);
+        let values: T::CompletedNewValues = self
+            .insertable_model
+            .transpose_or(T::NewRecord::NESTED_COLUMN_NAMES)
+            .map_err(|_| missing_fields)?;
+        Ok(T::NewRecord::default().eq_all(values).flatten())
+    }
+
+    /// Inserts the associated builders of the bundle, sets their primary keys
+    /// and the managed values in the new record, and returns the completed
+    /// values of the new record, ready to be inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the database connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insertion of an associated builder fails or
+    /// if any mandatory column of the new record is missing.
+    fn into_completed_new_values<Error, Conn>(
+        mut self,
+        conn: &mut Conn,
+    ) -> BuilderResult<T::CompletedNewValues, Error>
+    where
+        T::NewValues: TrySetNestedColumns<Error, T::NestedMandatoryTriangularColumns>
+            + TryMaySetNestedColumns<Error, T::NestedDiscretionaryTriangularColumns>,
+        T::MandatoryNestedBuilders: InsertTuple<Error, Conn>,
+        T::OptionalDiscretionaryNestedBuilders: InsertOptionTuple<Error, Conn>,
+        T::NewRecord: TypedNestedTuple<NestedTupleColumnType = T::CompletedNewValues>,
+    {
+        let mandatory_models: T::NestedMandatoryModels =
+            self.nested_mandatory_associated_builders.insert_tuple(conn)?;
+        let mandatory_primary_keys: T::NestedMandatoryPrimaryKeyTypes =
+            mandatory_models.tuple_get_nested_columns();
+        self.insertable_model
+            .try_set_nested_columns(mandatory_primary_keys)
+            .map_err(BuilderError::Validation)?;
+        let discretionary_models: T::OptionalNestedDiscretionaryModels =
+            self.nested_discretionary_associated_builders.insert_option_tuple(conn)?;
+        let discretionary_primary_keys: T::OptionalNestedDiscretionaryPrimaryKeyTypes =
+            discretionary_models.tuple_may_get_nested_columns();
+        self.insertable_model
+            .try_may_set_nested_columns(discretionary_primary_keys)
+            .map_err(BuilderError::Validation)?;
+        T::set_managed_values(&mut self.insertable_model);
+
+        let missing_fields = missing_mandatory_fields::<T>(&self.insertable_model);
+        self.insertable_model
+            .transpose_or(T::NewRecord::NESTED_COLUMN_NAMES)
+            .map_err(|_| BuilderError::Incomplete(missing_fields))
+    }
+}
+
+/// Returns the error reporting every unset column of the new values of a
+/// record of `T`.
+fn missing_mandatory_fields<T: TableExt>(new_values: &T::NewValues) -> IncompleteBuilderError {
+    let mut presence = Vec::new();
+    new_values.push_presence(T::NewRecord::NESTED_COLUMN_NAMES, &mut presence);
+    IncompleteBuilderError::missing_mandatory_fields(
+        T::TABLE_NAME,
+        presence
+            .into_iter()
+            .filter_map(|(column_name, set)| (!set).then_some(column_name))
+            .collect(),
+    )
+}
+
 impl<T, Error, Conn> RecursiveBundleInsert<Error, Conn> for CompletedTableBuilderBundle<T>
 where
     Conn: diesel::connection::LoadConnection,
@@ -189,36 +327,11 @@ where
     >: for<'query> diesel::query_dsl::LoadQuery<'query, Conn, <Self::Table as TableExt>::Model>,
 {
     fn recursive_bundle_insert(
-        mut self,
+        self,
         conn: &mut Conn,
     ) -> BuilderResult<<T as TableExt>::Model, Error> {
-        let mandatory_models: T::NestedMandatoryModels = self
-            .nested_mandatory_associated_builders
-            .insert_tuple(conn)?;
-        let mandatory_primary_keys: T::NestedMandatoryPrimaryKeyTypes =
-            mandatory_models.tuple_get_nested_columns();
-        self.insertable_model
-            .try_set_nested_columns(mandatory_primary_keys)
-            .map_err(BuilderError::Validation)?;
-        let discretionary_models: T::OptionalNestedDiscretionaryModels = self
-            .nested_discretionary_associated_builders
-            .insert_option_tuple(conn)?;
-        let discretionary_primary_keys: T::OptionalNestedDiscretionaryPrimaryKeyTypes =
-            discretionary_models.tuple_may_get_nested_columns();
-        self.insertable_model
-            .try_may_set_nested_columns(discretionary_primary_keys)
-            .map_err(BuilderError::Validation)?;
-
         let columns = T::NewRecord::default();
-        let values: T::CompletedNewValues = self
-            .insertable_model
-            .transpose_or(T::NewRecord::NESTED_COLUMN_NAMES)
-            .map_err(|column_name| {
-                BuilderError::Incomplete(IncompleteBuilderError::MissingMandatoryField {
-                    table_name: T::TABLE_NAME,
-                    field_name: column_name,
-                })
-            })?;
+        let values: T::CompletedNewValues = self.into_completed_new_values(conn)?;
 
         Ok(diesel::insert_into(T::default())
             .values(columns.eq_all(values).flatten())
@@ -226,6 +339,74 @@ where
     }
 }
 
+/// The value of the single-column primary key of the table `T`.
+type PrimaryKeyValue<T> = <<T as Table>::PrimaryKey as ColumnTyped>::ColumnType;
+
+/// The flattened assignments of the new record of the table `T`.
+type FlattenedNewRecord<T> =
+    <<<T as TableExt>::NewRecord as TupleEqAll>::EqAll as FlattenNestedTuple>::Flattened;
+
+/// Trait defining the insertion of a builder into the database with a
+/// caller-provided primary key.
+pub trait RecursiveBundleInsertWithId<Error, Conn>:
+    RecursiveBundleInsert<Error, Conn, Table: HasPrimaryKeyColumn>
+{
+    /// Insert the builder's data into the database using the provided
+    /// connection, including the provided value of the primary key in the
+    /// inserted record instead of letting the database generate it.
+    ///
+    /// The primary key must not be part of the new record of the table,
+    /// as is the case for tables with a `surrogate_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The primary key of the record to insert.
+    /// * `conn` - A mutable reference to the database connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insertion fails or if any database constraints
+    /// are violated, such as a record with the same primary key already
+    /// existing.
+    fn recursive_bundle_insert_with_id(
+        self,
+        id: PrimaryKeyValue<Self::Table>,
+        conn: &mut Conn,
+    ) -> BuilderResult<<<Self as HasTable>::Table as TableExt>::Model, Error>;
+}
+
+impl<T, Error, Conn> RecursiveBundleInsertWithId<Error, Conn> for CompletedTableBuilderBundle<T>
+where
+    Conn: diesel::connection::LoadConnection,
+    T: BundlableTableExt + HasPrimaryKeyColumn,
+    T::PrimaryKey: ExpressionMethods + Default,
+    PrimaryKeyValue<T>: AsExpression<<T::PrimaryKey as Expression>::SqlType>,
+    Self: RecursiveBundleInsert<Error, Conn, Table = T>,
+    T::NewValues: TrySetNestedColumns<Error, T::NestedMandatoryTriangularColumns>
+        + TryMaySetNestedColumns<Error, T::NestedDiscretionaryTriangularColumns>,
+    T::MandatoryNestedBuilders: InsertTuple<Error, Conn>,
+    T::OptionalDiscretionaryNestedBuilders: InsertOptionTuple<Error, Conn>,
+    T::NewRecord: TupleEqAll<EqAll: FlattenNestedTuple> + TypedNestedTuple<NestedTupleColumnType=T::CompletedNewValues>,
+    (diesel::dsl::Eq<T::PrimaryKey, PrimaryKeyValue<T>>, FlattenedNewRecord<T>): Insertable<T>,
+    diesel::query_builder::InsertStatement<
+        T,
+        <(diesel::dsl::Eq<T::PrimaryKey, PrimaryKeyValue<T>>, FlattenedNewRecord<T>) as Insertable<T>>::Values,
+    >: for<'query> diesel::query_dsl::LoadQuery<'query, Conn, T::Model>,
+{
+    fn recursive_bundle_insert_with_id(
+        self,
+        id: PrimaryKeyValue<T>,
+        conn: &mut Conn,
+    ) -> BuilderResult<T::Model, Error> {
+        let columns = T::NewRecord::default();
+        let values: T::CompletedNewValues = self.into_completed_new_values(conn)?;
+
+        Ok(diesel::insert_into(T::default())
+            .values((T::PrimaryKey::default().eq(id), columns.eq_all(values).flatten()))
+            .get_result(conn)?)
+    }
+}
+
 /// Trait defining the insertion of a tuple of builders into the database.
 trait InsertTuple<Error, Conn>: HasNestedTables {
     /// Insert the tuple of builders' data into the database using the provided
@@ -364,3 +545,58 @@ where
         ))
     }
 }
+
+/// Trait for nested tuples of tables of a hierarchy, grouping them by
+/// [`Descendant::INSERT_LEVEL`](crate::Descendant::INSERT_LEVEL).
+///
+/// The bundles of a builder are inserted one table at a time following the
+/// order of the ancestors, and each table only references tables of lower
+/// levels: the tables of a level may thus, in principle, be inserted
+/// concurrently once the previous levels are inserted.
+pub trait NestedInsertLevels {
+    /// Appends the names of the tables to the levels they belong to, adding
+    /// the missing levels and preserving the order of the tables within each
+    /// level.
+    fn push_insert_levels(levels: &mut Vec<Vec<&'static str>>);
+
+    /// Returns the names of the tables grouped by insert level, from the
+    /// root level onwards.
+    #[must_use]
+    fn insert_levels() -> Vec<Vec<&'static str>> {
+        let mut levels = Vec::new();
+        Self::push_insert_levels(&mut levels);
+        levels
+    }
+}
+
+impl NestedInsertLevels for () {
+    #[inline]
+    fn push_insert_levels(_levels: &mut Vec<Vec<&'static str>>) {}
+}
+
+impl<T> NestedInsertLevels for (T,)
+where
+    T: crate::Descendant,
+{
+    #[inline]
+    fn push_insert_levels(levels: &mut Vec<Vec<&'static str>>) {
+        if levels.len() <= T::INSERT_LEVEL {
+            levels.resize_with(T::INSERT_LEVEL + 1, Vec::new);
+        }
+        if let Some(level) = levels.get_mut(T::INSERT_LEVEL) {
+            level.push(T::TABLE_NAME);
+        }
+    }
+}
+
+impl<Head, Tail> NestedInsertLevels for (Head, Tail)
+where
+    Head: crate::Descendant,
+    Tail: NestedInsertLevels,
+{
+    #[inline]
+    fn push_insert_levels(levels: &mut Vec<Vec<&'static str>>) {
+        <(Head,)>::push_insert_levels(levels);
+        Tail::push_insert_levels(levels);
+    }
+}
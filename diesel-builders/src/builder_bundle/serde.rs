@@ -68,3 +68,32 @@ where
         })
     }
 }
+
+#[cfg(feature = "postcard")]
+impl<T: BundlableTableExt> TableBuilderBundle<T> {
+    /// Serializes the bundle, including its nested associated builders, into
+    /// the compact binary `postcard` format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any value held by the bundle fails to serialize.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, postcard::Error>
+    where
+        Self: serde::Serialize,
+    {
+        postcard::to_allocvec(self)
+    }
+
+    /// Deserializes a bundle from the bytes produced by
+    /// [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes do not encode a bundle of this table.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, postcard::Error>
+    where
+        Self: serde::de::DeserializeOwned,
+    {
+        postcard::from_bytes(bytes)
+    }
+}
@@ -9,15 +9,61 @@ pub enum BuilderError<E> {
     Diesel(diesel::result::Error),
     /// Missing mandatory triangular builder fields.
     Incomplete(IncompleteBuilderError),
+    /// The record of a linked foreign model does not exist.
+    ForeignModelMissing(ForeignModelMissing),
     /// Underlying validation error.
     Validation(E),
 }
 
+impl<E> BuilderError<E> {
+    /// Returns the underlying validation error, if this is a validation
+    /// failure.
+    #[must_use]
+    pub fn as_validation(&self) -> Option<&E> {
+        match self {
+            BuilderError::Validation(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying diesel error, if this is a database failure.
+    #[must_use]
+    pub fn as_database(&self) -> Option<&diesel::result::Error> {
+        match self {
+            BuilderError::Diesel(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the operation failed on a transient database error,
+    /// so that running it again, typically in a new transaction, may succeed.
+    ///
+    /// Only database errors of kind
+    /// [`SerializationFailure`](diesel::result::DatabaseErrorKind::SerializationFailure)
+    /// are considered retryable, which on Postgres covers the `40001`
+    /// serialization failures of `SERIALIZABLE` and `REPEATABLE READ`
+    /// transactions. Diesel has no dedicated kind for deadlocks, which it
+    /// reports as `Unknown` along with SQLite busy errors, so they are not
+    /// classified as retryable. Validation, incomplete builder and missing
+    /// foreign model errors are never retryable.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            BuilderError::Diesel(diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::SerializationFailure,
+                _
+            ))
+        )
+    }
+}
+
 impl<E: std::error::Error + 'static> std::fmt::Display for BuilderError<E> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BuilderError::Diesel(e) => write!(f, "Diesel error: {e}"),
             BuilderError::Incomplete(e) => write!(f, "{e}"),
+            BuilderError::ForeignModelMissing(e) => write!(f, "{e}"),
             BuilderError::Validation(e) => write!(f, "Validation error: {e}"),
         }
     }
@@ -28,6 +74,7 @@ impl<E: std::error::Error + 'static> std::error::Error for BuilderError<E> {
         match self {
             BuilderError::Diesel(e) => Some(e),
             BuilderError::Incomplete(e) => Some(e),
+            BuilderError::ForeignModelMissing(e) => Some(e),
             BuilderError::Validation(e) => Some(e),
         }
     }
@@ -45,17 +92,100 @@ impl<E> From<IncompleteBuilderError> for BuilderError<E> {
     }
 }
 
+impl<E> From<ForeignModelMissing> for BuilderError<E> {
+    fn from(error: ForeignModelMissing) -> Self {
+        BuilderError::ForeignModelMissing(error)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, thiserror::Error)]
+#[error("Column `{table_name}.{column_name}` exceeds {max} characters ({actual} provided)")]
+/// Specific error indicating that a text value holds more characters than
+/// allowed by a `#[table_model(max_chars = ...)]` attribute.
+pub struct MaxCharsExceeded {
+    /// The table of the column.
+    pub table_name: &'static str,
+    /// The name of the column.
+    pub column_name: &'static str,
+    /// The maximum number of characters allowed.
+    pub max: usize,
+    /// The number of characters of the rejected value.
+    pub actual: usize,
+}
+
+/// The bound of a `#[table_model(range(...))]` attribute violated by a value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RangeViolation {
+    /// The value is smaller than the lower bound, or equal to it when
+    /// `inclusive` is `false`.
+    Below {
+        /// Whether the bound belongs to the range.
+        inclusive: bool,
+    },
+    /// The value is greater than the upper bound, or equal to it when
+    /// `inclusive` is `false`.
+    Above {
+        /// Whether the bound belongs to the range.
+        inclusive: bool,
+    },
+}
+
+impl core::fmt::Display for RangeViolation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            RangeViolation::Below { inclusive: true } => "greater than or equal to",
+            RangeViolation::Below { inclusive: false } => "strictly greater than",
+            RangeViolation::Above { inclusive: true } => "smaller than or equal to",
+            RangeViolation::Above { inclusive: false } => "strictly smaller than",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, thiserror::Error)]
+#[error("Column `{table_name}.{column_name}` must be {violation} {bound}")]
+/// Specific error indicating that a value lies outside of the range allowed
+/// by a `#[table_model(range(...))]` attribute.
+pub struct OutOfRange {
+    /// The table of the column.
+    pub table_name: &'static str,
+    /// The name of the column.
+    pub column_name: &'static str,
+    /// The violated bound of the range.
+    pub violation: RangeViolation,
+    /// The violated bound, formatted with its `Display` implementation.
+    pub bound: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, thiserror::Error)]
+#[error(
+    "Mandatory triangular builder field `{table_name}.{field_name}` referencing `{referenced_table_name}` is already set"
+)]
+/// Specific error indicating that a mandatory associated builder was set on a
+/// builder already holding one for the same relation.
+pub struct MandatoryBuilderAlreadySet {
+    /// The table of the column.
+    pub table_name: &'static str,
+    /// The name of the column.
+    pub field_name: &'static str,
+    /// The table of the associated builder.
+    pub referenced_table_name: &'static str,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, thiserror::Error)]
 /// Specific error indicating that not all mandatory triangular builder fields
 /// have been set.
 pub enum IncompleteBuilderError {
-    #[error("Missing mandatory triangular builder field: `{table_name}.{field_name}`")]
+    #[error(
+        "Missing mandatory triangular builder field: `{table_name}.{field_name}` referencing `{referenced_table_name}`"
+    )]
     /// Not all mandatory associated builders have been set.
     MissingMandatoryTriangularField {
         /// The table of the missing column.
         table_name: &'static str,
         /// The name of the missing column.
         field_name: &'static str,
+        /// The table of the missing associated builder.
+        referenced_table_name: &'static str,
     },
     #[error("Missing mandatory field: `{table_name}.{field_name}`")]
     /// A field required for insertion is missing.
@@ -65,6 +195,79 @@ pub enum IncompleteBuilderError {
         /// The name of the missing field.
         field_name: &'static str,
     },
+    #[error("Missing mandatory fields of `{table_name}`: `{}`", .field_names.join("`, `"))]
+    /// Several fields required for insertion are missing.
+    MissingMandatoryFields {
+        /// The table of the missing columns.
+        table_name: &'static str,
+        /// The names of the missing fields, in the order of the columns.
+        field_names: Vec<&'static str>,
+    },
+}
+
+impl IncompleteBuilderError {
+    /// Returns the error reporting the missing fields of a new record,
+    /// [`MissingMandatoryField`](Self::MissingMandatoryField) when a single
+    /// field is missing.
+    pub(crate) fn missing_mandatory_fields(
+        table_name: &'static str,
+        mut field_names: Vec<&'static str>,
+    ) -> Self {
+        if field_names.len() == 1
+            && let Some(field_name) = field_names.pop()
+        {
+            IncompleteBuilderError::MissingMandatoryField { table_name, field_name }
+        } else {
+            IncompleteBuilderError::MissingMandatoryFields { table_name, field_names }
+        }
+    }
+
+    /// Returns the names of the missing columns reported by the error.
+    #[must_use]
+    pub fn missing_columns(&self) -> &[&'static str] {
+        match self {
+            IncompleteBuilderError::MissingMandatoryTriangularField { field_name, .. }
+            | IncompleteBuilderError::MissingMandatoryField { field_name, .. } => {
+                core::slice::from_ref(field_name)
+            }
+            IncompleteBuilderError::MissingMandatoryFields { field_names, .. } => field_names,
+        }
+    }
+
+    /// Returns the number of missing columns reported by the error.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.missing_columns().len()
+    }
+
+    /// Returns whether the error reports no missing column.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing_columns().is_empty()
+    }
+}
+
+impl IntoIterator for IncompleteBuilderError {
+    type Item = &'static str;
+    type IntoIter = std::vec::IntoIter<&'static str>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            IncompleteBuilderError::MissingMandatoryFields { field_names, .. } => {
+                field_names.into_iter()
+            }
+            error => error.missing_columns().to_vec().into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a IncompleteBuilderError {
+    type Item = &'static str;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, &'static str>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.missing_columns().iter().copied()
+    }
 }
 
 /// Specific error indicating that a dynamic setting operation
@@ -82,8 +285,170 @@ pub enum DynamicColumnError {
     #[error("Validation error: {0}")]
     /// Validation error when setting the column.
     Validation(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Unknown field: `{0}`")]
+    /// The provided key does not match any column of the builder.
+    UnknownField(String),
+    #[error("Expected an object keyed by column name")]
+    /// The provided value is not an object keyed by column name.
+    NotAnObject,
+    #[error("Cannot parse value of column `{table_name}.{column_name}`: {error}")]
+    /// The provided raw value cannot be parsed into the column value type.
+    Parse {
+        /// The table of the column.
+        table_name: &'static str,
+        /// The name of the column.
+        column_name: &'static str,
+        #[source]
+        /// The underlying parse error.
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Type mismatch for column `{column}`: expected `{expected}`, found `{actual}`")]
+    /// The provided value does not have the value type of the column.
+    TypeMismatch {
+        /// The name of the column.
+        column: &'static str,
+        /// The name of the value type of the column.
+        expected: &'static str,
+        /// The name of the type of the provided value.
+        actual: &'static str,
+    },
+}
+
+/// Specific error indicating that a table of an inheritance hierarchy has no
+/// record for the primary key being loaded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, thiserror::Error)]
+#[error("Missing hierarchy record in table `{table_name}`")]
+pub struct MissingHierarchyRecordError {
+    /// The table lacking the record.
+    pub table_name: &'static str,
 }
 
+/// Error raised while loading the records of an inheritance hierarchy.
+#[derive(Debug, thiserror::Error)]
+pub enum LoadHierarchyError {
+    #[error("Diesel error: {0}")]
+    /// A diesel error.
+    Diesel(#[from] diesel::result::Error),
+    #[error(transparent)]
+    /// A table of the hierarchy has no record for the primary key.
+    MissingRecord(#[from] MissingHierarchyRecordError),
+}
+
+/// Specific error indicating that a builder was linked to a model whose record
+/// no longer exists in the referenced table.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, thiserror::Error)]
+#[error(
+    "Model referenced by `{table_name}.{field_name}` is missing from `{referenced_table_name}`"
+)]
+pub struct ForeignModelMissing {
+    /// The table of the column.
+    pub table_name: &'static str,
+    /// The name of the column.
+    pub field_name: &'static str,
+    /// The table lacking the referenced record.
+    pub referenced_table_name: &'static str,
+}
+
+impl DatabaseErrorInformation for ForeignModelMissing {
+    fn message(&self) -> &str {
+        "Foreign model missing"
+    }
+
+    fn details(&self) -> Option<&str> {
+        None
+    }
+
+    fn hint(&self) -> Option<&str> {
+        None
+    }
+
+    fn table_name(&self) -> Option<&str> {
+        Some(self.table_name)
+    }
+
+    fn column_name(&self) -> Option<&str> {
+        Some(self.field_name)
+    }
+
+    fn constraint_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn statement_position(&self) -> Option<i32> {
+        None
+    }
+}
+
+/// Aggregate of all the validation errors collected while validating a
+/// builder, as opposed to the first error encountered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationErrors<E> {
+    /// The collected validation errors, in column order.
+    errors: Vec<E>,
+}
+
+impl<E> ValidationErrors<E> {
+    /// Creates a new aggregate from the given errors.
+    #[must_use]
+    pub fn new(errors: Vec<E>) -> Self {
+        Self { errors }
+    }
+
+    /// Returns the collected errors.
+    #[must_use]
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Consumes the aggregate and returns the collected errors.
+    #[must_use]
+    pub fn into_errors(self) -> Vec<E> {
+        self.errors
+    }
+
+    /// Returns the number of collected errors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns whether no error was collected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl<E> IntoIterator for ValidationErrors<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a ValidationErrors<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ValidationErrors<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} validation error(s)", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "; {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ValidationErrors<E> {}
+
 /// A specialized `Result` type for builder operations.
 pub type BuilderResult<T, E> = Result<T, BuilderError<E>>;
 
@@ -94,6 +459,7 @@ impl DatabaseErrorInformation for IncompleteBuilderError {
                 "Missing mandatory triangular builder field"
             }
             IncompleteBuilderError::MissingMandatoryField { .. } => "Missing mandatory field",
+            IncompleteBuilderError::MissingMandatoryFields { .. } => "Missing mandatory fields",
         }
     }
 
@@ -108,7 +474,8 @@ impl DatabaseErrorInformation for IncompleteBuilderError {
     fn table_name(&self) -> Option<&str> {
         match self {
             IncompleteBuilderError::MissingMandatoryTriangularField { table_name, .. }
-            | IncompleteBuilderError::MissingMandatoryField { table_name, .. } => Some(table_name),
+            | IncompleteBuilderError::MissingMandatoryField { table_name, .. }
+            | IncompleteBuilderError::MissingMandatoryFields { table_name, .. } => Some(table_name),
         }
     }
 
@@ -116,6 +483,9 @@ impl DatabaseErrorInformation for IncompleteBuilderError {
         match self {
             IncompleteBuilderError::MissingMandatoryTriangularField { field_name, .. }
             | IncompleteBuilderError::MissingMandatoryField { field_name, .. } => Some(field_name),
+            IncompleteBuilderError::MissingMandatoryFields { field_names, .. } => {
+                field_names.first().copied()
+            }
         }
     }
 
@@ -140,6 +510,12 @@ impl<E: DatabaseErrorInformation + Send + Sync + 'static> From<BuilderError<E>>
                     Box::new(e),
                 )
             }
+            BuilderError::ForeignModelMissing(e) => {
+                diesel::result::Error::DatabaseError(
+                    diesel::result::DatabaseErrorKind::ForeignKeyViolation,
+                    Box::new(e),
+                )
+            }
             BuilderError::Validation(e) => {
                 diesel::result::Error::DatabaseError(
                     diesel::result::DatabaseErrorKind::CheckViolation,
@@ -2,20 +2,26 @@
 
 mod columns_collection;
 mod homogeneously_typed_nested_columns;
+mod nested_column_meta;
 mod nested_columns;
 mod nested_columns_collection;
 mod non_empty_nested_projection;
 mod non_empty_projection;
 mod tuple_eq_all;
+mod tuple_eq_all_except;
+mod tuple_eq_excluded;
 mod tuple_to_order;
 
 pub use columns_collection::ColumnsCollection;
 pub use homogeneously_typed_nested_columns::HomogeneouslyTypedNestedColumns;
+pub use nested_column_meta::NestedColumnMeta;
 pub use nested_columns::{HasNestedDynColumns, NestedColumns, NestedDynColumns};
 pub use nested_columns_collection::NestedColumnsCollection;
 pub use non_empty_nested_projection::NonEmptyNestedProjection;
 pub use non_empty_projection::NonEmptyProjection;
 pub use tuple_eq_all::TupleEqAll;
+pub use tuple_eq_all_except::TupleEqAllExcept;
+pub use tuple_eq_excluded::TupleEqExcluded;
 pub use tuple_to_order::TupleToOrder;
 use tuplities::prelude::*;
 
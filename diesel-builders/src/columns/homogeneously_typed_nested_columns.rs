@@ -2,13 +2,32 @@
 //! trait.
 
 use super::NestedColumns;
-use crate::{HomogeneouslyTypedNestedTuple, TypedColumn};
+use crate::{
+    HomogeneousNestedValues, HomogeneouslyTypedNestedTuple, TypedColumn, TypedNestedTuple,
+};
 
 /// Trait representing a nested tuple of columns where all columns have the same
 /// associated Type.
 pub trait HomogeneouslyTypedNestedColumns<VT>:
     NestedColumns + HomogeneouslyTypedNestedTuple<VT>
 {
+    #[inline]
+    /// Applies `f` to each of the provided values of the columns, such as
+    /// the values of a loaded projection.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The nested tuple of values of the columns.
+    /// * `f` - The function applied to each value.
+    fn map_same_type<U>(
+        values: <Self as TypedNestedTuple>::NestedTupleValueType,
+        f: impl Fn(VT) -> U,
+    ) -> <<Self as TypedNestedTuple>::NestedTupleValueType as HomogeneousNestedValues<VT>>::Mapped<U>
+    where
+        <Self as TypedNestedTuple>::NestedTupleValueType: HomogeneousNestedValues<VT>,
+    {
+        values.map_homogeneous(&f)
+    }
 }
 
 impl<VT> HomogeneouslyTypedNestedColumns<VT> for () {}
@@ -0,0 +1,40 @@
+//! Submodule defining and implementing the `NestedColumnMeta` trait.
+
+use crate::{ColumnMeta, HasColumnMeta};
+
+/// Trait for nested tuples of columns exposing their [`ColumnMeta`].
+///
+/// Given a tuple of columns `(C1, C2, C3)`, the metadata is collected from
+/// the nested columns `(C1, (C2, (C3,)))` in declaration order.
+pub trait NestedColumnMeta {
+    /// Appends the metadata of the columns to the provided vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `metas` - The vector to which the metadata is appended.
+    fn extend_column_meta(metas: &mut Vec<ColumnMeta>);
+}
+
+impl NestedColumnMeta for () {
+    #[inline]
+    fn extend_column_meta(_metas: &mut Vec<ColumnMeta>) {}
+}
+
+impl<C1: HasColumnMeta> NestedColumnMeta for (C1,) {
+    #[inline]
+    fn extend_column_meta(metas: &mut Vec<ColumnMeta>) {
+        metas.push(C1::META);
+    }
+}
+
+impl<Head, Tail> NestedColumnMeta for (Head, Tail)
+where
+    Head: HasColumnMeta,
+    Tail: NestedColumnMeta,
+{
+    #[inline]
+    fn extend_column_meta(metas: &mut Vec<ColumnMeta>) {
+        metas.push(Head::META);
+        Tail::extend_column_meta(metas);
+    }
+}
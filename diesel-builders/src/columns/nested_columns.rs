@@ -21,6 +21,21 @@ pub trait NestedColumns: TypedNestedTuple + Default + Copy {
     /// Const representing the names of the tables of the columns as a nested
     /// tuple of strings.
     const NESTED_TABLE_NAMES: Self::NestedNames;
+
+    /// Returns the position of the column with the provided name, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column to look for.
+    fn column_position(column_name: &str) -> Option<usize>;
+
+    /// Returns the name of the table of the column at the provided position,
+    /// if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the column.
+    fn table_name_at(position: usize) -> Option<&'static str>;
 }
 
 impl NestedColumns for () {
@@ -28,6 +43,16 @@ impl NestedColumns for () {
     type NestedNames = ();
     const NESTED_COLUMN_NAMES: Self::NestedNames = ();
     const NESTED_TABLE_NAMES: Self::NestedNames = ();
+
+    #[inline]
+    fn column_position(_column_name: &str) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn table_name_at(_position: usize) -> Option<&'static str> {
+        None
+    }
 }
 
 impl<C1: TypedColumn> NestedColumns for (C1,)
@@ -38,6 +63,16 @@ where
     type NestedNames = (&'static str,);
     const NESTED_COLUMN_NAMES: Self::NestedNames = (C1::NAME,);
     const NESTED_TABLE_NAMES: Self::NestedNames = (C1::Table::TABLE_NAME,);
+
+    #[inline]
+    fn column_position(column_name: &str) -> Option<usize> {
+        (C1::NAME == column_name).then_some(0)
+    }
+
+    #[inline]
+    fn table_name_at(position: usize) -> Option<&'static str> {
+        (position == 0).then_some(C1::Table::TABLE_NAME)
+    }
 }
 
 impl<Head, Tail> NestedColumns for (Head, Tail)
@@ -54,6 +89,23 @@ where
     const NESTED_COLUMN_NAMES: Self::NestedNames = (Head::NAME, Tail::NESTED_COLUMN_NAMES);
     const NESTED_TABLE_NAMES: Self::NestedNames =
         (Head::Table::TABLE_NAME, Tail::NESTED_TABLE_NAMES);
+
+    #[inline]
+    fn column_position(column_name: &str) -> Option<usize> {
+        if Head::NAME == column_name {
+            Some(0)
+        } else {
+            Tail::column_position(column_name).map(|position| position + 1)
+        }
+    }
+
+    #[inline]
+    fn table_name_at(position: usize) -> Option<&'static str> {
+        match position.checked_sub(1) {
+            None => Some(Head::Table::TABLE_NAME),
+            Some(tail_position) => Tail::table_name_at(tail_position),
+        }
+    }
 }
 
 /// Trait for n-uples of dynamic columns.
@@ -1,11 +1,76 @@
 //! Submodule defining and implementing the `NonEmptyProjection` trait.
 
-use super::{Columns, NonEmptyNestedProjection};
+use super::{Columns, NestedColumnMeta, NonEmptyNestedProjection};
 
 /// A trait representing a non-empty projection of Diesel columns.
+///
+/// Every column of the projection must belong to the same table, so that a
+/// projection mixing tables is rejected at compile time rather than failing
+/// when the query is run:
+///
+/// ```rust
+/// use diesel_builders::{columns::NonEmptyProjection, prelude::*};
+///
+/// #[derive(Queryable, Selectable, Identifiable, TableModel)]
+/// #[diesel(table_name = animals)]
+/// #[table_model(surrogate_key)]
+/// pub struct Animal {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// fn table_of<P: NonEmptyProjection>() -> P::Table {
+///     P::Table::default()
+/// }
+///
+/// fn main() {
+///     let _: animals::table = table_of::<(animals::id, animals::name)>();
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use diesel_builders::{columns::NonEmptyProjection, prelude::*};
+///
+/// #[derive(Queryable, Selectable, Identifiable, TableModel)]
+/// #[diesel(table_name = animals)]
+/// #[table_model(surrogate_key)]
+/// pub struct Animal {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(Queryable, Selectable, Identifiable, TableModel)]
+/// #[diesel(table_name = dogs)]
+/// #[table_model(ancestors(animals))]
+/// pub struct Dog {
+///     id: i32,
+///     breed: String,
+/// }
+///
+/// fn table_of<P: NonEmptyProjection>() -> P::Table {
+///     P::Table::default()
+/// }
+///
+/// fn main() {
+///     // `dogs::breed` does not belong to `animals`.
+///     let _ = table_of::<(animals::id, dogs::breed)>();
+/// }
+/// ```
 pub trait NonEmptyProjection: Columns<Nested: NonEmptyNestedProjection> {
     /// The table associated to this projection.
     type Table: diesel::Table + Default;
+
+    /// Returns the names of the nullable columns of the projection, in the
+    /// order in which they appear in it.
+    #[must_use]
+    fn nullable_columns() -> Vec<&'static str>
+    where
+        Self::Nested: NestedColumnMeta,
+    {
+        let mut metas = Vec::new();
+        <Self::Nested as NestedColumnMeta>::extend_column_meta(&mut metas);
+        metas.into_iter().filter(|meta| meta.is_nullable).map(|meta| meta.name).collect()
+    }
 }
 
 impl<T> NonEmptyProjection for T
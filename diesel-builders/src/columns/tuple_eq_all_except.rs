@@ -0,0 +1,68 @@
+//! Submodule providing a nested tuple version of the `EqAll` trait for Diesel
+//! columns, skipping the excluded columns.
+
+use diesel::{Expression, expression::AsExpression, sql_types::SingleValue};
+use tuplities::prelude::FlattenNestedTuple;
+
+use crate::{TypedColumn, TypedNestedTuple};
+
+/// Trait for creating a tuple of assignments of the columns to the provided
+/// values, leaving out the excluded columns.
+///
+/// The assignments of the excluded columns are `None`, which Diesel skips
+/// when writing the `SET` clause of an update, so that the same changeset
+/// type is built whichever columns are excluded.
+pub trait TupleEqAllExcept: TypedNestedTuple {
+    /// The output type of the assignments.
+    type EqAllExcept: FlattenNestedTuple;
+    /// Creates a tuple of assignments of the columns whose name is not
+    /// excluded by `is_excluded`.
+    fn eq_all_except<F>(
+        self,
+        rhs: Self::NestedTupleColumnType,
+        is_excluded: F,
+    ) -> Self::EqAllExcept
+    where
+        F: Fn(&str) -> bool + Copy;
+}
+
+impl<Head> TupleEqAllExcept for (Head,)
+where
+    Head: TypedColumn<ColumnType: AsExpression<<Head as diesel::Expression>::SqlType>>
+        + Expression<SqlType: SingleValue>,
+{
+    type EqAllExcept = (Option<diesel::dsl::Eq<Head, Head::ColumnType>>,);
+    fn eq_all_except<F>(self, rhs: (Head::ColumnType,), is_excluded: F) -> Self::EqAllExcept
+    where
+        F: Fn(&str) -> bool + Copy,
+    {
+        use diesel::ExpressionMethods;
+        ((!is_excluded(Head::NAME)).then(|| self.0.eq(rhs.0)),)
+    }
+}
+
+impl<Head, Tail> TupleEqAllExcept for (Head, Tail)
+where
+    Head: TypedColumn<ColumnType: AsExpression<<Head as diesel::Expression>::SqlType>>
+        + Expression<SqlType: SingleValue>,
+    Tail: TupleEqAllExcept,
+    (Head, Tail):
+        TypedNestedTuple<NestedTupleColumnType = (Head::ColumnType, Tail::NestedTupleColumnType)>,
+    (Option<diesel::dsl::Eq<Head, Head::ColumnType>>, Tail::EqAllExcept): FlattenNestedTuple,
+{
+    type EqAllExcept = (Option<diesel::dsl::Eq<Head, Head::ColumnType>>, Tail::EqAllExcept);
+    fn eq_all_except<F>(
+        self,
+        rhs: (Head::ColumnType, Tail::NestedTupleColumnType),
+        is_excluded: F,
+    ) -> Self::EqAllExcept
+    where
+        F: Fn(&str) -> bool + Copy,
+    {
+        use diesel::ExpressionMethods;
+        (
+            (!is_excluded(Head::NAME)).then(|| self.0.eq(rhs.0)),
+            self.1.eq_all_except(rhs.1, is_excluded),
+        )
+    }
+}
@@ -0,0 +1,65 @@
+//! Submodule providing a nested tuple of assignments of Diesel columns to the
+//! values excluded by a conflicting insertion, as used by bulk upserts.
+
+use diesel::{
+    Expression,
+    expression::AsExpression,
+    sql_types::SingleValue,
+    upsert::{Excluded, excluded},
+};
+use tuplities::prelude::FlattenNestedTuple;
+
+use crate::{NestedColumns, TypedColumn};
+
+/// Trait for creating a tuple of assignments setting each column to the value
+/// proposed for insertion, i.e. `c = excluded.c` in an `ON CONFLICT DO UPDATE`
+/// clause.
+///
+/// The assignments of the columns skipped by the caller are `None`, which
+/// Diesel leaves out of the `SET` clause.
+pub trait TupleEqExcluded: NestedColumns {
+    /// The output type of the assignments.
+    type EqExcluded: FlattenNestedTuple;
+    /// Creates a tuple of assignments of the columns to their excluded values,
+    /// skipping the columns whose name is matched by `is_skipped`.
+    fn eq_excluded<F>(self, is_skipped: F) -> Self::EqExcluded
+    where
+        F: Fn(&str) -> bool + Copy;
+}
+
+impl<Head> TupleEqExcluded for (Head,)
+where
+    Head: TypedColumn + Expression<SqlType: SingleValue>,
+    Excluded<Head>: AsExpression<<Head as Expression>::SqlType>,
+    (Head,): NestedColumns,
+{
+    type EqExcluded = (Option<diesel::dsl::Eq<Head, Excluded<Head>>>,);
+    fn eq_excluded<F>(self, is_skipped: F) -> Self::EqExcluded
+    where
+        F: Fn(&str) -> bool + Copy,
+    {
+        use diesel::ExpressionMethods;
+        ((!is_skipped(Head::NAME)).then(|| self.0.eq(excluded(self.0))),)
+    }
+}
+
+impl<Head, Tail> TupleEqExcluded for (Head, Tail)
+where
+    Head: TypedColumn + Expression<SqlType: SingleValue>,
+    Excluded<Head>: AsExpression<<Head as Expression>::SqlType>,
+    Tail: TupleEqExcluded,
+    (Head, Tail): NestedColumns,
+    (Option<diesel::dsl::Eq<Head, Excluded<Head>>>, Tail::EqExcluded): FlattenNestedTuple,
+{
+    type EqExcluded = (Option<diesel::dsl::Eq<Head, Excluded<Head>>>, Tail::EqExcluded);
+    fn eq_excluded<F>(self, is_skipped: F) -> Self::EqExcluded
+    where
+        F: Fn(&str) -> bool + Copy,
+    {
+        use diesel::ExpressionMethods;
+        (
+            (!is_skipped(Head::NAME)).then(|| self.0.eq(excluded(self.0))),
+            self.1.eq_excluded(is_skipped),
+        )
+    }
+}
@@ -208,6 +208,13 @@ pub trait ForeignPrimaryKey: TypedColumn {
                 ColumnType = <Self as ValueTyped>::ValueType,
             >,
         > + Descendant;
+
+    #[inline]
+    #[must_use]
+    /// Returns the name of the table referenced by this foreign primary key.
+    fn referenced_table_name() -> &'static str {
+        <Self::ReferencedTable as TableExt>::TABLE_NAME
+    }
 }
 
 impl<C>
@@ -1,4 +1,4 @@
-//! Blanket implementations for `GetColumn` on smart pointers.
+//! Blanket implementations for `GetColumn` on references and smart pointers.
 
 use crate::{TypedColumn, get_column::GetColumn};
 
@@ -18,6 +18,22 @@ where
     }
 }
 
+impl<C, T> GetColumn<C> for &mut T
+where
+    C: TypedColumn,
+    T: GetColumn<C>,
+{
+    #[inline]
+    fn get_column_ref(&self) -> &C::ColumnType {
+        (**self).get_column_ref()
+    }
+
+    #[inline]
+    fn get_column(&self) -> C::ColumnType {
+        (**self).get_column()
+    }
+}
+
 impl<C, T> GetColumn<C> for Box<T>
 where
     C: TypedColumn,
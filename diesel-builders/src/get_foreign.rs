@@ -1,15 +1,21 @@
 //! Submodule defining the `GetForeign` trait for Diesel table models.
 
+use std::collections::{HashMap, hash_map::Entry};
+
 use tuplities::prelude::{IntoNestedTupleOption, NestedTupleInto, NestedTupleOption};
 
 use crate::{
-    GetNestedColumns, TableExt, TypedNestedTuple, UniqueTableIndex,
+    ForeignPrimaryKey, GetNestedColumns, TableExt, TypedNestedTuple, UniqueTableIndex,
     columns::{NonEmptyNestedProjection, NonEmptyProjection},
     load_query_builder::LoadFirst,
 };
 
 /// The `GetForeign` trait allows retrieving the foreign table
 /// model curresponding to specified foreign columns from a host table model.
+///
+/// Composite foreign keys are supported by passing tuples of host and foreign
+/// columns of the same arity: the foreign model is the one matching all the
+/// host column values, pairwise.
 pub trait GetForeign<
     Conn,
     HostColumns: NonEmptyProjection<Nested: NonEmptyNestedProjection>,
@@ -88,3 +94,154 @@ pub trait GetForeignExt<Conn> {
 }
 
 impl<T, Conn> GetForeignExt<Conn> for T {}
+
+/// Primary key column of the table referenced by a foreign primary key.
+type ReferencedPrimaryKey<C> =
+    <<C as ForeignPrimaryKey>::ReferencedTable as diesel::Table>::PrimaryKey;
+
+/// Helper trait following a chain of two foreign primary keys, such as
+/// `puppy -> dog -> animal`, from a host model to the model at its far end.
+///
+/// The chain is resolved with two queries, one per hop: the intermediate
+/// model is loaded first, and the far model is then loaded through its
+/// foreign key.
+pub trait GetForeignChain<Conn> {
+    /// Returns the model referenced by the `Far` foreign key of the model
+    /// referenced by the `Mid` foreign key of this model.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if either
+    ///   query fails or if either hop finds no matching record.
+    fn get_foreign_2<Mid, Far>(
+        &self,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<<Far::ReferencedTable as TableExt>::Model>
+    where
+        Mid: ForeignPrimaryKey,
+        Far: ForeignPrimaryKey<Table = Mid::ReferencedTable>,
+        (Mid,): NonEmptyProjection<Nested: NonEmptyNestedProjection>,
+        (Far,): NonEmptyProjection<Nested: NonEmptyNestedProjection>,
+        (ReferencedPrimaryKey<Mid>,): UniqueTableIndex<Table = Mid::ReferencedTable>,
+        (ReferencedPrimaryKey<Far>,): UniqueTableIndex<Table = Far::ReferencedTable>,
+        Self: GetForeign<Conn, (Mid,), (ReferencedPrimaryKey<Mid>,)>,
+        <Mid::ReferencedTable as TableExt>::Model:
+            GetForeign<Conn, (Far,), (ReferencedPrimaryKey<Far>,)>,
+    {
+        let mid =
+            <Self as GetForeign<Conn, (Mid,), (ReferencedPrimaryKey<Mid>,)>>::foreign(self, conn)?;
+        <<Mid::ReferencedTable as TableExt>::Model as GetForeign<
+            Conn,
+            (Far,),
+            (ReferencedPrimaryKey<Far>,),
+        >>::foreign(&mid, conn)
+    }
+}
+
+impl<T, Conn> GetForeignChain<Conn> for T {}
+
+/// Key under which a [`ForeignCache`] stores the loaded foreign models.
+type ForeignCacheKey<HostColumns> =
+    <<HostColumns as NonEmptyProjection>::Nested as TypedNestedTuple>::NestedTupleColumnType;
+
+/// Opt-in memoization of foreign models loaded through [`GetForeign`].
+///
+/// The cache is keyed by the values of the host columns, so host models
+/// referencing the same foreign record share a single query within the
+/// lifetime of the cache. It never observes writes made through the
+/// connection: drop or [`clear`](Self::clear) it when the cached records may
+/// have changed.
+pub struct ForeignCache<HostColumns, ForeignColumns>
+where
+    HostColumns: NonEmptyProjection<Nested: NonEmptyNestedProjection>,
+    ForeignColumns: UniqueTableIndex<Table: TableExt>,
+{
+    /// The foreign models loaded so far, keyed by the host column values.
+    models: HashMap<ForeignCacheKey<HostColumns>, <ForeignColumns::Table as TableExt>::Model>,
+    /// Marker binding the cache to its host and foreign columns.
+    _columns: core::marker::PhantomData<fn() -> (HostColumns, ForeignColumns)>,
+}
+
+impl<HostColumns, ForeignColumns> Default for ForeignCache<HostColumns, ForeignColumns>
+where
+    HostColumns: NonEmptyProjection<Nested: NonEmptyNestedProjection>,
+    ForeignColumns: UniqueTableIndex<Table: TableExt>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self { models: HashMap::new(), _columns: core::marker::PhantomData }
+    }
+}
+
+impl<HostColumns, ForeignColumns> ForeignCache<HostColumns, ForeignColumns>
+where
+    HostColumns: NonEmptyProjection<Nested: NonEmptyNestedProjection>,
+    ForeignColumns: UniqueTableIndex<Table: TableExt>,
+{
+    /// Creates an empty cache.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of foreign models currently cached.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    /// Returns whether no foreign model is currently cached.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    /// Removes all the cached foreign models.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.models.clear();
+    }
+
+    /// Returns the foreign model referenced by the provided host model,
+    /// loading it only if no host model with the same host column values was
+    /// looked up before.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host model whose foreign model is retrieved.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query on a cache miss.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails or if no matching record is found. Failed lookups are not
+    ///   cached.
+    pub fn get_foreign<Conn, Host>(
+        &mut self,
+        host: &Host,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<&<ForeignColumns::Table as TableExt>::Model>
+    where
+        Host: GetForeign<Conn, HostColumns, ForeignColumns>,
+        ForeignCacheKey<HostColumns>: Eq + core::hash::Hash,
+    {
+        let key = <Host as GetNestedColumns<HostColumns::Nested>>::get_nested_columns(host);
+        match self.models.entry(key) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                Ok(entry.insert(<Host as GetForeign<Conn, HostColumns, ForeignColumns>>::foreign(
+                    host, conn,
+                )?))
+            }
+        }
+    }
+}
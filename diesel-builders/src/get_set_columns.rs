@@ -8,12 +8,22 @@ mod may_get_nested_columns;
 pub use may_get_nested_columns::*;
 mod tuple_may_get_nested_columns;
 pub use tuple_may_get_nested_columns::*;
+mod diff_nested_columns;
+pub use diff_nested_columns::*;
+mod validate_all_nested_columns;
+pub use validate_all_nested_columns::*;
+mod apply_changeset;
+pub use apply_changeset::*;
+mod copy_shared_columns;
+pub use copy_shared_columns::*;
 mod set_nested_columns;
 pub use set_nested_columns::*;
 mod may_set_nested_columns;
 pub use may_set_nested_columns::*;
 mod try_set_nested_columns;
 pub use try_set_nested_columns::*;
+mod try_set_nested_columns_counted;
+pub use try_set_nested_columns_counted::*;
 mod try_set_nested_columns_collection;
 pub use try_set_nested_columns_collection::*;
 mod try_may_set_nested_columns;
@@ -28,3 +38,5 @@ mod set_homogeneous_nested_columns;
 pub use set_homogeneous_nested_columns::*;
 mod try_set_homogeneous_nested_columns;
 pub use try_set_homogeneous_nested_columns::*;
+mod set_columns_from;
+pub use set_columns_from::*;
@@ -0,0 +1,75 @@
+//! Submodule bridging diesel `AsChangeset` structs into builders.
+//!
+//! Diesel lowers a changeset into an opaque expression tree, so the values
+//! cannot be read back from [`AsChangeset::Changeset`]. Instead, the
+//! changeset struct exposes its columns and values through
+//! [`ChangesetColumns`], which covers the common case of a
+//! `#[derive(AsChangeset)]` struct whose fields map one-to-one onto columns,
+//! with `None` fields left untouched. Changesets using `treat_none_as_null`,
+//! custom expressions or embedded changesets are out of scope.
+
+use diesel::query_builder::AsChangeset;
+use tuplities::prelude::IntoNestedTupleOption;
+
+use crate::{
+    BuildableTable, TableBuilder, TableExt, TryMaySetNestedColumns,
+    columns::{NestedColumns, NonEmptyNestedProjection},
+};
+
+/// Trait for diesel changeset structs whose values can be read as columns of
+/// the changeset target table.
+pub trait ChangesetColumns: AsChangeset<Target: TableExt> {
+    /// The nested columns covered by the changeset.
+    type Columns: NonEmptyNestedProjection<Table = <Self as AsChangeset>::Target>;
+
+    /// Returns the values of the changeset, where `None` marks a column which
+    /// is not changed.
+    fn changeset_values(
+        self,
+    ) -> <<Self::Columns as NestedColumns>::NestedTupleColumnType as IntoNestedTupleOption>::IntoOptions;
+}
+
+/// Trait for builders which may apply a diesel changeset struct.
+pub trait ApplyChangeset<C: ChangesetColumns>: Sized {
+    /// The error type returned when a changed value is invalid.
+    type Error;
+
+    /// Sets the builder columns changed by the provided changeset.
+    ///
+    /// # Arguments
+    ///
+    /// * `changeset` - The changeset whose values are applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any changed value fails validation.
+    fn apply_changeset_ref(&mut self, changeset: C) -> Result<&mut Self, Self::Error>;
+
+    /// Sets the builder columns changed by the provided changeset.
+    ///
+    /// # Arguments
+    ///
+    /// * `changeset` - The changeset whose values are applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any changed value fails validation.
+    fn apply_changeset(mut self, changeset: C) -> Result<Self, Self::Error> {
+        self.apply_changeset_ref(changeset)?;
+        Ok(self)
+    }
+}
+
+impl<T, C> ApplyChangeset<C> for TableBuilder<T>
+where
+    T: BuildableTable,
+    C: ChangesetColumns,
+    Self: TryMaySetNestedColumns<T::Error, C::Columns>,
+{
+    type Error = T::Error;
+
+    #[inline]
+    fn apply_changeset_ref(&mut self, changeset: C) -> Result<&mut Self, Self::Error> {
+        self.try_may_set_nested_columns(changeset.changeset_values())
+    }
+}
@@ -0,0 +1,225 @@
+//! Submodule providing the `CopySharedColumns` trait, copying the columns a
+//! builder shares by name with a model of another table.
+//!
+//! Columns are matched by name and column type at runtime, so copy flows
+//! between tables with overlapping schemas (e.g. a staging table and its
+//! production counterpart) do not need to spell out every column.
+
+use diesel::Table;
+use tuplities::prelude::NestTuple;
+
+use crate::{
+    AncestorOfIndex, BuildableTable, DescendantWithSelf, HasTableExt, NestedTables, TableBuilder,
+    builder_error::DynamicColumnError,
+};
+
+/// Trait for builders which may copy the columns they share with a source
+/// model.
+pub trait CopySharedColumns<Source>: Sized {
+    /// Sets every column of the builder sharing its name and column type with
+    /// a column of the source, returning the names of the copied columns in
+    /// the order of the source columns.
+    ///
+    /// Source columns without a counterpart in the builder are ignored. The
+    /// builder is left unchanged if any of the copied values is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The model whose values are copied.
+    ///
+    /// # Errors
+    ///
+    /// * [`DynamicColumnError::Validation`] if a copied value is invalid.
+    fn copy_shared_columns_ref(
+        &mut self,
+        source: &Source,
+    ) -> Result<Vec<&'static str>, DynamicColumnError>;
+}
+
+impl<T, Source> CopySharedColumns<Source> for TableBuilder<T>
+where
+    T: AncestorOfIndex<T> + BuildableTable,
+    Source: HasTableExt
+        + sealed::VariadicCopyColumns<<<Source::Table as Table>::AllColumns as NestTuple>::Nested>,
+    Self: Clone
+        + sealed::VariadicTrySetNamedColumn<
+            <<T as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >,
+{
+    #[inline]
+    fn copy_shared_columns_ref(
+        &mut self,
+        source: &Source,
+    ) -> Result<Vec<&'static str>, DynamicColumnError> {
+        use sealed::VariadicCopyColumns;
+        let mut copied_into = self.clone();
+        let mut copied = Vec::new();
+        source.variadic_copy_columns::<
+            Self,
+            <<T as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >(&mut copied_into, &mut copied)?;
+        *self = copied_into;
+        Ok(copied)
+    }
+}
+
+/// Copies the columns the builder shares by name with the source model,
+/// returning the names of the copied columns.
+///
+/// # Arguments
+///
+/// * `builder` - The builder to copy the columns into.
+/// * `source` - The model whose values are copied.
+///
+/// # Errors
+///
+/// Returns an error if any of the copied values is invalid, as described in
+/// [`CopySharedColumns::copy_shared_columns_ref`].
+pub fn copy_shared_columns<B: CopySharedColumns<S>, S>(
+    builder: &mut B,
+    source: &S,
+) -> Result<Vec<&'static str>, DynamicColumnError> {
+    builder.copy_shared_columns_ref(source)
+}
+
+/// Sealed module for private traits.
+mod sealed {
+    use crate::{
+        GetColumn, NestedColumns, TableExt, TrySetColumn, TypedColumn,
+        builder_error::DynamicColumnError,
+    };
+
+    /// Trait setting a column of a nested tuple of columns by its name.
+    pub trait VariadicTrySetNamedColumn<Columns: NestedColumns> {
+        /// Sets the first column named `name` whose column type is `V`,
+        /// returning whether such a column exists.
+        fn variadic_try_set_named_column<V: Clone + 'static>(
+            &mut self,
+            name: &'static str,
+            value: &V,
+        ) -> Result<bool, DynamicColumnError>;
+    }
+
+    /// Sets the column `C` if it is named `name` and its column type is `V`.
+    fn try_set_named_column<M, C, V>(
+        builder: &mut M,
+        name: &'static str,
+        value: &V,
+    ) -> Result<bool, DynamicColumnError>
+    where
+        M: TrySetColumn<C>,
+        C: TypedColumn<Table: TableExt, ColumnType: 'static>,
+        V: Clone + 'static,
+    {
+        let value_any: &dyn core::any::Any = value;
+        if C::NAME != name {
+            return Ok(false);
+        }
+        let Some(value) = value_any.downcast_ref::<C::ColumnType>() else {
+            return Ok(false);
+        };
+        builder
+            .try_set_column(value.clone())
+            .map_err(|e| DynamicColumnError::Validation(Box::new(e)))?;
+        Ok(true)
+    }
+
+    impl<M, CHead> VariadicTrySetNamedColumn<(CHead,)> for M
+    where
+        M: TrySetColumn<CHead>,
+        CHead: TypedColumn<Table: TableExt, ColumnType: 'static>,
+    {
+        #[inline]
+        fn variadic_try_set_named_column<V: Clone + 'static>(
+            &mut self,
+            name: &'static str,
+            value: &V,
+        ) -> Result<bool, DynamicColumnError> {
+            try_set_named_column::<M, CHead, V>(self, name, value)
+        }
+    }
+
+    impl<M, CHead, CTail> VariadicTrySetNamedColumn<(CHead, CTail)> for M
+    where
+        M: TrySetColumn<CHead> + VariadicTrySetNamedColumn<CTail>,
+        CHead: TypedColumn<Table: TableExt, ColumnType: 'static>,
+        CTail: NestedColumns,
+        (CHead, CTail): NestedColumns,
+    {
+        #[inline]
+        fn variadic_try_set_named_column<V: Clone + 'static>(
+            &mut self,
+            name: &'static str,
+            value: &V,
+        ) -> Result<bool, DynamicColumnError> {
+            if try_set_named_column::<M, CHead, V>(self, name, value)? {
+                return Ok(true);
+            }
+            <Self as VariadicTrySetNamedColumn<CTail>>::variadic_try_set_named_column(
+                self, name, value,
+            )
+        }
+    }
+
+    /// Trait copying the values of a nested tuple of columns into a builder.
+    pub trait VariadicCopyColumns<Columns: NestedColumns> {
+        /// Sets the builder columns sharing their name with the columns,
+        /// recording the names of the copied columns into `copied`.
+        fn variadic_copy_columns<B, BuilderColumns>(
+            &self,
+            builder: &mut B,
+            copied: &mut Vec<&'static str>,
+        ) -> Result<(), DynamicColumnError>
+        where
+            B: VariadicTrySetNamedColumn<BuilderColumns>,
+            BuilderColumns: NestedColumns;
+    }
+
+    impl<M, CHead> VariadicCopyColumns<(CHead,)> for M
+    where
+        M: GetColumn<CHead>,
+        CHead: TypedColumn<ColumnType: 'static>,
+    {
+        #[inline]
+        fn variadic_copy_columns<B, BuilderColumns>(
+            &self,
+            builder: &mut B,
+            copied: &mut Vec<&'static str>,
+        ) -> Result<(), DynamicColumnError>
+        where
+            B: VariadicTrySetNamedColumn<BuilderColumns>,
+            BuilderColumns: NestedColumns,
+        {
+            if builder.variadic_try_set_named_column(CHead::NAME, self.get_column_ref())? {
+                copied.push(CHead::NAME);
+            }
+            Ok(())
+        }
+    }
+
+    impl<M, CHead, CTail> VariadicCopyColumns<(CHead, CTail)> for M
+    where
+        M: GetColumn<CHead> + VariadicCopyColumns<CTail>,
+        CHead: TypedColumn<ColumnType: 'static>,
+        CTail: NestedColumns,
+        (CHead, CTail): NestedColumns,
+    {
+        #[inline]
+        fn variadic_copy_columns<B, BuilderColumns>(
+            &self,
+            builder: &mut B,
+            copied: &mut Vec<&'static str>,
+        ) -> Result<(), DynamicColumnError>
+        where
+            B: VariadicTrySetNamedColumn<BuilderColumns>,
+            BuilderColumns: NestedColumns,
+        {
+            if builder.variadic_try_set_named_column(CHead::NAME, self.get_column_ref())? {
+                copied.push(CHead::NAME);
+            }
+            <Self as VariadicCopyColumns<CTail>>::variadic_copy_columns::<B, BuilderColumns>(
+                self, builder, copied,
+            )
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Trait for builders which may be compared column-wise against each other.
+
+use crate::{MayGetColumn, NestedColumns, TableExt, TypedColumn};
+
+/// Trait indicating a builder whose columns may be compared against another
+/// instance of the same builder.
+pub trait DiffNestedColumns<CS: NestedColumns> {
+    /// Returns the names of the columns whose set state or value differs
+    /// between `self` and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The builder to compare against.
+    fn diff_nested_columns(&self, other: &Self) -> Vec<&'static str> {
+        let mut differing = Vec::new();
+        self.extend_diff_nested_columns(other, &mut differing);
+        differing
+    }
+
+    /// Pushes onto `differing` the names of the columns whose set state or
+    /// value differs between `self` and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The builder to compare against.
+    /// * `differing` - The vector collecting the differing column names.
+    fn extend_diff_nested_columns(&self, other: &Self, differing: &mut Vec<&'static str>);
+}
+
+impl<T> DiffNestedColumns<()> for T {
+    #[inline]
+    fn extend_diff_nested_columns(&self, _other: &Self, _differing: &mut Vec<&'static str>) {}
+}
+
+impl<C1, T> DiffNestedColumns<(C1,)> for T
+where
+    T: MayGetColumn<C1>,
+    C1: TypedColumn<Table: TableExt, ColumnType: PartialEq>,
+    (C1,): NestedColumns,
+{
+    #[inline]
+    fn extend_diff_nested_columns(&self, other: &Self, differing: &mut Vec<&'static str>) {
+        if self.may_get_column_ref() != other.may_get_column_ref() {
+            differing.push(C1::NAME);
+        }
+    }
+}
+
+impl<CHead, CTail, T> DiffNestedColumns<(CHead, CTail)> for T
+where
+    CHead: TypedColumn<ColumnType: PartialEq>,
+    CTail: NestedColumns,
+    (CHead, CTail): NestedColumns,
+    T: MayGetColumn<CHead> + DiffNestedColumns<CTail>,
+{
+    #[inline]
+    fn extend_diff_nested_columns(&self, other: &Self, differing: &mut Vec<&'static str>) {
+        if <Self as MayGetColumn<CHead>>::may_get_column_ref(self)
+            != <Self as MayGetColumn<CHead>>::may_get_column_ref(other)
+        {
+            differing.push(CHead::NAME);
+        }
+        <Self as DiffNestedColumns<CTail>>::extend_diff_nested_columns(self, other, differing);
+    }
+}
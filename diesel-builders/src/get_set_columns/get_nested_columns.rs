@@ -6,7 +6,13 @@ use crate::{GetColumn, TableExt, TypedColumn, columns::NonEmptyNestedProjection}
 
 /// Trait indicating a builder can get multiple columns.
 pub trait GetNestedColumns<CS: NonEmptyNestedProjection> {
-    /// Get the values of the specified columns.
+    /// Get the owned values of the specified columns.
+    ///
+    /// Each value is obtained through [`GetColumn::get_column`], which clones
+    /// it as guaranteed by the `Clone` bound of
+    /// [`ColumnTyped::ColumnType`](crate::ColumnTyped::ColumnType), so the
+    /// returned tuple does not borrow from `self` even when some of its
+    /// elements are not `Copy`.
     fn get_nested_columns(&self) -> CS::NestedTupleColumnType;
     /// Get the references of the specified columns.
     fn get_nested_column_refs(&self) -> <CS::NestedTupleColumnType as NestedTupleRef>::Ref<'_>;
@@ -0,0 +1,97 @@
+//! Submodule providing the `SetColumnsFrom` trait, setting the columns of
+//! the new record of a table from a flat tuple of values.
+
+use diesel::associations::HasTable;
+use tuplities::prelude::NestTuple;
+
+use crate::{SetNestedColumns, TableExt, TrySetNestedColumns, TypedNestedTuple};
+
+/// The nested columns of the new record of the table `T`.
+type NewRecord<T> = <T as TableExt>::NewRecord;
+
+/// The nested values of the columns of the new record of the table `T`.
+type NewRecordValues<T> = <NewRecord<T> as TypedNestedTuple>::NestedTupleColumnType;
+
+/// Extension trait setting every column of the new record of a table from a
+/// flat tuple of values, in the order of the new record, rather than from the
+/// nested tuple expected by [`SetNestedColumns`].
+pub trait SetColumnsFrom: HasTable<Table: TableExt> + Sized {
+    /// Sets the columns of the new record from the flat tuple `values` by
+    /// reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the columns, in the order of the new record.
+    #[inline]
+    fn set_columns_from_ref<V>(&mut self, values: V) -> &mut Self
+    where
+        V: NestTuple<Nested = NewRecordValues<Self::Table>>,
+        Self: SetNestedColumns<NewRecord<Self::Table>>,
+    {
+        self.set_nested_columns(values.nest())
+    }
+
+    /// Sets the columns of the new record from the flat tuple `values`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the columns, in the order of the new record.
+    #[inline]
+    #[must_use]
+    fn set_columns_from<V>(mut self, values: V) -> Self
+    where
+        V: NestTuple<Nested = NewRecordValues<Self::Table>>,
+        Self: SetNestedColumns<NewRecord<Self::Table>>,
+    {
+        self.set_columns_from_ref(values);
+        self
+    }
+
+    /// Attempts to set the columns of the new record from the flat tuple
+    /// `values` by reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the columns, in the order of the new record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the values fails validation, in which case
+    /// the columns preceding it are already set.
+    #[inline]
+    fn try_set_columns_from_ref<V>(
+        &mut self,
+        values: V,
+    ) -> Result<&mut Self, <Self::Table as TableExt>::Error>
+    where
+        V: NestTuple<Nested = NewRecordValues<Self::Table>>,
+        Self: TrySetNestedColumns<<Self::Table as TableExt>::Error, NewRecord<Self::Table>>,
+    {
+        self.try_set_nested_columns(values.nest())
+    }
+
+    /// Attempts to set the columns of the new record from the flat tuple
+    /// `values`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the columns, in the order of the new record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the values fails validation.
+    #[inline]
+    fn try_set_columns_from<V>(
+        mut self,
+        values: V,
+    ) -> Result<Self, <Self::Table as TableExt>::Error>
+    where
+        V: NestTuple<Nested = NewRecordValues<Self::Table>>,
+        Self: TrySetNestedColumns<<Self::Table as TableExt>::Error, NewRecord<Self::Table>>,
+    {
+        self.try_set_columns_from_ref(values)?;
+        Ok(self)
+    }
+}
+
+impl<T> SetColumnsFrom for T where T: HasTable<Table: TableExt> {}
@@ -66,3 +66,33 @@ where
         Ok(self)
     }
 }
+
+/// Attempts to apply an optional patch of the columns `CS` to the builder, as
+/// for a nested JSON patch whose associated object may be absent.
+///
+/// Nothing is set when `patch` is `None`. Otherwise, the patch is converted
+/// into the nested options of `CS` and each present value is set, as by
+/// [`TryMaySetNestedColumns::try_may_set_nested_columns`].
+///
+/// # Arguments
+///
+/// * `builder` - The builder to apply the patch to.
+/// * `patch` - The optional patch.
+///
+/// # Errors
+///
+/// Returns an error if any present value cannot be set.
+pub fn may_set_from_option<Error, CS, B, Patch>(
+    builder: &mut B,
+    patch: Option<Patch>,
+) -> Result<&mut B, Error>
+where
+    CS: NestedColumns,
+    B: TryMaySetNestedColumns<Error, CS>,
+    Patch: Into<<CS::NestedTupleColumnType as IntoNestedTupleOption>::IntoOptions>,
+{
+    match patch {
+        Some(patch) => builder.try_may_set_nested_columns(patch.into()),
+        None => Ok(builder),
+    }
+}
@@ -0,0 +1,69 @@
+//! Trait for fallibly setting multiple nested columns while counting the
+//! columns whose value changes.
+
+use crate::{
+    MayGetColumn, TableExt, TrySetColumn, TypedColumn, TypedNestedTuple, ValidateColumn,
+    columns::NestedColumns,
+};
+
+/// Trait indicating a builder can fallibly set multiple columns, reporting
+/// how many of them changed value.
+pub trait TrySetNestedColumnsCounted<Error, CS: NestedColumns> {
+    /// Attempt to set the values of the specified columns, returning the
+    /// number of columns which were unset or held a different value.
+    ///
+    /// The columns are set in order, so when one of them fails to be set the
+    /// preceding ones hold their new values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any column cannot be set.
+    fn try_set_nested_columns_counted(
+        &mut self,
+        values: CS::NestedTupleColumnType,
+    ) -> Result<usize, Error>;
+}
+
+impl<T, Error> TrySetNestedColumnsCounted<Error, ()> for T {
+    #[inline]
+    fn try_set_nested_columns_counted(&mut self, _values: ()) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+impl<C1, T, Error> TrySetNestedColumnsCounted<Error, (C1,)> for T
+where
+    T: TrySetColumn<C1> + MayGetColumn<C1>,
+    C1: TypedColumn<Table: TableExt, ColumnType: PartialEq>,
+    Error: From<<T as ValidateColumn<C1>>::Error>,
+{
+    #[inline]
+    fn try_set_nested_columns_counted(
+        &mut self,
+        (head,): (C1::ColumnType,),
+    ) -> Result<usize, Error> {
+        let changed = <Self as MayGetColumn<C1>>::may_get_column_ref(self) != Some(&head);
+        self.try_set_column(head)?;
+        Ok(usize::from(changed))
+    }
+}
+
+impl<CHead, CTail, T, Error> TrySetNestedColumnsCounted<Error, (CHead, CTail)> for T
+where
+    CHead: TypedColumn<ColumnType: PartialEq>,
+    CTail: NestedColumns,
+    (CHead, CTail):
+        NestedColumns<NestedTupleColumnType = (CHead::ColumnType, CTail::NestedTupleColumnType)>,
+    T: TrySetColumn<CHead> + MayGetColumn<CHead> + TrySetNestedColumnsCounted<Error, CTail>,
+    Error: From<<T as ValidateColumn<CHead>>::Error>,
+{
+    #[inline]
+    fn try_set_nested_columns_counted(
+        &mut self,
+        (head, tail): <(CHead, CTail) as TypedNestedTuple>::NestedTupleColumnType,
+    ) -> Result<usize, Error> {
+        let changed = <Self as MayGetColumn<CHead>>::may_get_column_ref(self) != Some(&head);
+        self.try_set_column(head)?;
+        Ok(usize::from(changed) + self.try_set_nested_columns_counted(tail)?)
+    }
+}
@@ -0,0 +1,59 @@
+//! Trait for builders which may validate all of their present columns at once.
+
+use crate::{MayGetColumn, NestedColumns, OptionalRef, TableExt, TypedColumn, ValidateColumn};
+
+/// Trait indicating a builder which may validate every present value of the
+/// specified columns, collecting all failures instead of stopping at the first
+/// one.
+pub trait ValidateAllNestedColumns<Error, CS: NestedColumns> {
+    /// Validates every present value of the specified columns, pushing each
+    /// failure onto `errors`.
+    ///
+    /// # Arguments
+    ///
+    /// * `errors` - The vector collecting the validation errors.
+    fn validate_all_nested_columns(&self, errors: &mut Vec<Error>);
+}
+
+impl<T, Error> ValidateAllNestedColumns<Error, ()> for T {
+    #[inline]
+    fn validate_all_nested_columns(&self, _errors: &mut Vec<Error>) {}
+}
+
+impl<C1, T, Error> ValidateAllNestedColumns<Error, (C1,)> for T
+where
+    Error: From<<T as ValidateColumn<C1>>::Error>,
+    T: MayGetColumn<C1> + ValidateColumn<C1>,
+    C1: TypedColumn<Table: TableExt>,
+    (C1,): NestedColumns,
+{
+    #[inline]
+    fn validate_all_nested_columns(&self, errors: &mut Vec<Error>) {
+        if let Some(value) = self.may_get_column_ref().and_then(|v| v.as_optional_ref())
+            && let Err(error) = self.validate_column_in_context(value)
+        {
+            errors.push(error.into());
+        }
+    }
+}
+
+impl<CHead, CTail, T, Error> ValidateAllNestedColumns<Error, (CHead, CTail)> for T
+where
+    CHead: TypedColumn,
+    CTail: NestedColumns,
+    (CHead, CTail): NestedColumns,
+    T: MayGetColumn<CHead> + ValidateColumn<CHead> + ValidateAllNestedColumns<Error, CTail>,
+    Error: From<<T as ValidateColumn<CHead>>::Error>,
+{
+    #[inline]
+    fn validate_all_nested_columns(&self, errors: &mut Vec<Error>) {
+        if let Some(value) = <Self as MayGetColumn<CHead>>::may_get_column_ref(self)
+            .and_then(|v| v.as_optional_ref())
+            && let Err(error) =
+                <Self as ValidateColumn<CHead>>::validate_column_in_context(self, value)
+        {
+            errors.push(error.into());
+        }
+        <Self as ValidateAllNestedColumns<Error, CTail>>::validate_all_nested_columns(self, errors);
+    }
+}
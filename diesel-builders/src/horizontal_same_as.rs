@@ -4,7 +4,8 @@ use tuplities::prelude::*;
 use typenum::Unsigned;
 
 use crate::{
-    ForeignPrimaryKey, HasPrimaryKeyColumn, NestedBuildableTables, TableExt, TypedNestedTuple,
+    ForeignPrimaryKey, HasPrimaryKeyColumn, NestedBuildableTables, TableExt, TypedColumn,
+    TypedNestedTuple, UniquelyIndexedColumn,
     ancestors::DescendantWithSelf,
     columns::{
         ColumnsCollection, NestedColumns, NestedColumnsCollection, NonEmptyNestedProjection,
@@ -60,6 +61,50 @@ pub trait HorizontalKey:
     >>;
 }
 
+/// A trait for Diesel columns that define horizontal same-as relationships
+/// through a foreign key referencing a unique column other than the primary
+/// key of the referenced table.
+///
+/// The key column holds the value of the
+/// [`ReferencedColumn`](HorizontalUniqueKey::ReferencedColumn) of the
+/// referenced record, and the host columns mirror the foreign columns of that
+/// same record, as they do for an [`HorizontalKey`].
+///
+/// # Constraints
+///
+/// * The referenced column must be covered by a single-column UNIQUE index,
+///   declared with `unique_index!`, so that it identifies a single record.
+/// * The key cannot back a `#[mandatory]` or `#[discretionary]` triangular
+///   relation: the builders of those relations are inserted before the host
+///   record, which then receives their primary key. The shared values are
+///   instead propagated from an already-loaded referenced model through
+///   [`SetUniqueSharedKeyFromModel`](crate::SetUniqueSharedKeyFromModel).
+/// * The trait is not generated by the `TableModel` derive, and is to be
+///   implemented manually for the key column.
+pub trait HorizontalUniqueKey: TypedColumn<Table: TableExt> {
+    /// The unique column of the referenced table whose value is held by the
+    /// key.
+    type ReferencedColumn: UniquelyIndexedColumn<
+            typenum::U0,
+            (Self::ReferencedColumn,),
+            Table: TableExt,
+            ColumnType: Into<Self::ColumnType>,
+        >;
+    /// The set of host columns in the same table which have
+    /// an horizontal same-as relationship defined by this key.
+    type HostColumns: NonEmptyProjection<Table = Self::Table, Nested: NonEmptyNestedProjection>;
+    /// The set of foreign columns in the referenced table which have
+    /// an horizontal same-as relationship defined by this key.
+    type ForeignColumns: NonEmptyProjection<
+            Table = <Self::ReferencedColumn as diesel::Column>::Table,
+            Nested: NonEmptyNestedProjection<
+                NestedTupleColumnType: NestedTupleInto<
+                    <<Self::HostColumns as NestTuple>::Nested as TypedNestedTuple>::NestedTupleColumnType,
+                >,
+            >,
+        >;
+}
+
 /// Extension trait for [`HorizontalKey`] to access nested host and foreign
 /// columns.
 pub trait HorizontalKeyExt: HorizontalKey {
@@ -113,8 +158,8 @@ pub trait HorizontalNestedKeys<T>: NestedColumns {
             >,
         > + NestedBuildableTables<
             NestedOptionalBuilders: NestedTupleOptionWith<
-                &'static str,
-                SameDepth = <Self as NestedColumns>::NestedNames,
+                (&'static str, &'static str),
+                SameDepth = Self::NestedReferencedNames,
             >,
         >;
     /// Tuple of tuples of host columns associated to each horizontal same-as
@@ -123,12 +168,22 @@ pub trait HorizontalNestedKeys<T>: NestedColumns {
     /// Tuple of tuples of foreign columns associated to each horizontal same-as
     /// key.
     type NestedForeignColumnsMatrix: NestedColumnsCollection<FlattenedMatrix: ColumnsCollection>;
+    /// Nested tuple of the name of each key alongside the name of the table
+    /// it references.
+    type NestedReferencedNames: Copy;
+
+    /// Const pairing the name of each key with the name of the table it
+    /// references, as a nested tuple.
+    const NESTED_REFERENCED_NAMES: Self::NestedReferencedNames;
 }
 
 impl<T> HorizontalNestedKeys<T> for () {
     type NestedReferencedTables = ();
     type NestedHostColumnsMatrix = ();
     type NestedForeignColumnsMatrix = ();
+    type NestedReferencedNames = ();
+
+    const NESTED_REFERENCED_NAMES: Self::NestedReferencedNames = ();
 }
 
 impl<Head, T> HorizontalNestedKeys<T> for (Head,)
@@ -142,8 +197,8 @@ where
             >,
         > + NestedBuildableTables<
             NestedOptionalBuilders: NestedTupleOptionWith<
-                &'static str,
-                SameDepth = <Self as NestedColumns>::NestedNames,
+                (&'static str, &'static str),
+                SameDepth = ((&'static str, &'static str),),
             >,
         >,
     (Head::NestedHostColumns,): NestedColumnsCollection<FlattenedMatrix: ColumnsCollection>,
@@ -152,6 +207,10 @@ where
     type NestedReferencedTables = (Head::ReferencedTable,);
     type NestedHostColumnsMatrix = (Head::NestedHostColumns,);
     type NestedForeignColumnsMatrix = (Head::NestedForeignColumns,);
+    type NestedReferencedNames = ((&'static str, &'static str),);
+
+    const NESTED_REFERENCED_NAMES: Self::NestedReferencedNames =
+        ((Head::NAME, <Head::ReferencedTable as TableExt>::TABLE_NAME),);
 }
 
 impl<Head, Tail, T> HorizontalNestedKeys<T> for (Head, Tail)
@@ -167,8 +226,8 @@ where
             >,
         > + NestedBuildableTables<
             NestedOptionalBuilders: NestedTupleOptionWith<
-                &'static str,
-                SameDepth = <Self as NestedColumns>::NestedNames,
+                (&'static str, &'static str),
+                SameDepth = ((&'static str, &'static str), Tail::NestedReferencedNames),
             >,
         >,
     (Head::NestedHostColumns, Tail::NestedHostColumnsMatrix):
@@ -180,4 +239,10 @@ where
     type NestedHostColumnsMatrix = (Head::NestedHostColumns, Tail::NestedHostColumnsMatrix);
     type NestedForeignColumnsMatrix =
         (Head::NestedForeignColumns, Tail::NestedForeignColumnsMatrix);
+    type NestedReferencedNames = ((&'static str, &'static str), Tail::NestedReferencedNames);
+
+    const NESTED_REFERENCED_NAMES: Self::NestedReferencedNames = (
+        (Head::NAME, <Head::ReferencedTable as TableExt>::TABLE_NAME),
+        Tail::NESTED_REFERENCED_NAMES,
+    );
 }
@@ -1,10 +1,11 @@
 //! Column which is associated to a group of horizontal same-as columns.
 
-use tuplities::prelude::{NestTuple, NestedTupleRow};
+use tuplities::prelude::{NestTuple, NestedTupleInto, NestedTupleRow};
 use typenum::Unsigned;
 
 use crate::{
-    Columns, HorizontalNestedKeys, TypedColumn,
+    Columns, GetColumn, GetNestedColumns, HorizontalKeyExt, HorizontalNestedKeys,
+    HorizontalUniqueKey, SetColumn, SetNestedColumns, TableExt, TypedColumn,
     columns::{HomogeneouslyTypedNestedColumns, NestedColumns},
 };
 
@@ -77,3 +78,157 @@ where
     type NestedDiscretionaryHorizontalKeys = <T::DiscretionaryHorizontalKeys as NestTuple>::Nested;
     type NestedMandatoryHorizontalKeys = <T::MandatoryHorizontalKeys as NestTuple>::Nested;
 }
+
+/// Trait for propagating the values shared through an horizontal key from an
+/// already-loaded referenced model, without providing a builder for it.
+///
+/// The values of the foreign columns of the key are read from the model and
+/// written into the host columns, which in turn propagate them to every
+/// builder linked to the same horizontal same-as group. The foreign key column
+/// itself is left untouched.
+///
+/// # Type Parameters
+///
+/// * `Key`: The horizontal key whose shared values should be propagated.
+pub trait SetSharedKeyFromModel<Key: HorizontalKeyExt> {
+    /// Sets the host columns of `Key` to the values of its foreign columns in
+    /// the provided model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The loaded model of the table referenced by `Key`.
+    fn set_shared_key_from_model(
+        &mut self,
+        model: &<Key::ReferencedTable as TableExt>::Model,
+    ) -> &mut Self;
+}
+
+impl<Key, T> SetSharedKeyFromModel<Key> for T
+where
+    Key: HorizontalKeyExt,
+    T: SetNestedColumns<Key::NestedHostColumns>,
+    <Key::ReferencedTable as TableExt>::Model: GetNestedColumns<Key::NestedForeignColumns>,
+{
+    #[inline]
+    fn set_shared_key_from_model(
+        &mut self,
+        model: &<Key::ReferencedTable as TableExt>::Model,
+    ) -> &mut Self {
+        let columns = model.get_nested_columns();
+        self.set_nested_columns(columns.nested_tuple_into())
+    }
+}
+
+/// Extension trait for [`SetSharedKeyFromModel`] that allows specifying the
+/// key at the method level.
+pub trait SetSharedKeyFromModelExt: Sized {
+    #[inline]
+    /// Sets the host columns of `Key` to the values of its foreign columns in
+    /// the provided model.
+    fn set_shared_key_from_model_ref<Key>(
+        &mut self,
+        model: &<Key::ReferencedTable as TableExt>::Model,
+    ) -> &mut Self
+    where
+        Key: HorizontalKeyExt,
+        Self: SetSharedKeyFromModel<Key>,
+    {
+        <Self as SetSharedKeyFromModel<Key>>::set_shared_key_from_model(self, model)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the host columns of `Key` to the values of its foreign columns in
+    /// the provided model.
+    fn set_shared_key_from_model<Key>(
+        mut self,
+        model: &<Key::ReferencedTable as TableExt>::Model,
+    ) -> Self
+    where
+        Key: HorizontalKeyExt,
+        Self: SetSharedKeyFromModel<Key>,
+    {
+        <Self as SetSharedKeyFromModel<Key>>::set_shared_key_from_model(&mut self, model);
+        self
+    }
+}
+
+impl<T> SetSharedKeyFromModelExt for T {}
+
+/// The model of the table referenced by the [`HorizontalUniqueKey`] `Key`.
+type UniqueReferencedModel<Key> =
+    <<<Key as HorizontalUniqueKey>::ReferencedColumn as diesel::Column>::Table as TableExt>::Model;
+
+/// Trait for propagating the values shared through an [`HorizontalUniqueKey`]
+/// from an already-loaded referenced model.
+///
+/// Unlike [`SetSharedKeyFromModel`], the foreign key column is set as well, to
+/// the value of the unique column it references in the provided model, since
+/// it cannot be derived from the primary key of the model.
+///
+/// # Type Parameters
+///
+/// * `Key`: The horizontal unique key whose shared values should be propagated.
+pub trait SetUniqueSharedKeyFromModel<Key: HorizontalUniqueKey> {
+    /// Sets `Key` to the value of its referenced column and its host columns
+    /// to the values of its foreign columns in the provided model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The loaded model of the table referenced by `Key`.
+    fn set_unique_shared_key_from_model(&mut self, model: &UniqueReferencedModel<Key>)
+    -> &mut Self;
+}
+
+impl<Key, T> SetUniqueSharedKeyFromModel<Key> for T
+where
+    Key: HorizontalUniqueKey,
+    T: SetColumn<Key> + SetNestedColumns<<Key::HostColumns as NestTuple>::Nested>,
+    UniqueReferencedModel<Key>: GetColumn<Key::ReferencedColumn>
+        + GetNestedColumns<<Key::ForeignColumns as NestTuple>::Nested>,
+{
+    #[inline]
+    fn set_unique_shared_key_from_model(
+        &mut self,
+        model: &UniqueReferencedModel<Key>,
+    ) -> &mut Self {
+        let columns = model.get_nested_columns();
+        self.set_nested_columns(columns.nested_tuple_into());
+        self.set_column(GetColumn::<Key::ReferencedColumn>::get_column(model))
+    }
+}
+
+/// Extension trait for [`SetUniqueSharedKeyFromModel`] that allows specifying
+/// the key at the method level.
+pub trait SetUniqueSharedKeyFromModelExt: Sized {
+    #[inline]
+    /// Sets `Key` to the value of its referenced column and its host columns
+    /// to the values of its foreign columns in the provided model.
+    fn set_unique_shared_key_from_model_ref<Key>(
+        &mut self,
+        model: &UniqueReferencedModel<Key>,
+    ) -> &mut Self
+    where
+        Key: HorizontalUniqueKey,
+        Self: SetUniqueSharedKeyFromModel<Key>,
+    {
+        <Self as SetUniqueSharedKeyFromModel<Key>>::set_unique_shared_key_from_model(self, model)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets `Key` to the value of its referenced column and its host columns
+    /// to the values of its foreign columns in the provided model.
+    fn set_unique_shared_key_from_model<Key>(mut self, model: &UniqueReferencedModel<Key>) -> Self
+    where
+        Key: HorizontalUniqueKey,
+        Self: SetUniqueSharedKeyFromModel<Key>,
+    {
+        <Self as SetUniqueSharedKeyFromModel<Key>>::set_unique_shared_key_from_model(
+            &mut self, model,
+        );
+        self
+    }
+}
+
+impl<T> SetUniqueSharedKeyFromModelExt for T {}
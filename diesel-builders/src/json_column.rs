@@ -0,0 +1,47 @@
+#![cfg(feature = "serde")]
+//! Submodule providing the `JsonColumn` trait for columns stored as JSON.
+//!
+//! Fields marked with `#[table_model(json)]` keep their Rust type as the
+//! column's [`ValueTyped::ValueType`](crate::ValueTyped), while the generated
+//! `diesel::table!` entry defaults to `Text` (override it with
+//! `#[diesel(sql_type = ...)]`, e.g. for `Jsonb`). The `FromSql`/`ToSql`
+//! implementations of the Rust type can be written in terms of
+//! [`JsonColumn::to_json_text`] and [`JsonColumn::from_json_text`].
+
+use crate::TypedColumn;
+
+/// Trait marking a column whose value is stored as its JSON serialization.
+///
+/// Implemented by the `TableModel` derive for fields marked with
+/// `#[table_model(json)]`.
+pub trait JsonColumn:
+    TypedColumn<ValueType: serde::Serialize + serde::de::DeserializeOwned>
+{
+    #[inline]
+    /// Serializes the provided value into its JSON text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to serialize.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be serialized.
+    fn to_json_text(value: &Self::ValueType) -> serde_json::Result<String> {
+        serde_json::to_string(value)
+    }
+
+    #[inline]
+    /// Deserializes a value from its JSON text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The JSON text to deserialize.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not a valid JSON encoding of the value.
+    fn from_json_text(text: &str) -> serde_json::Result<Self::ValueType> {
+        serde_json::from_str(text)
+    }
+}
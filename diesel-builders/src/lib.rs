@@ -4,7 +4,19 @@
 
 // Error handling helpers
 pub mod builder_error;
-pub use builder_error::{BuilderError, BuilderResult, IncompleteBuilderError};
+pub use builder_error::{
+    BuilderError, BuilderResult, ForeignModelMissing, IncompleteBuilderError, LoadHierarchyError,
+    MandatoryBuilderAlreadySet, MaxCharsExceeded, MissingHierarchyRecordError, OutOfRange,
+    RangeViolation, ValidationErrors,
+};
+pub mod range;
+pub use range::validate_range;
+pub mod text_length;
+#[cfg(feature = "unicode-segmentation")]
+pub use text_length::grapheme_len;
+pub use text_length::{char_len, validate_max_chars};
+pub mod timestamp;
+pub use timestamp::Timestamp;
 
 // Re-exported modules from diesel-additions
 pub mod tables;
@@ -17,7 +29,10 @@ pub use table_models::NestedTableModels;
 pub mod typed;
 pub use typed::*;
 pub mod typed_column;
-pub use typed_column::{DynColumn, TypedColumn};
+pub use typed_column::{ColumnMeta, DynColumn, HasColumnMeta, TypedColumn};
+pub mod json_column;
+#[cfg(feature = "serde")]
+pub use json_column::JsonColumn;
 pub mod get_column;
 pub use get_column::{
     GetColumn, GetColumnExt, MayGetColumn, MayGetColumnExt, TryGetDynamicColumn,
@@ -31,8 +46,8 @@ pub mod table_addition;
 pub use table_addition::{HasTableExt, TableExt};
 pub mod set_column;
 pub use set_column::{
-    MaySetColumn, SetColumn, SetColumnExt, TrySetColumn, TrySetColumnExt, TrySetDynamicColumn,
-    ValidateColumn,
+    AdjustColumn, MaySetColumn, OrderedNewValues, SetColumn, SetColumnExt, TrySetColumn,
+    TrySetColumnExt, TrySetDynamicColumn, ValidateColumn,
 };
 pub mod foreign_key;
 pub use foreign_key::*;
@@ -42,35 +57,57 @@ pub mod ancestors;
 pub mod horizontal_same_as;
 pub mod vertical_same_as_group;
 pub use ancestors::{
-    AncestorOfIndex, Descendant, DescendantOf, DescendantWithSelf, ModelDelete, ModelDescendantExt,
-    ModelFind, ModelUpsert, Root,
+    AncestorOfIndex, CommonAncestor, Descendant, DescendantOf, DescendantWithSelf, IsA,
+    LoadHierarchy, LoadNestedHierarchy, ModelAncestorOf, ModelCountChildren, ModelDelete,
+    ModelDescendantExt, ModelExistsMany, ModelFind, ModelReparent, ModelUpdate, ModelUpsert,
+    ModelUpsertBatch, ReparentNestedHierarchy, Root, UpsertOutcome,
 };
 pub use horizontal_same_as::*;
 pub use vertical_same_as_group::VerticalSameAsGroup;
 pub mod horizontal_same_as_group;
-pub use horizontal_same_as_group::HorizontalSameAsGroup;
+pub use horizontal_same_as_group::{
+    HorizontalSameAsGroup, SetSharedKeyFromModel, SetSharedKeyFromModelExt,
+    SetUniqueSharedKeyFromModel, SetUniqueSharedKeyFromModelExt,
+};
 
 pub mod buildable_table;
 pub mod nested_buildable_tables;
 pub mod table_builder;
 pub use buildable_table::*;
 pub use nested_buildable_tables::*;
-pub use table_builder::{RecursiveBuilderInsert, TableBuilder};
+pub use table_builder::{
+    MapErrTableBuilder, RecursiveBuilderInsert, RecursiveBuilderInsertWithId, TableBuilder,
+    WithAncestor,
+};
 pub mod set_builder;
 pub use set_builder::*;
 pub mod nested_insert;
-pub use nested_insert::Insert;
+pub use nested_insert::{Insert, InsertWithId};
 pub mod builder_bundle;
+#[cfg(feature = "pretty-debug")]
+mod pretty_debug;
 pub use builder_bundle::{
-    BundlableTable, CompletedTableBuilderBundle, RecursiveBundleInsert, TableBuilderBundle,
+    BundlableTable, CompletedTableBuilderBundle, NestedBuilderPresence, NestedInsertLevels,
+    RecursiveBundleInsert, RecursiveBundleInsertWithId, TableBuilderBundle,
 };
 pub mod nested_bundlable_tables;
 pub use nested_bundlable_tables::*;
 pub mod get_foreign;
-pub use get_foreign::{GetForeign, GetForeignExt};
+pub use get_foreign::{ForeignCache, GetForeign, GetForeignChain, GetForeignExt};
 pub mod load_query_builder;
-pub use load_query_builder::{LoadFirst, LoadMany, LoadQueryBuilder, LoadSorted};
+#[cfg(feature = "async")]
+pub use load_query_builder::LoadStream;
+pub use load_query_builder::{
+    LoadChunked, LoadFirst, LoadFirstOrInsert, LoadFirstSorted, LoadGroupedCount, LoadJoined,
+    LoadMany, LoadManyByForeign, LoadManyDistinct, LoadManyWhereIlike, LoadQueryBuilder,
+    LoadRandom, LoadSorted, SortDirection,
+};
+pub mod bind_form;
 pub mod load_nested_query_builder;
+pub use bind_form::{TryBindForm, bind_form};
+
+pub mod nth;
+pub use nth::Nth;
 
 /// Re-export typenum for convenience
 pub mod typenum {
@@ -105,7 +142,8 @@ pub mod prelude {
 
     // Table relationship traits
     pub use crate::ancestors::{
-        Descendant, DescendantOf, ModelDescendantExt, ModelFind, ModelUpsert,
+        Descendant, DescendantOf, IsA, LoadHierarchy, ModelDescendantExt, ModelExistsMany,
+        ModelFind, ModelUpdate, ModelUpsert, ModelUpsertBatch,
     };
     // Core table building traits
     pub use crate::buildable_table::BuildableTable;
@@ -115,23 +153,33 @@ pub mod prelude {
     };
     // Note: Root is NOT exported here to avoid collision with Root macro from
     // diesel_builders_derive
-    pub use crate::horizontal_same_as::HorizontalKey;
+    pub use crate::horizontal_same_as::{HorizontalKey, HorizontalUniqueKey};
     // Builder setter extension traits (always use Ext variants)
     /// Query loading traits
-    pub use crate::load_query_builder::{LoadFirst, LoadMany, LoadSorted};
+    pub use crate::load_query_builder::{
+        LoadChunked, LoadFirst, LoadFirstOrInsert, LoadFirstSorted, LoadGroupedCount, LoadJoined,
+        LoadMany, LoadManyByForeign, LoadManyDistinct, LoadManyWhereIlike, LoadRandom, LoadSorted,
+    };
     pub use crate::{
+        bind_form::TryBindForm,
         builder_bundle::BundlableTable,
         foreign_key::IterForeignKeyExt,
-        get_foreign::GetForeignExt,
+        get_foreign::{GetForeignChain, GetForeignExt},
         get_model::GetModelExt,
+        get_set_columns::SetColumnsFrom,
+        horizontal_same_as_group::{SetSharedKeyFromModelExt, SetUniqueSharedKeyFromModelExt},
         load_nested_query_builder::{LoadNestedFirst, LoadNestedMany, LoadNestedSorted},
-        nested_insert::Insert,
+        nested_insert::{Insert, InsertWithId},
+        nth::Nth,
         set_builder::{
             SetDiscretionaryBuilderExt, SetDiscretionaryModelExt, SetMandatoryBuilderExt,
             TrySetDiscretionaryBuilderExt, TrySetDiscretionaryModelExt, TrySetMandatoryBuilderExt,
         },
-        set_column::{SetColumnExt, TrySetColumnExt, TrySetDynamicColumn, ValidateColumn},
+        set_column::{
+            AdjustColumn, SetColumnExt, TrySetColumnExt, TrySetDynamicColumn, ValidateColumn,
+        },
         table_addition::TableExt,
-        table_builder::TableBuilder,
+        table_builder::{TableBuilder, WithAncestor},
+        typed_column::HasColumnMeta,
     };
 }
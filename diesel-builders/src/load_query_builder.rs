@@ -1,16 +1,30 @@
 //! Module providing a helper trait to construct a load query to be further
 //! specialized and completed by other traits.
 
+use std::{collections::BTreeMap, num::NonZeroUsize};
+
 use diesel::{
-    Table,
+    ExpressionMethods, OptionalExtension, Table,
+    dsl::{Asc, CountStar, Desc, Gt, InnerJoin},
+    expression::{AppearsOnTable, AsExpression, Expression, SelectableExpression, ValidGrouping},
     expression_methods::EqAll,
-    query_dsl::methods::{FilterDsl, LimitDsl, LoadQuery, OffsetDsl, OrderDsl, SelectDsl},
+    query_builder::{AstPass, QueryFragment, QueryId},
+    query_dsl::{
+        JoinWithImplicitOnClause,
+        methods::{
+            DistinctDsl, FilterDsl, GroupByDsl, HavingDsl, LimitDsl, LoadQuery, OffsetDsl,
+            OrderDsl, SelectDsl,
+        },
+    },
+    query_source::Inner,
 };
 use tuplities::prelude::{FlattenNestedTuple, NestedTupleInto};
 
 use crate::{
-    DescendantWithSelf, TableExt,
-    columns::{NonEmptyNestedProjection, TupleToOrder},
+    BuildableTable, BuilderResult, ColumnTyped, Descendant, DescendantOf, DescendantWithSelf,
+    ForeignPrimaryKey, GetColumn, HasPrimaryKeyColumn, Insert, TableBuilder, TableExt, TypedColumn,
+    TypedNestedTuple,
+    columns::{Columns, NonEmptyNestedProjection, NonEmptyProjection, TupleToOrder},
 };
 
 /// The `LoadQueryBuilder` trait allows retrieving the foreign table
@@ -98,6 +112,57 @@ where
     }
 }
 
+/// The `LoadFirstOrInsert` trait allows retrieving the first record matching a
+/// load query, inserting a builder when no such record exists.
+pub trait LoadFirstOrInsert<Conn>: LoadFirst<Conn, Table: BuildableTable> {
+    /// Returns the first record matching the load query, or inserts the
+    /// provided builder if there is none.
+    ///
+    /// The lookup and the insertion run in a single transaction, which
+    /// narrows but, depending on the isolation level of the backend, does not
+    /// close the window for a concurrent insertion of a matching record. A
+    /// unique index on the columns turns such a race into an insertion error.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - A nested tuple of values corresponding to the columns.
+    /// * `builder` - The builder to insert when no record matches.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the queries fail or if the builder cannot be
+    ///   inserted.
+    fn load_first_or_insert(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        builder: TableBuilder<Self::Table>,
+        conn: &mut Conn,
+    ) -> BuilderResult<<Self::Table as TableExt>::Model, <Self::Table as TableExt>::Error>;
+}
+
+impl<Conn, NestedColumns> LoadFirstOrInsert<Conn> for NestedColumns
+where
+    Conn: diesel::Connection,
+    NestedColumns: LoadFirst<Conn, Table: BuildableTable>,
+    TableBuilder<NestedColumns::Table>: Insert<Conn>,
+{
+    fn load_first_or_insert(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        builder: TableBuilder<Self::Table>,
+        conn: &mut Conn,
+    ) -> BuilderResult<<Self::Table as TableExt>::Model, <Self::Table as TableExt>::Error> {
+        use diesel::OptionalExtension;
+        let values: Self::NestedTupleValueType = values.nested_tuple_into();
+        conn.transaction(|conn| {
+            if let Some(model) = Self::load_first(values, conn).optional()? {
+                return Ok(model);
+            }
+            builder.insert(conn)
+        })
+    }
+}
+
 /// The `LoadMany` trait allows retrieving several records from a load query.
 pub trait LoadMany<Conn>: LoadQueryBuilder<Table: TableExt> {
     /// Constructs a load query.
@@ -117,8 +182,43 @@ pub trait LoadMany<Conn>: LoadQueryBuilder<Table: TableExt> {
         values: impl NestedTupleInto<Self::NestedTupleValueType>,
         conn: &mut Conn,
     ) -> diesel::QueryResult<Vec<<Self::Table as TableExt>::Model>>;
+
+    /// Returns the records matching the load query keyed by their primary
+    /// key, iterating in the order of the primary keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - A nested tuple of values corresponding to the foreign
+    ///   columns.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_map(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<
+        BTreeMap<PrimaryKeyValue<Self::Table>, <Self::Table as TableExt>::Model>,
+    >
+    where
+        Self::Table: HasPrimaryKeyColumn,
+        PrimaryKeyValue<Self::Table>: Ord,
+    {
+        Ok(Self::load_many(values, conn)?
+            .into_iter()
+            .map(|model| {
+                (GetColumn::<<Self::Table as Table>::PrimaryKey>::get_column(&model), model)
+            })
+            .collect())
+    }
 }
 
+/// The value of the single-column primary key of the table `T`.
+type PrimaryKeyValue<T> = <<T as Table>::PrimaryKey as ColumnTyped>::ColumnType;
+
 impl<Conn, NestedColumns> LoadMany<Conn> for NestedColumns
 where
     Conn: diesel::connection::LoadConnection,
@@ -135,6 +235,373 @@ where
     }
 }
 
+/// The `LoadManyByForeign` trait allows retrieving the records of a table
+/// referencing a record of another table through a foreign primary key.
+pub trait LoadManyByForeign<Conn>: ForeignPrimaryKey<Table: TableExt> {
+    /// Returns the records whose foreign primary key holds the provided
+    /// primary key of the referenced table, as in `SELECT ... WHERE fk = ?`.
+    ///
+    /// Unlike [`IterForeignKeys`](crate::IterForeignKeys), no model of the
+    /// referenced table is needed, only its primary key.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_pk` - The primary key of the referenced record.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_by_foreign(
+        parent_pk: Self::ValueType,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Vec<<Self::Table as TableExt>::Model>>;
+}
+
+impl<Conn, C> LoadManyByForeign<Conn> for C
+where
+    C: ForeignPrimaryKey<Table: TableExt>,
+    (C,): LoadMany<Conn, Table = C::Table, NestedTupleValueType = (C::ValueType,)>,
+{
+    fn load_by_foreign(
+        parent_pk: C::ValueType,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Vec<<C::Table as TableExt>::Model>> {
+        <(C,) as LoadMany<Conn>>::load_many((parent_pk,), conn)
+    }
+}
+
+/// Predicate matching the values of the text column `C` equal to a value
+/// regardless of case, written as `col ILIKE ?` on Postgres and as
+/// `LOWER(col) = LOWER(?)` on `SQLite`.
+///
+/// The predicate can be used on the backends enabled by the `postgres` and
+/// `sqlite` features.
+#[derive(Debug, Clone)]
+pub struct CaseInsensitiveEq<C> {
+    /// The compared column.
+    column: C,
+    /// The value to match.
+    #[cfg(feature = "sqlite")]
+    value: String,
+    /// The value with the `ILIKE` wildcards escaped, so that it is matched
+    /// literally.
+    #[cfg(feature = "postgres")]
+    pattern: String,
+}
+
+impl<C> CaseInsensitiveEq<C> {
+    /// Creates a predicate matching the values of the column equal to the
+    /// provided value regardless of case.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The compared column.
+    /// * `value` - The value to match.
+    #[must_use]
+    #[cfg_attr(not(any(feature = "sqlite", feature = "postgres")), allow(unused_variables))]
+    pub fn new(column: C, value: &str) -> Self {
+        Self {
+            column,
+            #[cfg(feature = "sqlite")]
+            value: value.to_owned(),
+            #[cfg(feature = "postgres")]
+            pattern: value.chars().fold(
+                String::with_capacity(value.len()),
+                |mut pattern, character| {
+                    if matches!(character, '\\' | '%' | '_') {
+                        pattern.push('\\');
+                    }
+                    pattern.push(character);
+                    pattern
+                },
+            ),
+        }
+    }
+}
+
+impl<C: Expression> Expression for CaseInsensitiveEq<C> {
+    type SqlType = diesel::sql_types::Bool;
+}
+
+impl<C: AppearsOnTable<QS>, QS> AppearsOnTable<QS> for CaseInsensitiveEq<C> {}
+
+impl<C: SelectableExpression<QS>, QS> SelectableExpression<QS> for CaseInsensitiveEq<C> {}
+
+impl<C: ValidGrouping<GB>, GB> ValidGrouping<GB> for CaseInsensitiveEq<C> {
+    type IsAggregate = C::IsAggregate;
+}
+
+impl<C: QueryId> QueryId for CaseInsensitiveEq<C> {
+    type QueryId = CaseInsensitiveEq<C::QueryId>;
+    const HAS_STATIC_QUERY_ID: bool = C::HAS_STATIC_QUERY_ID;
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryFragment<diesel::pg::Pg> for CaseInsensitiveEq<C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, diesel::pg::Pg>) -> diesel::QueryResult<()> {
+        self.column.walk_ast(out.reborrow())?;
+        out.push_sql(" ILIKE ");
+        out.push_bind_param::<diesel::sql_types::Text, _>(&self.pattern)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<C> QueryFragment<diesel::sqlite::Sqlite> for CaseInsensitiveEq<C>
+where
+    C: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast<'b>(
+        &'b self,
+        mut out: AstPass<'_, 'b, diesel::sqlite::Sqlite>,
+    ) -> diesel::QueryResult<()> {
+        out.push_sql("LOWER(");
+        self.column.walk_ast(out.reborrow())?;
+        out.push_sql(") = LOWER(");
+        out.push_bind_param::<diesel::sql_types::Text, _>(&self.value)?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The query selecting the records of the table of `C` whose value of `C`
+/// equals the provided value regardless of case.
+type IexactQuery<C> = <<<C as diesel::Column>::Table as SelectDsl<
+    <<C as diesel::Column>::Table as Table>::AllColumns,
+>>::Output as FilterDsl<CaseInsensitiveEq<C>>>::Output;
+
+/// The `LoadManyWhereIlike` trait allows retrieving the records of a table
+/// whose text column matches a value regardless of case.
+pub trait LoadManyWhereIlike<Conn>: TypedColumn<Table: TableExt> {
+    /// Returns the records whose column equals the provided value ignoring
+    /// case, as in `SELECT ... WHERE col ILIKE ?` on Postgres and
+    /// `SELECT ... WHERE LOWER(col) = LOWER(?)` on `SQLite`, which require
+    /// the `postgres` and `sqlite` features respectively.
+    ///
+    /// The `%` and `_` in the value are matched literally. On Postgres, the
+    /// comparison can be served by `citext` columns and trigram indexes,
+    /// while on `SQLite` an index on the column itself cannot serve it: on
+    /// large tables, declare an index on the expression instead, as in
+    /// `CREATE INDEX ... ON t (LOWER(col))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to match.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn find_by_iexact(
+        value: &str,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Vec<<Self::Table as TableExt>::Model>>;
+}
+
+impl<Conn, C> LoadManyWhereIlike<Conn> for C
+where
+    C: TypedColumn<Table: TableExt> + diesel::Expression<SqlType = diesel::sql_types::Text>,
+    C::Table: SelectDsl<<C::Table as Table>::AllColumns>,
+    <C::Table as SelectDsl<<C::Table as Table>::AllColumns>>::Output:
+        FilterDsl<CaseInsensitiveEq<C>>,
+    IexactQuery<C>: diesel::query_dsl::RunQueryDsl<Conn>,
+    for<'query> IexactQuery<C>: LoadQuery<'query, Conn, <C::Table as TableExt>::Model>,
+{
+    fn find_by_iexact(
+        value: &str,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Vec<<C::Table as TableExt>::Model>> {
+        let query = FilterDsl::filter(
+            SelectDsl::select(C::Table::default(), C::Table::all_columns()),
+            CaseInsensitiveEq::new(C::default(), value),
+        );
+        diesel::query_dsl::RunQueryDsl::load::<<C::Table as TableExt>::Model>(query, conn)
+    }
+}
+
+diesel::define_sql_function! {
+    /// The SQL `RANDOM` function, returning a random value for each row, as
+    /// named by both SQLite and Postgres.
+    pub fn random() -> diesel::sql_types::Double;
+}
+
+/// The `LoadRandom` trait allows retrieving a random sample of the records of
+/// a table.
+pub trait LoadRandom<Conn>: TableExt {
+    /// Returns up to `limit` records picked at random, as in
+    /// `SELECT ... ORDER BY RANDOM() LIMIT n`.
+    ///
+    /// The database draws a random value for every record of the table and
+    /// sorts all of them before applying the limit, so the query scans the
+    /// whole table and is slow on large tables, whatever the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum number of records to return.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_random(limit: i64, conn: &mut Conn) -> diesel::QueryResult<Vec<Self::Model>>;
+}
+
+impl<Conn, T> LoadRandom<Conn> for T
+where
+    Conn: diesel::connection::LoadConnection,
+    T: TableExt + OrderDsl<random>,
+    <T as OrderDsl<random>>::Output: LimitDsl,
+    for<'query> <<T as OrderDsl<random>>::Output as LimitDsl>::Output:
+        LoadQuery<'query, Conn, T::Model>,
+{
+    fn load_random(limit: i64, conn: &mut Conn) -> diesel::QueryResult<Vec<T::Model>> {
+        let table: T = Default::default();
+        let query = LimitDsl::limit(OrderDsl::order(table, random()), limit);
+        diesel::query_dsl::RunQueryDsl::load::<T::Model>(query, conn)
+    }
+}
+
+/// The query selecting the records of `T` joined with the records of its
+/// ancestor `A` sharing a primary key.
+type LoadJoinedQuery<T, A> = <<InnerJoin<T, A> as SelectDsl<(
+    <T as Table>::AllColumns,
+    <A as Table>::AllColumns,
+)>>::Output as FilterDsl<
+    diesel::dsl::Eq<<T as Table>::PrimaryKey, PrimaryKeyValue<T>>,
+>>::Output;
+
+/// The `LoadJoined` trait allows retrieving the record of a descendant table
+/// together with the record of one of its ancestors in a single query.
+pub trait LoadJoined<Ancestor: Descendant, Conn>:
+    DescendantOf<Ancestor> + HasPrimaryKeyColumn
+{
+    /// Returns the record of this table with the provided primary key along
+    /// with the record of the ancestor table sharing it, issuing an `INNER
+    /// JOIN` on the shared primary key.
+    ///
+    /// The join relies on the `joinable!` and
+    /// `allow_tables_to_appear_in_same_query!` declarations generated by the
+    /// `TableModel` derive for each ancestor.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The primary key of the records to load.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails or if no matching record is found.
+    fn load_with_ancestor(
+        id: PrimaryKeyValue<Self>,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<(Self::Model, Ancestor::Model)>;
+}
+
+impl<Conn, T, A> LoadJoined<A, Conn> for T
+where
+    Conn: diesel::connection::LoadConnection,
+    A: Descendant,
+    T: DescendantOf<A> + HasPrimaryKeyColumn + JoinWithImplicitOnClause<A, Inner>,
+    <T as Table>::PrimaryKey: ExpressionMethods,
+    PrimaryKeyValue<T>: AsExpression<<<T as Table>::PrimaryKey as diesel::Expression>::SqlType>,
+    InnerJoin<T, A>: SelectDsl<(<T as Table>::AllColumns, <A as Table>::AllColumns)>,
+    <InnerJoin<T, A> as SelectDsl<(<T as Table>::AllColumns, <A as Table>::AllColumns)>>::Output:
+        FilterDsl<diesel::dsl::Eq<<T as Table>::PrimaryKey, PrimaryKeyValue<T>>>,
+    LoadJoinedQuery<T, A>: LimitDsl + diesel::query_dsl::RunQueryDsl<Conn>,
+    for<'query> <LoadJoinedQuery<T, A> as LimitDsl>::Output:
+        LoadQuery<'query, Conn, (T::Model, A::Model)>,
+{
+    fn load_with_ancestor(
+        id: PrimaryKeyValue<T>,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<(T::Model, A::Model)> {
+        let primary_key: <T as Table>::PrimaryKey = Default::default();
+        let query = FilterDsl::filter(
+            SelectDsl::select(
+                diesel::QueryDsl::inner_join(T::default(), A::default()),
+                (T::all_columns(), A::all_columns()),
+            ),
+            primary_key.eq(id),
+        )
+        .limit(1);
+        diesel::query_dsl::RunQueryDsl::get_result::<(T::Model, A::Model)>(query, conn)
+    }
+}
+
+/// The `LoadStream` trait allows streaming the records of a load query from
+/// an asynchronous connection.
+///
+/// The returned future resolves to a stream which mutably borrows the
+/// connection for `'conn`: no other query can run on the connection until the
+/// stream is dropped. The values are moved into the query, so the stream does
+/// not borrow them.
+#[cfg(feature = "async")]
+pub trait LoadStream<Conn: diesel_async::AsyncConnectionCore>:
+    LoadQueryBuilder<Table: TableExt>
+{
+    /// Returns a stream over the records matching the load query.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - A nested tuple of values corresponding to the foreign
+    ///   columns.
+    /// * `conn` - A mutable reference to the asynchronous Diesel connection to
+    ///   use for the query, borrowed for as long as the stream is alive.
+    ///
+    /// # Errors
+    ///
+    /// * The future resolves to an error if the query cannot be started, and
+    ///   each item of the stream may be an error if loading that record fails.
+    fn load_stream<'conn>(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        conn: &'conn mut Conn,
+    ) -> impl Future<
+        Output = diesel::QueryResult<
+            impl futures_util::Stream<Item = diesel::QueryResult<<Self::Table as TableExt>::Model>>
+            + Send
+            + 'conn,
+        >,
+    > + Send
+    + 'conn;
+}
+
+#[cfg(feature = "async")]
+impl<Conn, NestedColumns> LoadStream<Conn> for NestedColumns
+where
+    Conn: diesel_async::AsyncConnectionCore,
+    NestedColumns: LoadQueryBuilder + NonEmptyNestedProjection<Table: TableExt>,
+    NestedColumns::LoadQuery: diesel_async::RunQueryDsl<Conn>,
+    for<'query> Self::LoadQuery:
+        diesel_async::methods::LoadQuery<'query, Conn, <Self::Table as TableExt>::Model> + 'query,
+    <NestedColumns::Table as TableExt>::Model: Send + 'static,
+{
+    fn load_stream<'conn>(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        conn: &'conn mut Conn,
+    ) -> impl Future<
+        Output = diesel::QueryResult<
+            impl futures_util::Stream<Item = diesel::QueryResult<<Self::Table as TableExt>::Model>>
+            + Send
+            + 'conn,
+        >,
+    > + Send
+    + 'conn {
+        let query = Self::load_query(values);
+        diesel_async::RunQueryDsl::load_stream::<<Self::Table as TableExt>::Model>(query, conn)
+    }
+}
+
 /// The `LoadSorted` trait allows retrieving several records from a load
 /// query, sorted by a given expression.
 pub trait LoadSorted<Conn>: LoadQueryBuilder<Table: TableExt> {
@@ -236,3 +703,256 @@ where
         diesel::query_dsl::RunQueryDsl::load::<<Self::Table as TableExt>::Model>(query, conn)
     }
 }
+
+/// The `LoadChunked` trait allows processing the records of a load query in
+/// fixed-size chunks, without holding all of them in memory at once.
+pub trait LoadChunked<Conn>: LoadPaginated<Conn> {
+    /// Loads the records matching the load query sorted by primary key,
+    /// `chunk_size` records at a time, passing each chunk to `f` until the
+    /// records are exhausted.
+    ///
+    /// The chunks are fetched with `LIMIT` and `OFFSET`, which keeps this
+    /// method available whatever the primary key of the table, but has two
+    /// costs that keyset pagination (`WHERE pk > last_pk`) would avoid: the
+    /// database still scans the skipped rows, so each chunk is slower than
+    /// the previous one, and rows inserted or deleted by `f` or concurrently
+    /// between two chunks shift the offsets, so that records may be skipped
+    /// or seen twice. Run the method within a transaction with a suitable
+    /// isolation level when the table may change while it is being read.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to filter the load query by.
+    /// * `chunk_size` - The maximum number of records in each chunk.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   queries.
+    /// * `f` - The callback processing each chunk.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if any of
+    ///   the queries fails, or the first error returned by `f`, in which case
+    ///   no further chunk is loaded.
+    fn load_chunked<F>(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        chunk_size: NonZeroUsize,
+        conn: &mut Conn,
+        f: F,
+    ) -> diesel::QueryResult<()>
+    where
+        F: FnMut(Vec<<Self::Table as TableExt>::Model>) -> diesel::QueryResult<()>;
+}
+
+impl<Conn, NestedColumns> LoadChunked<Conn> for NestedColumns
+where
+    NestedColumns: LoadPaginated<Conn, NestedTupleValueType: Clone>,
+{
+    fn load_chunked<F>(
+        values: impl NestedTupleInto<Self::NestedTupleValueType>,
+        chunk_size: NonZeroUsize,
+        conn: &mut Conn,
+        mut f: F,
+    ) -> diesel::QueryResult<()>
+    where
+        F: FnMut(Vec<<Self::Table as TableExt>::Model>) -> diesel::QueryResult<()>,
+    {
+        let values: Self::NestedTupleValueType = values.nested_tuple_into();
+        let limit = i64::try_from(chunk_size.get()).unwrap_or(i64::MAX);
+        let mut offset: i64 = 0;
+        loop {
+            let chunk = Self::load_many_paginated(values.clone(), offset, limit, conn)?;
+            let loaded = i64::try_from(chunk.len()).unwrap_or(i64::MAX);
+            if loaded == 0 {
+                return Ok(());
+            }
+            f(chunk)?;
+            if loaded < limit {
+                return Ok(());
+            }
+            offset = offset.saturating_add(loaded);
+        }
+    }
+}
+
+/// The direction in which records are sorted by a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// From the smallest to the largest value, as in `ORDER BY c ASC`.
+    Ascending,
+    /// From the largest to the smallest value, as in `ORDER BY c DESC`.
+    Descending,
+}
+
+/// The `LoadFirstSorted` trait allows retrieving the record of a table holding
+/// the smallest or largest value of a column.
+pub trait LoadFirstSorted<Conn>: TypedColumn<Table: TableExt> {
+    /// Returns the first record sorted by the column in the provided
+    /// direction, as in `SELECT ... ORDER BY c DESC LIMIT 1`, or `None` if the
+    /// table is empty. When several records share the extreme value, which
+    /// one is returned is up to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The direction in which the records are sorted.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_first_sorted(
+        direction: SortDirection,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Option<<Self::Table as TableExt>::Model>>;
+}
+
+impl<Conn, C> LoadFirstSorted<Conn> for C
+where
+    Conn: diesel::connection::LoadConnection,
+    C: TypedColumn<Table: TableExt> + ExpressionMethods,
+    C::Table: OrderDsl<Asc<C>> + OrderDsl<Desc<C>>,
+    <C::Table as OrderDsl<Asc<C>>>::Output: LimitDsl,
+    <C::Table as OrderDsl<Desc<C>>>::Output: LimitDsl,
+    for<'query> <<C::Table as OrderDsl<Asc<C>>>::Output as LimitDsl>::Output:
+        LoadQuery<'query, Conn, <C::Table as TableExt>::Model>,
+    for<'query> <<C::Table as OrderDsl<Desc<C>>>::Output as LimitDsl>::Output:
+        LoadQuery<'query, Conn, <C::Table as TableExt>::Model>,
+{
+    fn load_first_sorted(
+        direction: SortDirection,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Option<<C::Table as TableExt>::Model>> {
+        let table: C::Table = Default::default();
+        match direction {
+            SortDirection::Ascending => {
+                let query = LimitDsl::limit(OrderDsl::order(table, C::default().asc()), 1);
+                diesel::query_dsl::RunQueryDsl::get_result(query, conn).optional()
+            }
+            SortDirection::Descending => {
+                let query = LimitDsl::limit(OrderDsl::order(table, C::default().desc()), 1);
+                diesel::query_dsl::RunQueryDsl::get_result(query, conn).optional()
+            }
+        }
+    }
+}
+
+/// The `LoadGroupedCount` trait allows counting the records of a table grouped
+/// by the values of a column.
+pub trait LoadGroupedCount<Conn>: TypedColumn {
+    /// Returns each distinct value of the column alongside the number of
+    /// records holding it, as in `SELECT c, COUNT(*) ... GROUP BY c`.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_grouped_count(conn: &mut Conn) -> diesel::QueryResult<Vec<(Self::ColumnType, i64)>>;
+
+    /// Returns each distinct value of the column held by more than
+    /// `min_exclusive` records, alongside the number of such records, as in
+    /// `SELECT c, COUNT(*) ... GROUP BY c HAVING COUNT(*) > n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_exclusive` - The number of records a value must exceed.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_grouped_count_having_gt(
+        min_exclusive: i64,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Vec<(Self::ColumnType, i64)>>;
+}
+
+impl<Conn, C> LoadGroupedCount<Conn> for C
+where
+    Conn: diesel::connection::LoadConnection,
+    C: TypedColumn,
+    C::Table: GroupByDsl<C>,
+    <C::Table as GroupByDsl<C>>::Output: SelectDsl<(C, CountStar)>,
+    for<'query> <<C::Table as GroupByDsl<C>>::Output as SelectDsl<(C, CountStar)>>::Output:
+        LoadQuery<'query, Conn, (C::ColumnType, i64)>,
+    <C::Table as GroupByDsl<C>>::Output: HavingDsl<Gt<CountStar, i64>>,
+    <<C::Table as GroupByDsl<C>>::Output as HavingDsl<Gt<CountStar, i64>>>::Output:
+        SelectDsl<(C, CountStar)>,
+    for<'query> <<<C::Table as GroupByDsl<C>>::Output as HavingDsl<Gt<CountStar, i64>>>::Output as SelectDsl<
+        (C, CountStar),
+    >>::Output: LoadQuery<'query, Conn, (C::ColumnType, i64)>,
+{
+    fn load_grouped_count(conn: &mut Conn) -> diesel::QueryResult<Vec<(C::ColumnType, i64)>> {
+        let table: C::Table = Default::default();
+        let query = SelectDsl::select(
+            GroupByDsl::group_by(table, C::default()),
+            (C::default(), diesel::dsl::count_star()),
+        );
+        diesel::query_dsl::RunQueryDsl::load::<(C::ColumnType, i64)>(query, conn)
+    }
+
+    fn load_grouped_count_having_gt(
+        min_exclusive: i64,
+        conn: &mut Conn,
+    ) -> diesel::QueryResult<Vec<(C::ColumnType, i64)>> {
+        let table: C::Table = Default::default();
+        let query = SelectDsl::select(
+            HavingDsl::having(
+                GroupByDsl::group_by(table, C::default()),
+                diesel::dsl::count_star().gt(min_exclusive),
+            ),
+            (C::default(), diesel::dsl::count_star()),
+        );
+        diesel::query_dsl::RunQueryDsl::load::<(C::ColumnType, i64)>(query, conn)
+    }
+}
+
+/// The flat tuple of values loaded for the projection `P`.
+type ProjectionValues<P> =
+    <<<P as Columns>::Nested as TypedNestedTuple>::NestedTupleColumnType as FlattenNestedTuple>::Flattened;
+
+/// The `LoadManyDistinct` trait allows retrieving the distinct values of a
+/// projection of columns of a table.
+pub trait LoadManyDistinct<Conn>: NonEmptyProjection {
+    /// Returns the distinct values of the projection, as in
+    /// `SELECT DISTINCT c1, c2, ... FROM table`.
+    ///
+    /// Deduplication happens in the database, so whether two values are
+    /// considered equal follows the SQL semantics of the column types (e.g.
+    /// the collation of text columns) rather than their Rust `Eq` or `Hash`
+    /// implementations. The order of the returned values is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   query
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `diesel::QueryResult` which may contain an error if the
+    ///   query fails.
+    fn load_distinct(conn: &mut Conn) -> diesel::QueryResult<Vec<ProjectionValues<Self>>>;
+}
+
+impl<Conn, P> LoadManyDistinct<Conn> for P
+where
+    Conn: diesel::connection::LoadConnection,
+    P: NonEmptyProjection + diesel::Expression + Default,
+    <P as NonEmptyProjection>::Table: SelectDsl<P>,
+    <<P as NonEmptyProjection>::Table as SelectDsl<P>>::Output: DistinctDsl,
+    for<'query> <<<P as NonEmptyProjection>::Table as SelectDsl<P>>::Output as DistinctDsl>::Output:
+        LoadQuery<'query, Conn, ProjectionValues<P>>,
+{
+    fn load_distinct(conn: &mut Conn) -> diesel::QueryResult<Vec<ProjectionValues<P>>> {
+        let table: <P as NonEmptyProjection>::Table = Default::default();
+        let query = DistinctDsl::distinct(SelectDsl::select(table, P::default()));
+        diesel::query_dsl::RunQueryDsl::load::<ProjectionValues<P>>(query, conn)
+    }
+}
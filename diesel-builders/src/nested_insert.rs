@@ -37,3 +37,34 @@ pub trait Insert<Conn>: HasTableExt<Table: DescendantWithSelf> {
         conn: &mut Conn,
     ) -> BuilderResult<<<Self::Table as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::NestedModels, <Self::Table as TableExt>::Error>;
 }
+
+/// Trait defining the insertion of a builder into the database with a
+/// caller-provided primary key, such as when migrating existing records.
+///
+/// The provided primary key is included in the insert of the root table of
+/// the hierarchy, which must have a single-column primary key that is not
+/// part of its new record, as is the case for tables with a `surrogate_key`.
+/// The descendant tables receive it as usual from their ancestors.
+pub trait InsertWithId<Conn>: Insert<Conn> {
+    /// The primary key value of the root table of the hierarchy.
+    type Id;
+
+    /// Insert the builder's data into the database using the provided
+    /// connection and primary key.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The primary key of the record to insert.
+    /// * `conn` - A mutable reference to the database connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insertion fails or if any database constraints
+    /// are violated, such as a record with the same primary key already
+    /// existing.
+    fn insert_with_id(
+        self,
+        id: Self::Id,
+        conn: &mut Conn,
+    ) -> BuilderResult<<Self::Table as TableExt>::Model, <Self::Table as TableExt>::Error>;
+}
@@ -0,0 +1,32 @@
+//! Submodule providing the `Nth` trait, accessing the elements of a tuple,
+//! such as a loaded projection, by a `typenum` index rather than by field
+//! position.
+
+use tuplities::prelude::TupleIndex;
+
+/// Extension trait accessing the elements of a tuple by a `typenum` index.
+///
+/// Code generic over the arity of a projection, such as macro-generated code,
+/// cannot spell `.1`, while `nth::<U1>()` only requires the tuple to implement
+/// [`TupleIndex`] for the index.
+///
+/// # Example
+///
+/// ```rust
+/// use diesel_builders::{prelude::*, typenum::U1};
+///
+/// let projection = (1_i32, "Buddy".to_owned(), true);
+/// assert_eq!(projection.nth::<U1>(), "Buddy");
+/// ```
+pub trait Nth {
+    /// Returns a reference to the element of the tuple at the index `Idx`.
+    #[inline]
+    fn nth<Idx>(&self) -> &<Self as TupleIndex<Idx>>::Element
+    where
+        Self: TupleIndex<Idx>,
+    {
+        self.tuple_index()
+    }
+}
+
+impl<T> Nth for T {}
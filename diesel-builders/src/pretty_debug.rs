@@ -0,0 +1,88 @@
+//! Submodule providing readable `Debug` implementations for builders, which
+//! print every column by name as `table { column: value, other: <unset> }`
+//! instead of the raw nested tuples of options.
+
+use core::fmt;
+
+use crate::{
+    BuildableTable, DescendantWithSelf, NestedTables, TableBuilder, TableBuilderBundle, TableExt,
+    builder_bundle::BundlableTableExt,
+};
+
+/// Marker printed in place of the value of an unset column.
+struct Unset;
+
+impl fmt::Debug for Unset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<unset>")
+    }
+}
+
+impl<T> fmt::Debug for TableBuilderBundle<T>
+where
+    T: BundlableTableExt,
+    Self: sealed::DebugColumns<T::NewRecord>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(T::TABLE_NAME);
+        <Self as sealed::DebugColumns<T::NewRecord>>::debug_columns(self, &mut debug);
+        debug.finish()
+    }
+}
+
+impl<T> fmt::Debug for TableBuilder<T>
+where
+    T: BuildableTable,
+    Self: sealed::DebugColumns<
+            <<T as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(T::TABLE_NAME);
+        <Self as sealed::DebugColumns<
+            <<T as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >>::debug_columns(self, &mut debug);
+        debug.finish()
+    }
+}
+
+/// Sealed module for private traits.
+mod sealed {
+    use core::fmt;
+
+    use super::Unset;
+    use crate::{MayGetColumn, NestedColumns, TypedColumn};
+
+    /// Trait adding the columns of a nested tuple to a debug struct.
+    pub trait DebugColumns<Columns: NestedColumns> {
+        /// Adds a field for each column, marking the unset ones.
+        fn debug_columns(&self, debug: &mut fmt::DebugStruct<'_, '_>);
+    }
+
+    impl<M, CHead> DebugColumns<(CHead,)> for M
+    where
+        M: MayGetColumn<CHead>,
+        CHead: TypedColumn<ColumnType: fmt::Debug>,
+    {
+        fn debug_columns(&self, debug: &mut fmt::DebugStruct<'_, '_>) {
+            match self.may_get_column_ref() {
+                Some(value) => debug.field(CHead::NAME, value),
+                None => debug.field(CHead::NAME, &Unset),
+            };
+        }
+    }
+
+    impl<M, CHead, CTail> DebugColumns<(CHead, CTail)> for M
+    where
+        M: DebugColumns<(CHead,)> + DebugColumns<CTail>,
+        CHead: TypedColumn,
+        CTail: NestedColumns,
+        (CHead,): NestedColumns,
+        (CHead, CTail): NestedColumns,
+    {
+        fn debug_columns(&self, debug: &mut fmt::DebugStruct<'_, '_>) {
+            <Self as DebugColumns<(CHead,)>>::debug_columns(self, debug);
+            <Self as DebugColumns<CTail>>::debug_columns(self, debug);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Submodule providing the check generated for the columns marked as
+//! `#[table_model(range(...))]`.
+
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    TableExt, TypedColumn,
+    builder_error::{OutOfRange, RangeViolation},
+};
+
+/// Checks that the value of column `C` lies within `range`, whose bounds may
+/// be inclusive, exclusive or open-ended.
+///
+/// Values which cannot be compared to a bound, such as a `NaN` float, are
+/// rejected.
+///
+/// # Examples
+///
+/// ```
+/// use diesel_builders::{RangeViolation, prelude::*, validate_range};
+///
+/// #[derive(Queryable, Selectable, Identifiable, TableModel)]
+/// #[diesel(table_name = people)]
+/// #[table_model(surrogate_key)]
+/// pub struct Person {
+///     id: i32,
+///     age: i32,
+/// }
+///
+/// fn main() {
+///     assert!(validate_range::<people::age, _>(&30, 0..=120).is_ok());
+///     let error = validate_range::<people::age, _>(&121, 0..=120).unwrap_err();
+///     assert_eq!(error.violation, RangeViolation::Above { inclusive: true });
+///     assert_eq!(error.to_string(), "Column `people.age` must be smaller than or equal to 120");
+/// }
+/// ```
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * `range` - The range of the allowed values.
+///
+/// # Errors
+///
+/// Returns an [`OutOfRange`] error reporting the violated bound if the value
+/// lies outside of the range.
+pub fn validate_range<C, V>(value: &V, range: impl RangeBounds<V>) -> Result<(), OutOfRange>
+where
+    C: TypedColumn<Table: TableExt>,
+    V: PartialOrd + Display,
+{
+    let out_of_range = |violation, bound: &V| {
+        OutOfRange {
+            table_name: <C::Table as TableExt>::TABLE_NAME,
+            column_name: C::NAME,
+            violation,
+            bound: bound.to_string(),
+        }
+    };
+
+    match range.start_bound() {
+        Bound::Included(start)
+            if !matches!(value.partial_cmp(start), Some(Ordering::Greater | Ordering::Equal)) =>
+        {
+            return Err(out_of_range(RangeViolation::Below { inclusive: true }, start));
+        }
+        Bound::Excluded(start) if value.partial_cmp(start) != Some(Ordering::Greater) => {
+            return Err(out_of_range(RangeViolation::Below { inclusive: false }, start));
+        }
+        _ => {}
+    }
+
+    match range.end_bound() {
+        Bound::Included(end)
+            if !matches!(value.partial_cmp(end), Some(Ordering::Less | Ordering::Equal)) =>
+        {
+            Err(out_of_range(RangeViolation::Above { inclusive: true }, end))
+        }
+        Bound::Excluded(end) if value.partial_cmp(end) != Some(Ordering::Less) => {
+            Err(out_of_range(RangeViolation::Above { inclusive: false }, end))
+        }
+        _ => Ok(()),
+    }
+}
@@ -1,11 +1,12 @@
 //! Submodule providing the `SetBuilder` trait.
 
-use diesel::Table;
+use diesel::{Column, Identifiable, Table};
 use tuplities::prelude::NestedTupleInto;
 
 use crate::{
-    BuildableTable, DiscretionarySameAsIndex, ForeignPrimaryKey, GetColumnExt, GetNestedColumns,
-    HasTableExt, MandatorySameAsIndex, SetColumn, SetNestedColumns, TableBuilder, TableExt,
+    BuildableTable, BuilderError, BuilderResult, DiscretionarySameAsIndex, ForeignModelMissing,
+    ForeignPrimaryKey, GetColumnExt, GetNestedColumns, HasTableExt, MandatoryBuilderAlreadySet,
+    MandatorySameAsIndex, ModelFind, SetColumn, SetNestedColumns, TableBuilder, TableExt,
     TrySetColumn, TrySetNestedColumns, TypedColumn, ValidateColumn, ValidateNestedColumns,
 };
 
@@ -26,6 +27,18 @@ pub trait SetMandatoryBuilder<Key: MandatorySameAsIndex<ReferencedTable: Buildab
     fn set_mandatory_builder(&mut self, builder: TableBuilder<Key::ReferencedTable>) -> &mut Self;
 }
 
+/// Trait reporting whether a mandatory triangular builder relationship is
+/// already set.
+///
+/// # Type Parameters
+///
+/// * `Key`: The foreign key relationship defining the mandatory link
+pub trait HasMandatoryBuilder<Key: MandatorySameAsIndex<ReferencedTable: BuildableTable>> {
+    /// Returns whether a mandatory builder is set for the specified
+    /// relationship.
+    fn has_mandatory_builder(&self) -> bool;
+}
+
 /// Trait for setting a discretionary triangular builder relationship.
 ///
 /// Discretionary relationships allow optional related records. You can either
@@ -203,6 +216,59 @@ pub trait SetMandatoryBuilderExt: Sized {
         self.set_mandatory_builder_ref::<Key>(builder);
         self
     }
+
+    /// Sets the mandatory builder for the specified column, unless one is
+    /// already set.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Key`: The specific mandatory relationship to set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a builder is already set for the relationship, in
+    /// which case the provided builder is dropped and `self` is left as is.
+    #[inline]
+    fn try_set_mandatory_builder_once_ref<Key>(
+        &mut self,
+        builder: TableBuilder<Key::ReferencedTable>,
+    ) -> Result<&mut Self, MandatoryBuilderAlreadySet>
+    where
+        Key: MandatorySameAsIndex<ReferencedTable: BuildableTable>,
+        Self: SetMandatoryBuilder<Key> + HasMandatoryBuilder<Key>,
+    {
+        if <Self as HasMandatoryBuilder<Key>>::has_mandatory_builder(self) {
+            return Err(MandatoryBuilderAlreadySet {
+                table_name: <Key::Table as TableExt>::TABLE_NAME,
+                field_name: Key::NAME,
+                referenced_table_name: <Key::ReferencedTable as TableExt>::TABLE_NAME,
+            });
+        }
+        Ok(self.set_mandatory_builder_ref::<Key>(builder))
+    }
+
+    /// Sets the mandatory builder for the specified column, unless one is
+    /// already set (consuming version).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Key`: The specific mandatory relationship to set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a builder is already set for the relationship.
+    #[inline]
+    fn try_set_mandatory_builder_once<Key>(
+        mut self,
+        builder: TableBuilder<Key::ReferencedTable>,
+    ) -> Result<Self, MandatoryBuilderAlreadySet>
+    where
+        Key: MandatorySameAsIndex<ReferencedTable: BuildableTable>,
+        Self: SetMandatoryBuilder<Key> + HasMandatoryBuilder<Key>,
+    {
+        self.try_set_mandatory_builder_once_ref::<Key>(builder)?;
+        Ok(self)
+    }
 }
 
 impl<T> SetMandatoryBuilderExt for T {}
@@ -422,6 +488,49 @@ pub trait TrySetDiscretionaryModelExt: Sized {
         self.try_set_discretionary_model_ref::<Key>(model)?;
         Ok(self)
     }
+
+    /// Attempt to set the discretionary model for the specified column after
+    /// checking that the record of the model still exists, so that a stale
+    /// model is reported before the insertion rather than as a raw foreign
+    /// key violation.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model to link to.
+    /// * `conn` - A mutable reference to the Diesel connection to use for the
+    ///   existence check.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a [`BuilderError::ForeignModelMissing`] if the record of the
+    ///   model does not exist.
+    /// * Returns a [`BuilderError::Diesel`] if the existence check fails.
+    /// * Returns a [`BuilderError::Validation`] if the model cannot be set for
+    ///   the discretionary relationship.
+    fn try_set_discretionary_model_verified<Key, Conn>(
+        self,
+        model: &<Key::ReferencedTable as TableExt>::Model,
+        conn: &mut Conn,
+    ) -> BuilderResult<Self, <Self::Table as TableExt>::Error>
+    where
+        Key: DiscretionarySameAsIndex<Table: TableExt>,
+        Self: TrySetDiscretionaryModel<Key>,
+        <Key::ReferencedTable as TableExt>::Model: ModelFind<Conn>,
+        for<'query> &'query <Key::ReferencedTable as TableExt>::Model: Identifiable,
+    {
+        if !<<Key::ReferencedTable as TableExt>::Model as ModelFind<Conn>>::exists(
+            model.id(),
+            conn,
+        )? {
+            return Err(BuilderError::ForeignModelMissing(ForeignModelMissing {
+                table_name: <Key::Table as TableExt>::TABLE_NAME,
+                field_name: Key::NAME,
+                referenced_table_name: <Key::ReferencedTable as TableExt>::TABLE_NAME,
+            }));
+        }
+        <Self as TrySetDiscretionaryModelExt>::try_set_discretionary_model::<Key>(self, model)
+            .map_err(BuilderError::Validation)
+    }
 }
 
 impl<T> TrySetDiscretionaryModelExt for T {}
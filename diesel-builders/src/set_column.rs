@@ -1,8 +1,8 @@
 //! Submodule providing the `SetColumn` trait.
 
 use crate::{
-    AncestorOfIndex, BuildableTable, ColumnTyped, DescendantWithSelf, DynColumn, NestedTables,
-    OptionalRef, TableBuilder, TableExt, TypedColumn, ValueTyped,
+    AncestorOfIndex, BuildableTable, ColumnTyped, DescendantWithSelf, DynColumn, MayGetColumn,
+    NestedTables, OptionalRef, TableBuilder, TableExt, TypedColumn, ValueTyped,
     builder_error::DynamicColumnError,
 };
 
@@ -111,6 +111,105 @@ where
     }
 }
 
+/// Opt-in wrapper around new values which records the order in which their
+/// columns are assigned.
+///
+/// Unlike the new values themselves, whose layout follows the declaration
+/// order of the columns, the wrapper keeps track of the assignment sequence.
+/// Implementing [`ValidateColumn`] on the wrapper gives access to
+/// [`set_order`](Self::set_order) from
+/// [`validate_column_in_context`](ValidateColumn::validate_column_in_context),
+/// so cross-column validation can depend on which columns were set first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OrderedNewValues<V> {
+    /// The wrapped new values.
+    values: V,
+    /// The names of the assigned columns, from the least to the most recently
+    /// assigned.
+    order: Vec<&'static str>,
+}
+
+impl<V> OrderedNewValues<V> {
+    /// Wraps the provided new values, starting with an empty set order.
+    #[inline]
+    #[must_use]
+    pub fn new(values: V) -> Self {
+        Self { values, order: Vec::new() }
+    }
+
+    /// Returns a reference to the wrapped new values.
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// Returns the wrapped new values, discarding the set order.
+    #[inline]
+    #[must_use]
+    pub fn into_values(self) -> V {
+        self.values
+    }
+
+    /// Returns the names of the assigned columns, from the least to the most
+    /// recently assigned. A column assigned more than once only appears at
+    /// the position of its latest assignment.
+    #[inline]
+    #[must_use]
+    pub fn set_order(&self) -> &[&'static str] {
+        &self.order
+    }
+
+    /// Records the assignment of the provided column.
+    fn record(&mut self, column_name: &'static str) {
+        self.order.retain(|name| *name != column_name);
+        self.order.push(column_name);
+    }
+}
+
+impl<V, C> SetColumn<C> for OrderedNewValues<V>
+where
+    V: SetColumn<C>,
+    C: TypedColumn,
+{
+    #[inline]
+    fn set_column(&mut self, value: impl Into<C::ColumnType>) -> &mut Self {
+        self.values.set_column(value);
+        self.record(C::NAME);
+        self
+    }
+}
+
+impl<V, C> TrySetColumn<C> for OrderedNewValues<V>
+where
+    Self: SetColumn<C> + ValidateColumn<C>,
+    C: TypedColumn,
+{
+    #[inline]
+    fn try_set_column(
+        &mut self,
+        value: impl Into<C::ColumnType>,
+    ) -> Result<&mut Self, Self::Error> {
+        let value = value.into();
+        if let Some(value_ref) = value.as_optional_ref() {
+            <Self as ValidateColumn<C>>::validate_column_in_context(self, value_ref)?;
+        }
+        <Self as SetColumn<C>>::set_column(self, value);
+        Ok(self)
+    }
+}
+
+impl<V, C> MayGetColumn<C> for OrderedNewValues<V>
+where
+    V: MayGetColumn<C>,
+    C: ColumnTyped,
+{
+    #[inline]
+    fn may_get_column_ref(&self) -> Option<&C::ColumnType> {
+        self.values.may_get_column_ref()
+    }
+}
+
 /// Extension trait for [`SetColumn`] that allows specifying the column at the
 /// method level.
 ///
@@ -182,10 +281,88 @@ pub trait TrySetColumnExt: Sized {
         <Self as TrySetColumn<Column>>::try_set_column(&mut self, value)?;
         Ok(self)
     }
+
+    #[inline]
+    /// Attempt to set the value of the specified column to the value produced
+    /// by a fallible computation, such as parsing or fetching it.
+    ///
+    /// The closure is run first, and the value it produces is then validated
+    /// and set as by [`try_set_column`](Self::try_set_column).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the closure, converted into the error of the
+    /// column, or an error if the column cannot be set.
+    fn try_set_with<Column, E>(
+        self,
+        f: impl FnOnce() -> Result<Column::ColumnType, E>,
+    ) -> Result<Self, <Self as ValidateColumn<Column>>::Error>
+    where
+        Column: TypedColumn,
+        Self: TrySetColumn<Column>,
+        <Self as ValidateColumn<Column>>::Error: From<E>,
+    {
+        let value = f()?;
+        self.try_set_column::<Column>(value)
+    }
 }
 
 impl<T> TrySetColumnExt for T {}
 
+/// Extension trait adjusting the current value of a column, rather than
+/// replacing it, such as incrementing a counter or appending to a text.
+///
+/// The adjusted value is set as by [`TrySetColumn::try_set_column`], so the
+/// validators of the column run on it.
+pub trait AdjustColumn: Sized {
+    #[inline]
+    /// Attempt to add `by` to the current value of the specified column.
+    ///
+    /// An unset column starts from the default of its type, such as `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting value cannot be set.
+    fn increment_column<Column>(
+        &mut self,
+        by: Column::ColumnType,
+    ) -> Result<&mut Self, <Self as ValidateColumn<Column>>::Error>
+    where
+        Column: TypedColumn<ColumnType: core::ops::Add<Output = Column::ColumnType> + Default>,
+        Self: TrySetColumn<Column> + MayGetColumn<Column>,
+    {
+        let current =
+            <Self as MayGetColumn<Column>>::may_get_column_ref(self).cloned().unwrap_or_default();
+        <Self as TrySetColumn<Column>>::try_set_column(self, current + by)
+    }
+
+    #[inline]
+    /// Attempt to append `item` to the current value of the specified column,
+    /// such as a `&str` to a text column or an element to an array column.
+    ///
+    /// An unset column starts from the default of its type, such as an empty
+    /// text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting value cannot be set.
+    fn append_column<Column, Item>(
+        &mut self,
+        item: Item,
+    ) -> Result<&mut Self, <Self as ValidateColumn<Column>>::Error>
+    where
+        Column: TypedColumn<ColumnType: Extend<Item> + Default>,
+        Self: TrySetColumn<Column> + MayGetColumn<Column>,
+    {
+        let mut value =
+            <Self as MayGetColumn<Column>>::may_get_column_ref(self).cloned().unwrap_or_default();
+        value.extend(core::iter::once(item));
+        <Self as TrySetColumn<Column>>::try_set_column(self, value)
+    }
+}
+
+impl<T> AdjustColumn for T {}
+
 /// Trait attempting to set a dynamic [`DynColumn`], which may fail.
 pub trait TrySetDynamicColumn: Sized {
     /// Attempt to set the value of the specified dynamic column.
@@ -247,6 +424,15 @@ mod sealed {
     use super::{DynColumn, DynamicColumnError, TableExt, TrySetColumn, TypedColumn};
     use crate::NestedColumns;
 
+    /// Returns the error for a value of type `VT` provided for the column `C`.
+    fn type_mismatch<C: TypedColumn, VT>() -> DynamicColumnError {
+        DynamicColumnError::TypeMismatch {
+            column: C::NAME,
+            expected: core::any::type_name::<C::ValueType>(),
+            actual: core::any::type_name::<VT>(),
+        }
+    }
+
     /// Trait attempting to set a dynamic [`DynColumn`], which may fail.
     pub trait VariadicTrySetDynamicColumn<Columns: NestedColumns> {
         /// Attempt to set the value of the specified dynamic column.
@@ -277,11 +463,13 @@ mod sealed {
             column: DynColumn<VT>,
             value: &VT,
         ) -> Result<&mut Self, DynamicColumnError> {
-            let value_any: &dyn core::any::Any = value;
             if column.column_name() == CHead::NAME
                 && column.table_name() == <CHead::Table as TableExt>::TABLE_NAME
-                && let Some(value) = value_any.downcast_ref::<CHead::ValueType>()
             {
+                let value_any: &dyn core::any::Any = value;
+                let Some(value) = value_any.downcast_ref::<CHead::ValueType>() else {
+                    return Err(type_mismatch::<CHead, VT>());
+                };
                 Ok(<Self as TrySetColumn<CHead>>::try_set_column(self, value.clone())
                     .map_err(|e| DynamicColumnError::Validation(Box::new(e)))?)
             } else {
@@ -310,11 +498,12 @@ mod sealed {
                 && column.table_name() == <CHead::Table as TableExt>::TABLE_NAME
             {
                 let value_any: &dyn core::any::Any = value;
-                if let Some(value) = value_any.downcast_ref::<CHead::ValueType>() {
-                    return self
-                        .try_set_column(value.clone())
-                        .map_err(|e| DynamicColumnError::Validation(Box::new(e)));
-                }
+                let Some(value) = value_any.downcast_ref::<CHead::ValueType>() else {
+                    return Err(type_mismatch::<CHead, VT>());
+                };
+                return self
+                    .try_set_column(value.clone())
+                    .map_err(|e| DynamicColumnError::Validation(Box::new(e)));
             }
 
             <Self as VariadicTrySetDynamicColumn<CTail>>::variadic_try_set_dynamic_column(
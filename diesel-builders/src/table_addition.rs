@@ -3,7 +3,8 @@
 use tuplities::prelude::{FlattenNestedTuple, IntoNestedTupleOption, NestedTupleOptionWith};
 
 use crate::{
-    NestedColumns, NonOptionalTypedNestedTuple, TableModel, TypedNestedTuple,
+    NestedBuilderPresence, NestedColumns, NonOptionalTypedNestedTuple, TableModel,
+    TypedNestedTuple,
     columns::{NonEmptyNestedProjection, NonEmptyProjection},
 };
 
@@ -31,17 +32,46 @@ pub trait TableExt:
             &'static str,
             Transposed = <Self::NewRecord as TypedNestedTuple>::NestedTupleColumnType,
             SameDepth = <Self::NewRecord as NestedColumns>::NestedNames,
-        >;
+        > + NestedBuilderPresence<<Self::NewRecord as NestedColumns>::NestedNames>;
     /// The nested primary key columns of this table.
     type NestedPrimaryKeyColumns: NonEmptyNestedProjection<Table = Self>
         + NonOptionalTypedNestedTuple;
     /// Error type associated with this table, such as for the validation
     /// of values before insertion.
     type Error;
+    /// The nested columns marked as `#[table_model(timestamp(updated))]`,
+    /// which are set to the current time whenever a record is updated.
+    type NestedUpdatedColumns: NestedColumns;
+    /// The changeset `C` extended with the assignments of the
+    /// `Self::NestedUpdatedColumns` to the current time.
+    type ManagedChangeset<C>;
 
     /// Returns the default values for the new record.
     #[must_use]
     fn default_new_values() -> Self::NewValues;
+
+    /// Sets the values of the columns managed by the table right before a
+    /// new record is inserted, such as the current time for the columns
+    /// marked as `#[table_model(timestamp(...))]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_values` - The values of the record about to be inserted.
+    #[inline]
+    fn set_managed_values(_new_values: &mut Self::NewValues) {}
+
+    /// Extends a changeset about to be applied to a record with the
+    /// assignments of the columns managed by the table, such as the current
+    /// time for the columns marked as `#[table_model(timestamp(updated))]`.
+    ///
+    /// The changeset is returned as-is when the table manages no column, and
+    /// it should not assign the managed columns itself: Postgres rejects
+    /// statements assigning the same column twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `changeset` - The changeset about to be applied.
+    fn managed_changeset<C>(changeset: C) -> Self::ManagedChangeset<C>;
 }
 
 /// Extended trait for Diesel models associated with a table.
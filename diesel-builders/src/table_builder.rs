@@ -4,21 +4,33 @@
 use diesel::{Table, associations::HasTable};
 use tuplities::prelude::*;
 
+mod apply_defaults;
 mod completed_table_builder;
+mod json;
+mod map_err;
 mod serde;
-pub use completed_table_builder::{RecursiveBuilderInsert, RecursiveTableBuilder};
+mod with_ancestor;
+pub use apply_defaults::{ApplyNestedDefaults, FillMissingNestedOptions};
+pub use completed_table_builder::{
+    RecursiveBuilderInsert, RecursiveBuilderInsertWithId, RecursiveTableBuilder,
+};
+pub use map_err::MapErrTableBuilder;
+pub use with_ancestor::WithAncestor;
 
 use crate::{
-    AncestorOfIndex, BundlableTable, ColumnTyped, DescendantOf, DiscretionarySameAsIndex,
-    ForeignPrimaryKey, MandatorySameAsIndex, MayGetColumn, MayGetNestedColumns, MaySetColumns,
-    MayValidateNestedColumns, NestedColumns, SetColumn, SetDiscretionaryBuilder,
-    SetHomogeneousNestedColumns, SetMandatoryBuilder, TableBuilderBundle, TableExt,
-    TryMaySetNestedColumns, TrySetColumn, TrySetDiscretionaryBuilder,
-    TrySetHomogeneousNestedColumns, TrySetMandatoryBuilder, TypedColumn, ValidateColumn,
-    buildable_table::BuildableTable, vertical_same_as_group::VerticalSameAsGroup,
+    AncestorOfIndex, BuilderError, BuilderResult, BundlableTable, ColumnTyped, DescendantOf,
+    DiffNestedColumns, DiscretionarySameAsIndex, ForeignPrimaryKey, HasMandatoryBuilder, Insert,
+    MandatorySameAsIndex, MayGetColumn, MayGetNestedColumns, MaySetColumns,
+    MayValidateNestedColumns, NestedColumns, NestedInsertLevels, NestedTables, OptionalRef,
+    SetColumn, SetDiscretionaryBuilder, SetHomogeneousNestedColumns, SetMandatoryBuilder,
+    TableBuilderBundle, TableExt, TryMaySetNestedColumns, TrySetColumn, TrySetDiscretionaryBuilder,
+    TrySetHomogeneousNestedColumns, TrySetMandatoryBuilder, TypedColumn, ValidateAllNestedColumns,
+    ValidateColumn, ValidationErrors, buildable_table::BuildableTable,
+    vertical_same_as_group::VerticalSameAsGroup,
 };
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(not(feature = "pretty-debug"), derive(Debug))]
 /// A builder for creating insertable models for a Diesel table and its
 /// ancestors.
 ///
@@ -26,6 +38,9 @@ use crate::{
 /// that may have inheritance relationships or triangular dependencies. It
 /// tracks the state of all required fields and ensures proper insertion order.
 ///
+/// With the `pretty-debug` feature, its `Debug` output lists the columns of
+/// the table and its ancestors by name, marking the unset ones.
+///
 /// # Type Parameters
 ///
 /// * `T`: The table type this builder is for, must implement `BuildableTable`
@@ -44,6 +59,205 @@ impl<T: BuildableTable> TableBuilder<T> {
     pub fn into_bundles(self) -> T::NestedAncestorBuilders {
         self.bundles
     }
+
+    /// Wraps the builder so that its validation errors are mapped through
+    /// `f` into a caller-defined error type.
+    ///
+    /// Within a function returning its own error type, the fluent setters of
+    /// the wrapper return that error type directly, so the conversion is
+    /// written once rather than at every `?`:
+    ///
+    /// ```ignore
+    /// let animal = animals::table::builder()
+    ///     .try_map_err(MyError::Animal)
+    ///     .try_name("Max")?
+    ///     .try_description(Some("A dog".to_owned()))?
+    ///     .insert(conn)?;
+    /// ```
+    ///
+    /// Only the columns whose validation error is the error of `T` are
+    /// mapped: the columns of ancestors with a different error type are to be
+    /// set before wrapping the builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function mapping the error of the table.
+    #[must_use]
+    pub fn try_map_err<F, E>(self, f: F) -> MapErrTableBuilder<T, F>
+    where
+        F: Fn(T::Error) -> E,
+    {
+        MapErrTableBuilder::new(self, f)
+    }
+
+    /// Inserts the builder within a transaction which is then rolled back,
+    /// reporting whether the insertion would succeed without persisting it.
+    ///
+    /// Unlike [`validate_all`](Self::validate_all), the records are checked by
+    /// the database itself, so `CHECK`, foreign key and unique constraints
+    /// are enforced as well. When the connection is already within a
+    /// transaction, the insertion is wrapped in a savepoint instead.
+    ///
+    /// This is relatively expensive, as the records of `T` and of all its
+    /// ancestors are actually inserted before being rolled back.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the database connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error the insertion would have raised, if any.
+    pub fn dry_run_insert<Conn>(self, conn: &mut Conn) -> BuilderResult<(), T::Error>
+    where
+        Conn: diesel::Connection,
+        Self: Insert<Conn>,
+    {
+        let outcome = conn.transaction(|conn| {
+            self.insert(conn)?;
+            Err(BuilderError::Diesel(diesel::result::Error::RollbackTransaction))
+        });
+        match outcome {
+            Err(BuilderError::Diesel(diesel::result::Error::RollbackTransaction)) => Ok(()),
+            outcome => outcome,
+        }
+    }
+
+    /// Returns the names of `T` and its ancestors grouped by insert level, in
+    /// the order in which the levels are inserted.
+    ///
+    /// Tables sharing a level do not depend on each other: with `pets`
+    /// extending both `dogs` and `cats`, which extend `animals`, the levels
+    /// are `[["animals"], ["dogs", "cats"], ["pets"]]`. Triangular builders
+    /// are separate hierarchies and are not included.
+    #[must_use]
+    pub fn insert_levels() -> Vec<Vec<&'static str>>
+    where
+        T::NestedAncestorsWithSelf: NestedInsertLevels,
+    {
+        T::NestedAncestorsWithSelf::insert_levels()
+    }
+
+    /// Returns a reference to the values of the columns of `T`, where `None`
+    /// marks a column which is not set.
+    ///
+    /// Only the columns of `T` itself are included: the values of the
+    /// ancestor tables are held by their own bundles, see
+    /// [`into_bundles`](Self::into_bundles).
+    #[must_use]
+    pub fn new_values(&self) -> &T::NewValues {
+        self.bundles.nested_index().new_values()
+    }
+
+    /// Consumes the builder and returns the values of the columns of `T`,
+    /// where `None` marks a column which is not set.
+    ///
+    /// The values of the ancestor tables and the associated builders are
+    /// discarded.
+    #[must_use]
+    pub fn into_new_values(mut self) -> T::NewValues
+    where
+        T::NestedAncestorBuilders:
+            NestedTupleIndexMut<<T as AncestorOfIndex<T>>::Idx, Element = TableBuilderBundle<T>>,
+    {
+        self.bundles.nested_index_mut().take_new_values()
+    }
+
+    /// Returns the names of the columns, across the table and its ancestors,
+    /// whose set state or value differs between this builder and `other`.
+    ///
+    /// A column set in one builder and unset in the other is reported as
+    /// differing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The builder to compare against.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<&'static str>
+    where
+        Self: DiffNestedColumns<<T::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords>,
+    {
+        <Self as DiffNestedColumns<
+            <T::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >>::diff_nested_columns(self, other)
+    }
+
+    /// Runs the validator of every present column, across the table and its
+    /// ancestors, before any SQL is issued.
+    ///
+    /// Unlike the setters, which stop at the first invalid value, this collects
+    /// every failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationErrors`] aggregate with all the validation errors
+    /// if any present column value is invalid.
+    pub fn validate_all(&self) -> Result<(), ValidationErrors<T::Error>>
+    where
+        Self: ValidateAllNestedColumns<
+                T::Error,
+                <T::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+            >,
+    {
+        let mut errors = Vec::new();
+        <Self as ValidateAllNestedColumns<
+            T::Error,
+            <T::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+        >>::validate_all_nested_columns(self, &mut errors);
+        if errors.is_empty() { Ok(()) } else { Err(ValidationErrors::new(errors)) }
+    }
+
+    /// Fills every unset column, across the table and its ancestors, with its
+    /// default value.
+    ///
+    /// Columns which are already set are left untouched, and columns without
+    /// a default remain unset. Builders start with their defaults, so this is
+    /// mostly useful after clearing columns or deserializing a builder.
+    pub fn apply_defaults(&mut self) -> &mut Self
+    where
+        T::NestedAncestorBuilders: ApplyNestedDefaults,
+    {
+        self.bundles.apply_nested_defaults();
+        self
+    }
+
+    /// Returns the builder to its initial state, as by
+    /// [`BuildableTable::builder`].
+    ///
+    /// Every column, across the table and its ancestors, is restored to its
+    /// default value or unset if it has none, and all the associated builders
+    /// are dropped.
+    pub fn clear_all(&mut self) -> &mut Self {
+        self.bundles = T::default_bundles();
+        self
+    }
+
+    /// Checks whether the provided value would be accepted for column `C`
+    /// given the current state of the builder, without setting it.
+    ///
+    /// A `None` value of a nullable column is always accepted, as setters do
+    /// not validate it either.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The candidate value of the column.
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation error of the column if the value is invalid.
+    pub fn check_column<C>(
+        &self,
+        value: &C::ColumnType,
+    ) -> Result<(), <Self as ValidateColumn<C>>::Error>
+    where
+        C: TypedColumn,
+        Self: ValidateColumn<C>,
+    {
+        match value.as_optional_ref() {
+            Some(value) => self.validate_column_in_context(value),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<T> HasTable for TableBuilder<T>
@@ -212,6 +426,24 @@ where
     }
 }
 
+impl<C, T> HasMandatoryBuilder<C> for TableBuilder<T>
+where
+    T: BuildableTable + DescendantOf<C::Table>,
+    C: MandatorySameAsIndex,
+    C::Table: AncestorOfIndex<T> + BuildableTable,
+    C::ReferencedTable: BuildableTable,
+    TableBuilderBundle<C::Table>: HasMandatoryBuilder<C>,
+    T::NestedAncestorBuilders: NestedTupleIndex<
+            <C::Table as AncestorOfIndex<T>>::Idx,
+            Element = TableBuilderBundle<C::Table>,
+        >,
+{
+    #[inline]
+    fn has_mandatory_builder(&self) -> bool {
+        self.bundles.nested_index().has_mandatory_builder()
+    }
+}
+
 impl<Key, T> TrySetDiscretionaryBuilder<Key> for TableBuilder<T>
 where
     T: BuildableTable + DescendantOf<Key::Table>,
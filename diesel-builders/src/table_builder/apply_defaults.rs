@@ -0,0 +1,77 @@
+//! Submodule providing the traits used to fill the unset columns of a builder
+//! with their default values.
+
+use crate::{TableBuilderBundle, builder_bundle::BundlableTableExt};
+
+/// Trait for nested tuples of optional values which may fill their `None`
+/// entries from another nested tuple of the same shape.
+pub trait FillMissingNestedOptions {
+    /// Replaces every `None` entry of `self` with the matching entry of
+    /// `other`, leaving the `Some` entries untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The nested tuple providing the fallback values.
+    fn fill_missing_nested_options(&mut self, other: Self);
+}
+
+impl FillMissingNestedOptions for () {
+    #[inline]
+    fn fill_missing_nested_options(&mut self, _other: Self) {}
+}
+
+impl<T> FillMissingNestedOptions for (Option<T>,) {
+    #[inline]
+    fn fill_missing_nested_options(&mut self, other: Self) {
+        if self.0.is_none() {
+            self.0 = other.0;
+        }
+    }
+}
+
+impl<Head, Tail> FillMissingNestedOptions for (Option<Head>, Tail)
+where
+    Tail: FillMissingNestedOptions,
+{
+    #[inline]
+    fn fill_missing_nested_options(&mut self, other: Self) {
+        if self.0.is_none() {
+            self.0 = other.0;
+        }
+        self.1.fill_missing_nested_options(other.1);
+    }
+}
+
+/// Trait for nested tuples of builder bundles which may fill their unset
+/// columns with the default values of their tables.
+pub trait ApplyNestedDefaults {
+    /// Fills the unset columns of every bundle with their default values.
+    fn apply_nested_defaults(&mut self);
+}
+
+impl ApplyNestedDefaults for () {
+    #[inline]
+    fn apply_nested_defaults(&mut self) {}
+}
+
+impl<T> ApplyNestedDefaults for (TableBuilderBundle<T>,)
+where
+    T: BundlableTableExt<NewValues: FillMissingNestedOptions>,
+{
+    #[inline]
+    fn apply_nested_defaults(&mut self) {
+        self.0.apply_defaults();
+    }
+}
+
+impl<T, Tail> ApplyNestedDefaults for (TableBuilderBundle<T>, Tail)
+where
+    T: BundlableTableExt<NewValues: FillMissingNestedOptions>,
+    Tail: ApplyNestedDefaults,
+{
+    #[inline]
+    fn apply_nested_defaults(&mut self) {
+        self.0.apply_defaults();
+        self.1.apply_nested_defaults();
+    }
+}
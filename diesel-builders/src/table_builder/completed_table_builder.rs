@@ -8,12 +8,13 @@ use tuplities::prelude::{
 };
 
 use crate::{
-    AncestorOfIndex, BuildableTable, BuilderError, BuilderResult, BundlableTable,
+    AncestorOfIndex, BuildableTable, BuilderError, BuilderResult, BundlableTable, ColumnTyped,
     CompletedTableBuilderBundle, DescendantOf, DescendantWithSelf, GetNestedColumns,
-    HasNestedTables, HasTableExt, IncompleteBuilderError, Insert, NestedTables, OptionalRef,
-    TableBuilder, TableExt, TrySetColumn, TrySetHomogeneousNestedColumns,
-    TrySetHomogeneousNestedColumnsCollection, TypedColumn, TypedNestedTuple, ValidateColumn,
-    VerticalSameAsGroup, builder_bundle::RecursiveBundleInsert,
+    HasNestedTables, HasTableExt, IncompleteBuilderError, Insert, InsertWithId, MayGetColumn,
+    NestedTables, OptionalRef, TableBuilder, TableExt, TrySetColumn,
+    TrySetHomogeneousNestedColumns, TrySetHomogeneousNestedColumnsCollection, TypedColumn,
+    TypedNestedTuple, ValidateColumn, VerticalSameAsGroup,
+    builder_bundle::{RecursiveBundleInsert, RecursiveBundleInsertWithId},
 };
 
 /// A completed builder for creating insertable models for a Diesel table and
@@ -105,6 +106,58 @@ where
     }
 }
 
+/// Trait defining the insertion of a builder into the database with a
+/// caller-provided primary key for the root of the hierarchy.
+pub trait RecursiveBuilderInsertWithId<Error, Conn>: RecursiveBuilderInsert<Error, Conn> {
+    /// The primary key value of the root table of the hierarchy.
+    type Id;
+
+    /// Insert the builder's data into the database using the provided
+    /// connection, inserting the root table of the hierarchy with the
+    /// provided primary key, which is then propagated to the descendants.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The primary key of the root record to insert.
+    /// * `conn` - A mutable reference to the database connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insertion fails or if any database constraints
+    /// are violated.
+    fn recursive_insert_with_id(
+        self,
+        id: Self::Id,
+        conn: &mut Conn,
+    ) -> BuilderResult<<Self::Table as TableExt>::Model, Error>;
+}
+
+impl<T, Error, Conn> RecursiveBuilderInsertWithId<Error, Conn> for TableBuilder<T>
+where
+    Conn: diesel::connection::LoadConnection,
+    T: BuildableTable,
+    Self: RecursiveBuilderInsert<Error, Conn> + HasTable<Table = T>,
+    RecursiveTableBuilder<T, typenum::U0, T::NestedCompletedAncestorBuilders>:
+        TryFrom<Self, Error = IncompleteBuilderError>
+            + RecursiveBuilderInsertWithId<Error, Conn, Table = T>,
+{
+    type Id = <RecursiveTableBuilder<T, typenum::U0, T::NestedCompletedAncestorBuilders> as RecursiveBuilderInsertWithId<Error, Conn>>::Id;
+
+    #[inline]
+    fn recursive_insert_with_id(
+        self,
+        id: Self::Id,
+        conn: &mut Conn,
+    ) -> BuilderResult<T::Model, Error> {
+        let completed_builder: RecursiveTableBuilder<
+            T,
+            typenum::U0,
+            T::NestedCompletedAncestorBuilders,
+        > = self.try_into()?;
+        completed_builder.recursive_insert_with_id(id, conn)
+    }
+}
+
 impl<T: BuildableTable + DescendantWithSelf, Conn> Insert<Conn> for TableBuilder<T>
 where
     Self: RecursiveBuilderInsert<
@@ -130,6 +183,23 @@ where
     }
 }
 
+impl<T: BuildableTable + DescendantWithSelf, Conn> InsertWithId<Conn> for TableBuilder<T>
+where
+    Self: Insert<Conn>
+        + RecursiveBuilderInsertWithId<<Self::Table as TableExt>::Error, Conn, Table = T>,
+{
+    type Id = <Self as RecursiveBuilderInsertWithId<<Self::Table as TableExt>::Error, Conn>>::Id;
+
+    #[inline]
+    fn insert_with_id(
+        self,
+        id: Self::Id,
+        conn: &mut Conn,
+    ) -> BuilderResult<<Self::Table as TableExt>::Model, <Self::Table as TableExt>::Error> {
+        self.recursive_insert_with_id(id, conn)
+    }
+}
+
 impl<T: Table + Default, Depth, Bundles> HasTable for RecursiveTableBuilder<T, Depth, Bundles> {
     type Table = T;
 
@@ -139,6 +209,23 @@ impl<T: Table + Default, Depth, Bundles> HasTable for RecursiveTableBuilder<T, D
     }
 }
 
+impl<T, C, Depth, Bundles> MayGetColumn<C> for RecursiveTableBuilder<T, Depth, Bundles>
+where
+    Bundles: NestedTupleIndex<
+            <<C::Table as AncestorOfIndex<T>>::Idx as Sub<Depth>>::Output,
+            Element = CompletedTableBuilderBundle<C::Table>,
+        >,
+    T: BuildableTable + DescendantOf<C::Table>,
+    C: TypedColumn,
+    C::Table: AncestorOfIndex<T, Idx: Sub<Depth>> + BundlableTable,
+    CompletedTableBuilderBundle<C::Table>: MayGetColumn<C>,
+{
+    #[inline]
+    fn may_get_column_ref(&self) -> Option<&C::ColumnType> {
+        self.nested_bundles.nested_index().may_get_column_ref()
+    }
+}
+
 impl<T, C, Depth, Bundles> ValidateColumn<C> for RecursiveTableBuilder<T, Depth, Bundles>
 where
     Bundles: NestedTupleIndex<
@@ -211,6 +298,25 @@ where
     }
 }
 
+impl<T: diesel::Table, Depth, Error, Conn, Head> RecursiveBuilderInsertWithId<Error, Conn>
+    for RecursiveTableBuilder<T, Depth, (Head,)>
+where
+    Conn: diesel::connection::LoadConnection,
+    Head: RecursiveBundleInsertWithId<Error, Conn>,
+    Self: RecursiveBuilderInsert<Error, Conn> + HasTableExt<Table = Head::Table>,
+{
+    type Id = <<Head::Table as Table>::PrimaryKey as ColumnTyped>::ColumnType;
+
+    #[inline]
+    fn recursive_insert_with_id(
+        self,
+        id: Self::Id,
+        conn: &mut Conn,
+    ) -> BuilderResult<<Head::Table as TableExt>::Model, Error> {
+        self.nested_bundles.0.recursive_bundle_insert_with_id(id, conn)
+    }
+}
+
 // Recursive case: nested 2-tuple (Head, Tail) where Tail is itself a nested
 // tuple
 impl<T, Depth, Error, Conn, Head, Tail> RecursiveBuilderInsert<Error, Conn>
@@ -274,6 +380,46 @@ where
     }
 }
 
+impl<T, Depth, Error, Conn, Head, Tail> RecursiveBuilderInsertWithId<Error, Conn>
+    for RecursiveTableBuilder<T, Depth, (Head, Tail)>
+where
+    T: TableExt,
+    Conn: diesel::connection::LoadConnection,
+    Head: RecursiveBundleInsertWithId<Error, Conn> + HasTable,
+    Tail: FlattenNestedTuple + HasNestedTables,
+    <Head::Table as TableExt>::Model:
+        GetNestedColumns<<Head::Table as TableExt>::NestedPrimaryKeyColumns>,
+    Depth: core::ops::Add<typenum::U1>,
+    Self: RecursiveBuilderInsert<Error, Conn, Table = T>,
+    RecursiveTableBuilder<T, typenum::Sum<Depth, typenum::U1>, Tail>:
+        RecursiveBuilderInsert<Error, Conn, Table = T>
+            + TrySetHomogeneousNestedColumnsCollection<
+                Error,
+                <<Head::Table as TableExt>::NestedPrimaryKeyColumns as TypedNestedTuple>::NestedTupleColumnType,
+                <Tail::NestedTables as NestedTables>::NestedPrimaryKeyColumnsCollection,
+            >,
+{
+    type Id = <<Head::Table as Table>::PrimaryKey as ColumnTyped>::ColumnType;
+
+    fn recursive_insert_with_id(
+        self,
+        id: Self::Id,
+        conn: &mut Conn,
+    ) -> BuilderResult<T::Model, Error> {
+        // Insert the root table with the provided primary key
+        let first = self.nested_bundles.0;
+        let model: <Head::Table as TableExt>::Model =
+            first.recursive_bundle_insert_with_id(id, conn)?;
+        // Extract primary keys and set them in the tail builder
+        let mut tail_builder = RecursiveTableBuilder::from_nested_bundles(self.nested_bundles.1);
+        tail_builder
+            .try_set_homogeneous_nested_columns_collection(model.get_nested_columns())
+            .map_err(BuilderError::Validation)?;
+        // Recursively insert the tail
+        tail_builder.recursive_insert(conn)
+    }
+}
+
 impl<T> TryFrom<TableBuilder<T>>
     for RecursiveTableBuilder<T, typenum::U0, T::NestedCompletedAncestorBuilders>
 where
@@ -0,0 +1,144 @@
+#![cfg(feature = "serde")]
+//! Submodule providing the construction of table builders from JSON objects.
+
+use std::collections::HashSet;
+
+use crate::{
+    AncestorOfIndex, BuildableTable, DescendantWithSelf, NestedTables, TableBuilder,
+    builder_error::DynamicColumnError,
+};
+
+impl<T> TableBuilder<T>
+where
+    T: AncestorOfIndex<T> + BuildableTable,
+{
+    /// Builds a new builder from a JSON object keyed by column name.
+    ///
+    /// Each value is deserialized into the type of the column sharing its key,
+    /// with `null` unsetting nullable columns, and is then validated as with
+    /// any other setter. Columns missing from the object keep their defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The JSON object to build from.
+    ///
+    /// # Errors
+    ///
+    /// * [`DynamicColumnError::NotAnObject`] if the value is not an object.
+    /// * [`DynamicColumnError::UnknownField`] if a key matches no column.
+    /// * [`DynamicColumnError::Parse`] if a value does not deserialize into the
+    ///   type of its column.
+    /// * [`DynamicColumnError::Validation`] if a deserialized value is invalid.
+    pub fn try_from_json(value: &serde_json::Value) -> Result<Self, DynamicColumnError>
+    where
+        Self: sealed::VariadicTrySetJsonColumns<
+                <<T as DescendantWithSelf>::NestedAncestorsWithSelf as NestedTables>::ChainedNestedRecords,
+            >,
+    {
+        use sealed::VariadicTrySetJsonColumns;
+        let serde_json::Value::Object(object) = value else {
+            return Err(DynamicColumnError::NotAnObject);
+        };
+        let mut builder = T::builder();
+        let mut known = HashSet::new();
+        builder.variadic_try_set_json_columns(object, &mut known)?;
+        if let Some(unknown) = object.keys().find(|key| !known.contains(key.as_str())) {
+            return Err(DynamicColumnError::UnknownField(unknown.clone()));
+        }
+        Ok(builder)
+    }
+}
+
+/// Sealed module for private traits.
+mod sealed {
+    use std::collections::HashSet;
+
+    use serde_json::{Map, Value};
+
+    use crate::{
+        NestedColumns, TableExt, TrySetColumn, TypedColumn, builder_error::DynamicColumnError,
+    };
+
+    /// Trait setting the values of a JSON object to a nested tuple of columns.
+    pub trait VariadicTrySetJsonColumns<Columns: NestedColumns> {
+        /// Deserialize and set the object values matching the columns,
+        /// recording the names of the matched columns into `known`.
+        fn variadic_try_set_json_columns(
+            &mut self,
+            object: &Map<String, Value>,
+            known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError>;
+    }
+
+    impl<M> VariadicTrySetJsonColumns<()> for M {
+        #[inline]
+        fn variadic_try_set_json_columns(
+            &mut self,
+            _object: &Map<String, Value>,
+            _known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError> {
+            Ok(())
+        }
+    }
+
+    /// Deserializes and sets the object value associated with the column `C`,
+    /// if any.
+    fn try_set_json_column<M, C>(
+        builder: &mut M,
+        object: &Map<String, Value>,
+        known: &mut HashSet<&'static str>,
+    ) -> Result<(), DynamicColumnError>
+    where
+        M: TrySetColumn<C>,
+        C: TypedColumn<Table: TableExt, ColumnType: serde::de::DeserializeOwned>,
+    {
+        let Some(raw) = object.get(C::NAME) else {
+            return Ok(());
+        };
+        known.insert(C::NAME);
+        let value = <C::ColumnType as serde::Deserialize>::deserialize(raw).map_err(|error| {
+            DynamicColumnError::Parse {
+                table_name: <C::Table as TableExt>::TABLE_NAME,
+                column_name: C::NAME,
+                error: Box::new(error),
+            }
+        })?;
+        builder.try_set_column(value).map_err(|e| DynamicColumnError::Validation(Box::new(e)))?;
+        Ok(())
+    }
+
+    impl<M, CHead> VariadicTrySetJsonColumns<(CHead,)> for M
+    where
+        M: TrySetColumn<CHead>,
+        CHead: TypedColumn<Table: TableExt, ColumnType: serde::de::DeserializeOwned>,
+    {
+        #[inline]
+        fn variadic_try_set_json_columns(
+            &mut self,
+            object: &Map<String, Value>,
+            known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError> {
+            try_set_json_column::<M, CHead>(self, object, known)
+        }
+    }
+
+    impl<M, CHead, CTail> VariadicTrySetJsonColumns<(CHead, CTail)> for M
+    where
+        M: TrySetColumn<CHead> + VariadicTrySetJsonColumns<CTail>,
+        CHead: TypedColumn<Table: TableExt, ColumnType: serde::de::DeserializeOwned>,
+        CTail: NestedColumns,
+        (CHead, CTail): NestedColumns,
+    {
+        #[inline]
+        fn variadic_try_set_json_columns(
+            &mut self,
+            object: &Map<String, Value>,
+            known: &mut HashSet<&'static str>,
+        ) -> Result<(), DynamicColumnError> {
+            try_set_json_column::<M, CHead>(self, object, known)?;
+            <Self as VariadicTrySetJsonColumns<CTail>>::variadic_try_set_json_columns(
+                self, object, known,
+            )
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Submodule providing the `MapErrTableBuilder` wrapper, re-typing the
+//! validation errors of a builder into a caller-defined error type.
+
+use crate::{
+    BuilderError, BuilderResult, ColumnTyped, Insert, MayGetColumn, SetColumn, TableBuilder,
+    TableExt, TrySetColumn, TypedColumn, ValidateColumn, buildable_table::BuildableTable,
+};
+
+/// A [`TableBuilder`] whose validation errors are mapped through `F`.
+///
+/// The wrapper implements [`TrySetColumn`] with the mapped error type for
+/// every column whose validation error is the error of the table, so that the
+/// generated fluent setters such as `try_name` return the caller's error
+/// directly, and `?` needs no conversion within a function returning it.
+/// Infallible columns remain settable through [`SetColumn`].
+///
+/// # Type Parameters
+///
+/// * `T`: The table type of the wrapped builder.
+/// * `F`: The function mapping the error of the table.
+#[derive(Debug, Clone)]
+pub struct MapErrTableBuilder<T: BuildableTable, F> {
+    /// The wrapped builder.
+    builder: TableBuilder<T>,
+    /// The function mapping the error of the table.
+    f: F,
+}
+
+impl<T: BuildableTable, F> MapErrTableBuilder<T, F> {
+    /// Wraps the provided builder, mapping its errors through `f`.
+    #[inline]
+    #[must_use]
+    pub fn new(builder: TableBuilder<T>, f: F) -> Self {
+        Self { builder, f }
+    }
+
+    /// Returns a reference to the wrapped builder.
+    #[inline]
+    #[must_use]
+    pub fn builder(&self) -> &TableBuilder<T> {
+        &self.builder
+    }
+
+    /// Consumes the wrapper and returns the wrapped builder.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> TableBuilder<T> {
+        self.builder
+    }
+
+    /// Inserts the wrapped builder, mapping the validation errors raised
+    /// during the insertion through `F`.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - A mutable reference to the database connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insertion fails or if any database constraints
+    /// are violated.
+    pub fn insert<Conn, E>(self, conn: &mut Conn) -> BuilderResult<T::Model, E>
+    where
+        TableBuilder<T>: Insert<Conn>,
+        F: Fn(T::Error) -> E,
+    {
+        self.builder.insert(conn).map_err(|error| {
+            match error {
+                BuilderError::Diesel(e) => BuilderError::Diesel(e),
+                BuilderError::Incomplete(e) => BuilderError::Incomplete(e),
+                BuilderError::ForeignModelMissing(e) => BuilderError::ForeignModelMissing(e),
+                BuilderError::Validation(e) => BuilderError::Validation((self.f)(e)),
+            }
+        })
+    }
+}
+
+impl<T, F, C> MayGetColumn<C> for MapErrTableBuilder<T, F>
+where
+    T: BuildableTable,
+    C: ColumnTyped,
+    TableBuilder<T>: MayGetColumn<C>,
+{
+    #[inline]
+    fn may_get_column_ref(&self) -> Option<&C::ColumnType> {
+        self.builder.may_get_column_ref()
+    }
+}
+
+impl<T, F, C> SetColumn<C> for MapErrTableBuilder<T, F>
+where
+    T: BuildableTable,
+    C: TypedColumn,
+    TableBuilder<T>: SetColumn<C>,
+{
+    #[inline]
+    fn set_column(&mut self, value: impl Into<C::ColumnType>) -> &mut Self {
+        self.builder.set_column(value);
+        self
+    }
+}
+
+impl<T, F, C, E> ValidateColumn<C> for MapErrTableBuilder<T, F>
+where
+    T: BuildableTable,
+    C: ColumnTyped,
+    TableBuilder<T>: ValidateColumn<C, Error = <T as TableExt>::Error>,
+    F: Fn(<T as TableExt>::Error) -> E,
+    E: core::error::Error + Send + Sync + 'static,
+{
+    type Error = E;
+
+    #[inline]
+    fn validate_column_in_context(&self, value: &C::ValueType) -> Result<(), Self::Error> {
+        self.builder.validate_column_in_context(value).map_err(&self.f)
+    }
+}
+
+impl<T, F, C, E> TrySetColumn<C> for MapErrTableBuilder<T, F>
+where
+    T: BuildableTable,
+    C: ColumnTyped,
+    TableBuilder<T>: TrySetColumn<C> + ValidateColumn<C, Error = <T as TableExt>::Error>,
+    F: Fn(<T as TableExt>::Error) -> E,
+    E: core::error::Error + Send + Sync + 'static,
+{
+    #[inline]
+    fn try_set_column(
+        &mut self,
+        value: impl Into<C::ColumnType>,
+    ) -> Result<&mut Self, Self::Error> {
+        self.builder.try_set_column(value).map_err(&self.f)?;
+        Ok(self)
+    }
+}
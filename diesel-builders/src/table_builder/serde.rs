@@ -32,3 +32,32 @@ where
         Ok(Self { bundles })
     }
 }
+
+#[cfg(feature = "postcard")]
+impl<T: BuildableTable> TableBuilder<T> {
+    /// Serializes the builder state into the compact binary `postcard`
+    /// format, e.g. to cache many in-progress builders.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any value held by the builder fails to serialize.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, postcard::Error>
+    where
+        Self: serde::Serialize,
+    {
+        postcard::to_allocvec(self)
+    }
+
+    /// Deserializes a builder from the bytes produced by
+    /// [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes do not encode a builder of this table.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, postcard::Error>
+    where
+        Self: serde::de::DeserializeOwned,
+    {
+        postcard::from_bytes(bytes)
+    }
+}
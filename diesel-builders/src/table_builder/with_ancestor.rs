@@ -0,0 +1,133 @@
+//! Submodule providing the `WithAncestor` trait, configuring the part of a
+//! builder relative to one of the ancestors of its table as a builder of the
+//! ancestor itself.
+
+use std::convert::Infallible;
+
+use crate::{DescendantOf, TableBuilder, buildable_table::BuildableTable};
+
+/// Trait for builders of descendant tables whose ancestor columns may be set
+/// through a builder of the ancestor table `A`.
+pub trait WithAncestor<A: BuildableTable>: Sized {
+    /// Applies `f` to a builder of `A` holding the values of `A` and of its
+    /// ancestors, and stores the values of the returned builder back.
+    ///
+    /// Vertical same-as columns are only propagated within the hierarchy of
+    /// `A`: columns of the descendant tables are not updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure configuring the ancestor builder.
+    #[must_use]
+    fn with_ancestor<F>(self, f: F) -> Self
+    where
+        F: FnOnce(TableBuilder<A>) -> TableBuilder<A>,
+    {
+        match self.try_with_ancestor(|ancestor| Ok::<_, Infallible>(f(ancestor))) {
+            Ok(builder) => builder,
+            Err(infallible) => match infallible {},
+        }
+    }
+
+    /// Applies the fallible `f` to a builder of `A` holding the values of `A`
+    /// and of its ancestors, and stores the values of the returned builder
+    /// back, as by [`with_ancestor`](Self::with_ancestor).
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure configuring the ancestor builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the closure, in which case the builder is
+    /// dropped.
+    fn try_with_ancestor<F, E>(self, f: F) -> Result<Self, E>
+    where
+        F: FnOnce(TableBuilder<A>) -> Result<TableBuilder<A>, E>;
+}
+
+impl<T, A> WithAncestor<A> for TableBuilder<T>
+where
+    T: BuildableTable + DescendantOf<A>,
+    A: BuildableTable,
+    A::NestedAncestorsWithSelf: sealed::NestedBundlesOf<T, Bundles = A::NestedAncestorBuilders>,
+{
+    #[inline]
+    fn try_with_ancestor<F, E>(mut self, f: F) -> Result<Self, E>
+    where
+        F: FnOnce(TableBuilder<A>) -> Result<TableBuilder<A>, E>,
+    {
+        use sealed::NestedBundlesOf;
+        let bundles = A::NestedAncestorsWithSelf::take_bundles(&mut self.bundles);
+        let ancestor = f(TableBuilder::from_bundles(bundles))?;
+        A::NestedAncestorsWithSelf::put_bundles(ancestor.into_bundles(), &mut self.bundles);
+        Ok(self)
+    }
+}
+
+/// Sealed module for private traits.
+mod sealed {
+    use tuplities::prelude::NestedTupleIndexMut;
+
+    use crate::{
+        AncestorOfIndex, DescendantOf, TableBuilderBundle, buildable_table::BuildableTable,
+        builder_bundle::BundlableTableExt,
+    };
+
+    /// Trait for nested tuples of ancestors of `T`, moving their bundles in
+    /// and out of the bundles of a builder of `T`.
+    pub trait NestedBundlesOf<T: BuildableTable> {
+        /// The nested tuple of the bundles of the ancestors.
+        type Bundles;
+
+        /// Takes the bundles of the ancestors out of `bundles`, leaving
+        /// default bundles in their place.
+        fn take_bundles(bundles: &mut T::NestedAncestorBuilders) -> Self::Bundles;
+
+        /// Puts the bundles of the ancestors back into `into`.
+        fn put_bundles(bundles: Self::Bundles, into: &mut T::NestedAncestorBuilders);
+    }
+
+    impl<T, X> NestedBundlesOf<T> for (X,)
+    where
+        T: BuildableTable + DescendantOf<X>,
+        X: AncestorOfIndex<T> + BundlableTableExt,
+        TableBuilderBundle<X>: Default,
+        T::NestedAncestorBuilders: NestedTupleIndexMut<X::Idx, Element = TableBuilderBundle<X>>,
+    {
+        type Bundles = (TableBuilderBundle<X>,);
+
+        #[inline]
+        fn take_bundles(bundles: &mut T::NestedAncestorBuilders) -> Self::Bundles {
+            (core::mem::take(bundles.nested_index_mut()),)
+        }
+
+        #[inline]
+        fn put_bundles(bundles: Self::Bundles, into: &mut T::NestedAncestorBuilders) {
+            *into.nested_index_mut() = bundles.0;
+        }
+    }
+
+    impl<T, Head, Tail> NestedBundlesOf<T> for (Head, Tail)
+    where
+        T: BuildableTable + DescendantOf<Head>,
+        Head: AncestorOfIndex<T> + BundlableTableExt,
+        Tail: NestedBundlesOf<T>,
+        TableBuilderBundle<Head>: Default,
+        T::NestedAncestorBuilders:
+            NestedTupleIndexMut<Head::Idx, Element = TableBuilderBundle<Head>>,
+    {
+        type Bundles = (TableBuilderBundle<Head>, Tail::Bundles);
+
+        #[inline]
+        fn take_bundles(bundles: &mut T::NestedAncestorBuilders) -> Self::Bundles {
+            (core::mem::take(bundles.nested_index_mut()), Tail::take_bundles(bundles))
+        }
+
+        #[inline]
+        fn put_bundles(bundles: Self::Bundles, into: &mut T::NestedAncestorBuilders) {
+            *into.nested_index_mut() = bundles.0;
+            Tail::put_bundles(bundles.1, into);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Submodule providing helpers measuring the length of text values, for use
+//! in `ValidateColumn` implementations.
+//!
+//! [`str::len`] counts bytes, so a byte-based limit rejects multibyte text
+//! well before it reaches the intended number of characters.
+
+use crate::{TableExt, TypedColumn, builder_error::MaxCharsExceeded};
+
+/// Returns the number of Unicode scalar values (`char`s) in the text.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(diesel_builders::char_len("café"), 4);
+/// assert_eq!("café".len(), 5);
+/// ```
+#[must_use]
+pub fn char_len(value: &str) -> usize {
+    value.chars().count()
+}
+
+/// Returns the number of extended grapheme clusters in the text, i.e. the
+/// characters as perceived by a reader.
+///
+/// # Examples
+///
+/// ```
+/// // A family emoji joins four code points into a single grapheme.
+/// assert_eq!(diesel_builders::grapheme_len("👨‍👩‍👧‍👦"), 1);
+/// assert_eq!(diesel_builders::char_len("👨‍👩‍👧‍👦"), 7);
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+#[must_use]
+pub fn grapheme_len(value: &str) -> usize {
+    unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+}
+
+/// Checks that the text value of column `C` holds at most `max` characters,
+/// as counted by [`char_len`].
+///
+/// This is the check generated for `#[table_model(max_chars = ...)]`.
+///
+/// # Arguments
+///
+/// * `value` - The text value to check.
+/// * `max` - The maximum number of characters allowed.
+///
+/// # Errors
+///
+/// Returns a [`MaxCharsExceeded`] error if the value is too long.
+pub fn validate_max_chars<C, V>(value: &V, max: usize) -> Result<(), MaxCharsExceeded>
+where
+    C: TypedColumn<Table: TableExt>,
+    V: AsRef<str> + ?Sized,
+{
+    let actual = char_len(value.as_ref());
+    if actual > max {
+        return Err(MaxCharsExceeded {
+            table_name: <C::Table as TableExt>::TABLE_NAME,
+            column_name: C::NAME,
+            max,
+            actual,
+        });
+    }
+    Ok(())
+}
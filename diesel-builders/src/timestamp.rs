@@ -0,0 +1,52 @@
+//! Submodule providing the `Timestamp` trait, the source of the values of the
+//! columns marked as `#[table_model(timestamp(created))]` or
+//! `#[table_model(timestamp(updated))]`.
+//!
+//! Implementations for the date and time types of `chrono` and `time` are
+//! provided by the features of the same names.
+
+/// Trait for column types which may hold the current time.
+pub trait Timestamp {
+    /// Returns the current time.
+    fn now() -> Self;
+}
+
+impl<T: Timestamp> Timestamp for Option<T> {
+    #[inline]
+    fn now() -> Self {
+        Some(T::now())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::NaiveDateTime {
+    #[inline]
+    fn now() -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::DateTime<chrono::Utc> {
+    #[inline]
+    fn now() -> Self {
+        chrono::Utc::now()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    #[inline]
+    fn now() -> Self {
+        time::OffsetDateTime::now_utc()
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::PrimitiveDateTime {
+    #[inline]
+    fn now() -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        time::PrimitiveDateTime::new(now.date(), now.time())
+    }
+}
@@ -8,6 +8,13 @@ mod typed_nested_tuple_collection;
 pub use typed_nested_tuple_collection::*;
 mod homogeneously_typed_nested_tuple;
 pub use homogeneously_typed_nested_tuple::*;
+mod homogeneous_nested_values;
+pub use homogeneous_nested_values::*;
+use tuplities::prelude::NestedTupleInto;
+
+use crate::{
+    GetNestedColumns, HasTableExt, TableExt, TypedColumn, builder_error::DynamicColumnError,
+};
 
 /// Trait representing an object with an associated value type.
 pub trait ValueTyped {
@@ -76,3 +83,119 @@ impl<T> NonOptionalTyped for T where T: ColumnTyped<ValueType = <T as ColumnType
 pub trait OptionalTyped: ColumnTyped<ColumnType = Option<<Self as ValueTyped>::ValueType>> {}
 
 impl<T> OptionalTyped for T where T: ColumnTyped<ColumnType = Option<<T as ValueTyped>::ValueType>> {}
+
+/// Trait converting a model into the new values of its table, for instance
+/// to insert a copy of the record.
+///
+/// The new values hold every column of the new record, that is every column
+/// except for a surrogate primary key.
+pub trait ToNewValues: HasTableExt {
+    /// Returns the values of the model as new values, each of them set.
+    #[must_use]
+    fn to_new_values(&self) -> <Self::Table as TableExt>::NewValues;
+}
+
+impl<M> ToNewValues for M
+where
+    M: HasTableExt + GetNestedColumns<<M::Table as TableExt>::NewRecord>,
+    <<M::Table as TableExt>::NewRecord as TypedNestedTuple>::NestedTupleColumnType:
+        NestedTupleInto<<M::Table as TableExt>::NewValues>,
+{
+    #[inline]
+    fn to_new_values(&self) -> <Self::Table as TableExt>::NewValues {
+        self.get_nested_columns().nested_tuple_into()
+    }
+}
+
+/// Trait parsing a value of a column from its string representation, for
+/// instance to bind `table.column=value` assignments from a command line.
+///
+/// It is implemented for every column whose value type implements
+/// [`FromStr`](core::str::FromStr).
+pub trait ParseColumnValue: TypedColumn<Table: TableExt> {
+    /// Parses the provided string into a value of the column.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string representation of the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DynamicColumnError::Parse`] if the string cannot be parsed
+    /// into the value type of the column.
+    fn parse_value(s: &str) -> Result<Self::ValueType, DynamicColumnError>;
+}
+
+impl<C> ParseColumnValue for C
+where
+    C: TypedColumn<
+            Table: TableExt,
+            ValueType: core::str::FromStr<Err: core::error::Error + Send + Sync + 'static>,
+        >,
+{
+    #[inline]
+    fn parse_value(s: &str) -> Result<Self::ValueType, DynamicColumnError> {
+        s.parse().map_err(|error| {
+            DynamicColumnError::Parse {
+                table_name: <C::Table as TableExt>::TABLE_NAME,
+                column_name: C::NAME,
+                error: Box::new(error),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use tuplities::prelude::{
+        IntoNestedTupleOption, NestTuple, NestedTupleInto, NestedTupleOption,
+    };
+
+    /// The nested tuple of options of the flat tuple `T`.
+    type Options<T> = <<T as NestTuple>::Nested as IntoNestedTupleOption>::IntoOptions;
+
+    /// Asserts that nesting the flat tuple `values`, converting it into a
+    /// nested tuple of options and transposing it back is the identity.
+    fn assert_round_trip<T>(values: T)
+    where
+        T: NestTuple<
+            Nested: IntoNestedTupleOption + NestedTupleInto<Options<T>> + Clone + PartialEq + Debug,
+        >,
+        Options<T>: NestedTupleOption<Transposed = T::Nested>,
+    {
+        let nested = values.nest();
+        let options: Options<T> = nested.clone().nested_tuple_into();
+        assert_eq!(options.transpose(), Some(nested));
+    }
+
+    #[test]
+    fn test_round_trip_arities_0_to_8() {
+        assert_round_trip(());
+        assert_round_trip((1_i32,));
+        assert_round_trip((1_i32, "a".to_owned()));
+        assert_round_trip((1_i32, "a".to_owned(), true));
+        assert_round_trip((1_i32, "a".to_owned(), true, 2.5_f64));
+        assert_round_trip((1_i32, "a".to_owned(), true, 2.5_f64, 'c'));
+        assert_round_trip((1_i32, "a".to_owned(), true, 2.5_f64, 'c', vec![1_u8]));
+        assert_round_trip((1_i32, "a".to_owned(), true, 2.5_f64, 'c', vec![1_u8], -3_i64));
+        assert_round_trip((1_i32, "a".to_owned(), true, 2.5_f64, 'c', vec![1_u8], -3_i64, 4_u16));
+    }
+
+    #[test]
+    fn test_round_trip_default_values() {
+        assert_round_trip((0_i32,));
+        assert_round_trip((0_i32, String::new()));
+        assert_round_trip((
+            0_i32,
+            String::new(),
+            false,
+            0.0_f64,
+            '\0',
+            Vec::<u8>::new(),
+            0_i64,
+            0_u16,
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+//! Submodule defining and implementing the `HomogeneousNestedValues` trait.
+
+/// Trait for nested tuples of values which are all of type `VT`.
+pub trait HomogeneousNestedValues<VT>: Sized {
+    /// The nested tuple obtained by replacing each value with one of type `U`.
+    type Mapped<U>;
+
+    /// Applies `f` to each value, preserving the nesting.
+    fn map_homogeneous<U, F: Fn(VT) -> U>(self, f: &F) -> Self::Mapped<U>;
+
+    /// Appends the values to `values`, in order.
+    fn extend_vec(self, values: &mut Vec<VT>);
+
+    #[inline]
+    /// Collects the values into a vector, in order, such as to build an `IN`
+    /// list or to aggregate them.
+    fn into_vec(self) -> Vec<VT> {
+        let mut values = Vec::new();
+        self.extend_vec(&mut values);
+        values
+    }
+}
+
+impl<VT> HomogeneousNestedValues<VT> for () {
+    type Mapped<U> = ();
+
+    #[inline]
+    fn map_homogeneous<U, F: Fn(VT) -> U>(self, _f: &F) -> Self::Mapped<U> {}
+
+    #[inline]
+    fn extend_vec(self, _values: &mut Vec<VT>) {}
+}
+
+impl<VT> HomogeneousNestedValues<VT> for (VT,) {
+    type Mapped<U> = (U,);
+
+    #[inline]
+    fn map_homogeneous<U, F: Fn(VT) -> U>(self, f: &F) -> Self::Mapped<U> {
+        (f(self.0),)
+    }
+
+    #[inline]
+    fn extend_vec(self, values: &mut Vec<VT>) {
+        values.push(self.0);
+    }
+}
+
+impl<VT, Tail> HomogeneousNestedValues<VT> for (VT, Tail)
+where
+    Tail: HomogeneousNestedValues<VT>,
+{
+    type Mapped<U> = (U, Tail::Mapped<U>);
+
+    #[inline]
+    fn map_homogeneous<U, F: Fn(VT) -> U>(self, f: &F) -> Self::Mapped<U> {
+        (f(self.0), self.1.map_homogeneous(f))
+    }
+
+    #[inline]
+    fn extend_vec(self, values: &mut Vec<VT>) {
+        values.push(self.0);
+        self.1.extend_vec(values);
+    }
+}
@@ -10,6 +10,30 @@ use crate::{ColumnTyped, TableExt, ValueTyped};
 pub trait TypedColumn: diesel::Column<Table: Default> + ColumnTyped + Default + Copy {}
 impl<T> TypedColumn for T where T: diesel::Column<Table: Default> + ColumnTyped + Default + Copy {}
 
+/// Schema facts about a column, gathered into a single introspectable value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColumnMeta {
+    /// The name of the column.
+    pub name: &'static str,
+    /// The name of the table the column belongs to.
+    pub table: &'static str,
+    /// Whether the column accepts `NULL` values.
+    pub is_nullable: bool,
+    /// Whether the column is part of the primary key of its table.
+    pub is_primary_key: bool,
+    /// Whether the column is assigned a value when left unset in a builder,
+    /// either by a `default` attribute or as a surrogate primary key.
+    pub has_default: bool,
+}
+
+/// Trait for columns exposing their [`ColumnMeta`].
+///
+/// Implemented by the `TableModel` derive for every column of the model.
+pub trait HasColumnMeta: TypedColumn {
+    /// The schema facts about the column.
+    const META: ColumnMeta;
+}
+
 /// A dynamic column type placeholder.
 pub struct DynColumn<V> {
     /// The table of the column.
@@ -7,8 +7,9 @@ mod shared_animals;
 use std::{rc::Rc, sync::Arc};
 
 use diesel_builders::{
-    ColumnTyped, TryGetDynamicColumns, TrySetDynamicColumn, ValueTyped,
-    builder_error::DynamicColumnError, prelude::*,
+    ApplyChangeset, ChangesetColumns, ColumnTyped, OrderedNewValues, SortDirection,
+    TryGetDynamicColumns, TrySetDynamicColumn, ValueTyped, builder_error::DynamicColumnError,
+    prelude::*,
 };
 use shared_animals::*;
 
@@ -34,6 +35,28 @@ impl ColumnTyped for CursedColumn {
     type ColumnType = String;
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// A column marker sharing the name of `animals::name` with a different
+/// value type, causing a type mismatch in `TrySetDynamicColumn`.
+struct MistypedNameColumn;
+
+impl Expression for MistypedNameColumn {
+    type SqlType = diesel::sql_types::Integer;
+}
+
+impl Column for MistypedNameColumn {
+    type Table = animals::table;
+    const NAME: &'static str = "name";
+}
+
+impl ValueTyped for MistypedNameColumn {
+    type ValueType = i32;
+}
+
+impl ColumnTyped for MistypedNameColumn {
+    type ColumnType = i32;
+}
+
 #[test]
 fn test_simple_table() -> Result<(), Box<dyn std::error::Error>> {
     let mut conn = shared::establish_connection()?;
@@ -315,6 +338,32 @@ fn test_get_column_blanket_impls() -> Result<(), Box<dyn std::error::Error>> {
         &Some("A test description".to_string())
     );
 
+    // Test nested references, as produced by closures over iterators of references
+    let animal_ref_ref = &animal_ref;
+    assert_eq!(animal_ref_ref.get_column::<animals::name>(), "Test Animal");
+    assert_eq!(
+        <&&Animal as diesel_builders::GetColumn<animals::description>>::get_column_ref(
+            &animal_ref_ref
+        ),
+        &Some("A test description".to_string())
+    );
+    let animal_refs = [&animal];
+    assert_eq!(
+        animal_refs
+            .iter()
+            .map(<&Animal as diesel_builders::GetColumn<animals::name>>::get_column)
+            .collect::<Vec<_>>(),
+        ["Test Animal"]
+    );
+
+    // Test mutable reference blanket impl
+    let mut animal_mut = animal.clone();
+    let animal_mut_ref = &mut animal_mut;
+    assert_eq!(
+        <&mut Animal as diesel_builders::GetColumn<animals::name>>::get_column_ref(&animal_mut_ref),
+        "Test Animal"
+    );
+
     // Test Box blanket impl
     let animal_box = Box::new(animal.clone());
     assert_eq!(animal_box.get_column::<animals::name>(), "Test Animal");
@@ -423,3 +472,748 @@ fn test_try_get_dynamic_column_blanket_impls() -> Result<(), Box<dyn std::error:
 
     Ok(())
 }
+
+#[test]
+fn test_validate_all_collects_every_error() {
+    let mut builder = animals::table::builder();
+    assert_eq!(builder.validate_all(), Ok(()));
+
+    // The plain setters bypass validation, so both values are invalid.
+    builder.set_column_ref::<animals::name>("   ");
+    builder.set_column_ref::<animals::description>(Some(String::new()));
+
+    let errors = builder.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.errors(), &[NewAnimalError::NameEmpty, NewAnimalError::DescriptionEmpty]);
+
+    // A valid name leaves only the description error.
+    builder.set_column_ref::<animals::name>("Max");
+    let errors = builder.validate_all().unwrap_err();
+    assert_eq!(errors.into_errors(), vec![NewAnimalError::DescriptionEmpty]);
+}
+
+#[derive(AsChangeset)]
+#[diesel(table_name = animals)]
+/// Partial update of an animal, as it would be written for diesel.
+struct AnimalChangeset {
+    /// The new name, if changed.
+    name: Option<String>,
+    /// The new description, if changed.
+    description: Option<Option<String>>,
+}
+
+impl ChangesetColumns for AnimalChangeset {
+    type Columns = (animals::name, (animals::description,));
+
+    fn changeset_values(self) -> (Option<String>, (Option<Option<String>>,)) {
+        (self.name, (self.description,))
+    }
+}
+
+#[test]
+fn test_apply_changeset() -> Result<(), Box<dyn std::error::Error>> {
+    let builder = animals::table::builder().try_name("Max")?;
+
+    // Only the description is changed, the name is left untouched.
+    let builder = builder.apply_changeset(AnimalChangeset {
+        name: None,
+        description: Some(Some("A good boy".to_owned())),
+    })?;
+    assert_eq!(builder.may_get_column_ref::<animals::name>().map(String::as_str), Some("Max"));
+    assert_eq!(
+        builder.may_get_column_ref::<animals::description>(),
+        Some(&Some("A good boy".to_owned()))
+    );
+
+    // Changed values go through the column validators.
+    let error = builder
+        .apply_changeset(AnimalChangeset { name: Some(String::new()), description: None })
+        .unwrap_err();
+    assert_eq!(error, NewAnimalError::NameEmpty);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_nested_columns_owned() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{GetNestedColumns, tuplities::FlattenNestedTuple};
+
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let animal = animals::table::builder()
+        .try_name("Max")?
+        .try_description(Some("A good boy".to_owned()))?
+        .insert(&mut conn)?;
+
+    let (name, description): (String, Option<String>) =
+        GetNestedColumns::<(animals::name, (animals::description,))>::get_nested_columns(&animal)
+            .flatten();
+    // The owned values outlive the model they were read from.
+    drop(animal);
+    assert_eq!(name, "Max");
+    assert_eq!(description.as_deref(), Some("A good boy"));
+
+    Ok(())
+}
+
+#[test]
+fn test_check_column() -> Result<(), Box<dyn std::error::Error>> {
+    let builder = animals::table::builder().try_name("Max")?;
+    let snapshot = builder.clone();
+
+    assert_eq!(builder.check_column::<animals::name>(&"Buddy".to_owned()), Ok(()));
+    assert_eq!(
+        builder.check_column::<animals::name>(&String::new()),
+        Err(NewAnimalError::NameEmpty)
+    );
+    assert_eq!(builder.check_column::<animals::description>(&None), Ok(()));
+
+    // Checking values never mutates the builder.
+    assert_eq!(builder, snapshot);
+    assert_eq!(builder.may_get_column_ref::<animals::name>().map(String::as_str), Some("Max"));
+
+    Ok(())
+}
+
+#[test]
+fn test_load_first_or_insert() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    // No animal is named "Max" yet, so the builder is inserted.
+    let inserted = <(animals::name,)>::load_first_or_insert(
+        ("Max",),
+        animals::table::builder().try_name("Max")?,
+        &mut conn,
+    )?;
+    assert_eq!(inserted.name(), "Max");
+
+    // The existing record is now loaded instead, and the builder discarded.
+    let loaded = <(animals::name,)>::load_first_or_insert(
+        ("Max",),
+        animals::table::builder().try_name("Max")?.try_description(Some("Unused".to_owned()))?,
+        &mut conn,
+    )?;
+    assert_eq!(loaded, inserted);
+    assert_eq!(animals::table.count().get_result::<i64>(&mut conn)?, 1);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_try_from_json() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::TableBuilder;
+
+    let builder = TableBuilder::<animals::table>::try_from_json(&serde_json::json!({
+        "name": "Max",
+        "description": "A good boy",
+    }))?;
+    assert_eq!(builder.may_get_column_ref::<animals::name>().map(String::as_str), Some("Max"));
+    assert_eq!(
+        builder.may_get_column_ref::<animals::description>(),
+        Some(&Some("A good boy".to_owned()))
+    );
+
+    // Values go through the column validators.
+    let result = TableBuilder::<animals::table>::try_from_json(&serde_json::json!({ "name": "" }));
+    assert!(matches!(result, Err(DynamicColumnError::Validation(_))));
+
+    // Values must deserialize into the type of their column.
+    let result = TableBuilder::<animals::table>::try_from_json(&serde_json::json!({ "name": 42 }));
+    assert!(matches!(
+        result,
+        Err(DynamicColumnError::Parse { table_name: "animals", column_name: "name", .. })
+    ));
+
+    let result = TableBuilder::<animals::table>::try_from_json(&serde_json::json!({
+        "name": "Max",
+        "color": "brown",
+    }));
+    assert!(matches!(result, Err(DynamicColumnError::UnknownField(key)) if key == "color"));
+
+    let result = TableBuilder::<animals::table>::try_from_json(&serde_json::json!(["Max"]));
+    assert!(matches!(result, Err(DynamicColumnError::NotAnObject)));
+
+    Ok(())
+}
+
+#[test]
+fn test_model_update() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let animal = animals::table::builder()
+        .try_name("Max")?
+        .try_description(Some("A good boy".to_owned()))?
+        .insert(&mut conn)?;
+
+    // Only the name is changed, the description is left untouched.
+    let updated = animal.update(
+        AnimalChangeset { name: Some("Maximus".to_owned()), description: None },
+        &mut conn,
+    )?;
+    assert_eq!(updated.id(), animal.id());
+    assert_eq!(updated.name(), "Maximus");
+    assert_eq!(updated.description().as_deref(), Some("A good boy"));
+
+    let reloaded: Animal = Animal::find(animal.id(), &mut conn)?;
+    assert_eq!(reloaded, updated);
+
+    Ok(())
+}
+
+#[test]
+fn test_column_meta() {
+    use diesel_builders::ColumnMeta;
+
+    assert_eq!(
+        animals::description::META,
+        ColumnMeta {
+            name: "description",
+            table: "animals",
+            is_nullable: true,
+            is_primary_key: false,
+            has_default: false,
+        }
+    );
+
+    let id_meta = animals::id::META;
+    assert!(id_meta.is_primary_key);
+    assert!(id_meta.has_default);
+    assert!(!id_meta.is_nullable);
+    assert!(!animals::name::META.is_nullable);
+}
+
+#[test]
+fn test_projection_nullable_columns() {
+    use diesel_builders::columns::NonEmptyProjection;
+
+    assert_eq!(<(animals::name, animals::description)>::nullable_columns(), vec!["description"]);
+    assert_eq!(
+        <(animals::description, animals::id, animals::name)>::nullable_columns(),
+        vec!["description"]
+    );
+    assert!(<(animals::id, animals::name)>::nullable_columns().is_empty());
+}
+
+/// Error variants of the ordered validation of the animal columns.
+#[derive(Debug, PartialEq, thiserror::Error)]
+enum OrderedAnimalError {
+    /// The description was assigned before the name.
+    #[error("Animal description cannot be assigned before its name")]
+    DescriptionBeforeName,
+    /// The description failed the validation of the animals table.
+    #[error(transparent)]
+    Animal(#[from] NewAnimalError),
+}
+
+/// Requires the name of an animal to be assigned before its description.
+impl ValidateColumn<animals::description>
+    for OrderedNewValues<<animals::table as TableExt>::NewValues>
+{
+    type Error = OrderedAnimalError;
+
+    fn validate_column_in_context(&self, value: &String) -> Result<(), Self::Error> {
+        if !self.set_order().contains(&"name") {
+            return Err(OrderedAnimalError::DescriptionBeforeName);
+        }
+        Ok(<<animals::table as TableExt>::NewValues as ValidateColumn<animals::description>>::validate_column(value)?)
+    }
+}
+
+#[test]
+fn test_ordered_new_values_set_order() -> Result<(), Box<dyn std::error::Error>> {
+    let mut values = OrderedNewValues::new(animals::table::default_new_values());
+    assert!(values.set_order().is_empty());
+
+    assert_eq!(
+        values.try_set_column_ref::<animals::description>(Some("First".to_owned())),
+        Err(OrderedAnimalError::DescriptionBeforeName)
+    );
+    assert!(values.set_order().is_empty());
+
+    values
+        .set_column_ref::<animals::name>("Buddy")
+        .try_set_column_ref::<animals::description>(Some("A good dog".to_owned()))?;
+    assert_eq!(values.set_order(), ["name", "description"]);
+    assert_eq!(
+        values.may_get_column_ref::<animals::description>(),
+        Some(&Some("A good dog".to_owned()))
+    );
+
+    // Assigning a column again moves it to the end of the set order.
+    values.set_column_ref::<animals::name>("Rex");
+    assert_eq!(values.set_order(), ["description", "name"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_load_first_sorted() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    assert_eq!(animals::name::load_first_sorted(SortDirection::Descending, &mut conn)?, None);
+
+    for name in ["Max", "Buddy", "Rex", "Charlie"] {
+        animals::table::builder().try_name(name)?.insert(&mut conn)?;
+    }
+
+    let largest = animals::name::load_first_sorted(SortDirection::Descending, &mut conn)?;
+    assert_eq!(largest.as_ref().map(|animal| animal.name().as_str()), Some("Rex"));
+
+    let smallest = animals::name::load_first_sorted(SortDirection::Ascending, &mut conn)?;
+    assert_eq!(smallest.as_ref().map(|animal| animal.name().as_str()), Some("Buddy"));
+
+    Ok(())
+}
+
+#[test]
+fn test_into_new_values() -> Result<(), Box<dyn std::error::Error>> {
+    // The nullable description starts out set to `NULL`.
+    let builder = animals::table::builder();
+    assert_eq!(builder.new_values(), &(None, (Some(None),)));
+
+    let builder = builder.try_name("Buddy")?.try_description(Some("A good dog".to_owned()))?;
+    let expected = (Some("Buddy".to_owned()), (Some(Some("A good dog".to_owned())),));
+    assert_eq!(builder.new_values(), &expected);
+    assert_eq!(builder.into_new_values(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn test_upsert_batch() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let mut animals = Vec::new();
+    for i in 0..50 {
+        animals.push(animals::table::builder().try_name(format!("Animal {i}"))?.insert(&mut conn)?);
+    }
+
+    // Only the first 20 animals are left in the table, so that the other 30
+    // are inserted anew while the first 20 conflict on their primary key.
+    let last_kept_id = *animals[19].id();
+    diesel::delete(animals::table.filter(animals::id.gt(last_kept_id))).execute(&mut conn)?;
+
+    for animal in &mut animals {
+        animal.set_name(format!("{} (imported)", animal.name()));
+    }
+
+    let mut upserted = Animal::upsert_batch(&animals, &mut conn)?;
+    upserted.sort_by_key(|animal| *animal.id());
+    assert_eq!(upserted, animals);
+
+    let mut reloaded: Vec<Animal> = animals::table.load(&mut conn)?;
+    reloaded.sort_by_key(|animal| *animal.id());
+    assert_eq!(reloaded, animals);
+
+    Ok(())
+}
+
+#[test]
+fn test_upsert_batch_spanning_several_statements() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    // With 3 columns, at most 333 animals fit in the 999 bind parameters of a
+    // statement, so 500 animals are upserted with two statements.
+    let mut animals = Vec::new();
+    for i in 0..500 {
+        animals.push(animals::table::builder().try_name(format!("Animal {i}"))?.insert(&mut conn)?);
+    }
+
+    // Every other animal is removed, so that both statements mix rows inserted
+    // anew with rows conflicting on their primary key.
+    for animal in animals.iter().step_by(2) {
+        diesel::delete(animals::table.find(*animal.id())).execute(&mut conn)?;
+    }
+    // The animal at position 401 is kept, so it conflicts in the second statement.
+    let kept: i64 = animals::table.find(*animals[401].id()).count().get_result(&mut conn)?;
+    assert_eq!(kept, 1);
+
+    for animal in &mut animals {
+        animal.set_name(format!("{} (imported)", animal.name()));
+    }
+
+    let mut upserted = Animal::upsert_batch(&animals, &mut conn)?;
+    upserted.sort_by_key(|animal| *animal.id());
+    assert_eq!(upserted, animals);
+
+    let mut reloaded: Vec<Animal> = animals::table.load(&mut conn)?;
+    reloaded.sort_by_key(|animal| *animal.id());
+    assert_eq!(reloaded.len(), 500);
+    assert_eq!(reloaded, animals);
+
+    Ok(())
+}
+
+#[test]
+fn test_completed_bundle_may_get_column() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{CompletedTableBuilderBundle, TableBuilderBundle};
+
+    let mut bundle = TableBuilderBundle::<animals::table>::default();
+    bundle.try_name_ref("Buddy")?.try_description_ref(Some("A good dog".to_owned()))?;
+    let completed = CompletedTableBuilderBundle::try_from(bundle)?;
+
+    assert_eq!(completed.may_get_column_ref::<animals::name>().map(String::as_str), Some("Buddy"));
+    assert_eq!(
+        completed.may_get_column::<animals::description>(),
+        Some(Some("A good dog".to_owned()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_completed_bundle_into_incomplete() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{CompletedTableBuilderBundle, TableBuilderBundle};
+
+    let mut bundle = TableBuilderBundle::<animals::table>::default();
+    bundle.try_name_ref("Buddy")?.try_description_ref(Some("A good dog".to_owned()))?;
+    let completed = CompletedTableBuilderBundle::try_from(bundle)?;
+
+    let mut bundle = completed.into_incomplete();
+    assert_eq!(bundle.may_get_column_ref::<animals::name>().map(String::as_str), Some("Buddy"));
+    assert_eq!(
+        bundle.may_get_column::<animals::description>(),
+        Some(Some("A good dog".to_owned()))
+    );
+
+    // The reverted bundle is editable again.
+    bundle.try_name_ref("Max")?;
+    let completed = CompletedTableBuilderBundle::try_from(bundle)?;
+    assert_eq!(completed.may_get_column_ref::<animals::name>().map(String::as_str), Some("Max"));
+
+    Ok(())
+}
+
+#[test]
+fn test_completed_bundle_into_insertable() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{
+        CompletedTableBuilderBundle, IncompleteBuilderError, TableBuilderBundle,
+    };
+
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let mut bundle = TableBuilderBundle::<animals::table>::default();
+    bundle.try_name_ref("Buddy")?;
+    let insertable = CompletedTableBuilderBundle::try_from(bundle)?.into_insertable()?;
+
+    let animal: Animal =
+        diesel::insert_into(animals::table).values(insertable).get_result(&mut conn)?;
+    assert_eq!(animal.name(), "Buddy");
+    assert!(animal.description().is_none());
+
+    let bundle = TableBuilderBundle::<animals::table>::default();
+    assert!(matches!(
+        CompletedTableBuilderBundle::try_from(bundle)?.into_insertable(),
+        Err(IncompleteBuilderError::MissingMandatoryField {
+            table_name: "animals",
+            field_name: "name"
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_model_to_new_values() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{TableExt, ToNewValues, TryMaySetNestedColumns};
+
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let animal = animals::table::builder()
+        .try_name("Buddy")?
+        .try_description(Some("A good dog".to_owned()))?
+        .insert(&mut conn)?;
+
+    let new_values = animal.to_new_values();
+    assert_eq!(new_values, (Some("Buddy".to_owned()), (Some(Some("A good dog".to_owned())),)));
+
+    let mut builder = animals::table::builder();
+    TryMaySetNestedColumns::<NewAnimalError, <animals::table as TableExt>::NewRecord>::try_may_set_nested_columns(
+        &mut builder,
+        new_values,
+    )?;
+    let copy = builder.insert(&mut conn)?;
+
+    assert_ne!(copy.id(), animal.id());
+    assert_eq!(copy.name(), animal.name());
+    assert_eq!(copy.description(), animal.description());
+
+    Ok(())
+}
+
+/// A patch of the animal columns, where `None` leaves a column untouched.
+struct AnimalPatch {
+    /// The new name, if any.
+    name: Option<String>,
+    /// The new description, if any.
+    description: Option<Option<String>>,
+}
+
+impl From<AnimalPatch> for (Option<String>, (Option<Option<String>>,)) {
+    fn from(patch: AnimalPatch) -> Self {
+        (patch.name, (patch.description,))
+    }
+}
+
+#[test]
+fn test_may_set_from_option() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{TableExt, may_set_from_option};
+
+    type Columns = <animals::table as TableExt>::NewRecord;
+
+    let mut builder = animals::table::builder().try_name("Buddy")?;
+    let unpatched = builder.clone();
+
+    may_set_from_option::<NewAnimalError, Columns, _, AnimalPatch>(&mut builder, None)?;
+    assert_eq!(builder, unpatched);
+
+    let patch = AnimalPatch { name: None, description: Some(Some("A good dog".to_owned())) };
+    may_set_from_option::<NewAnimalError, Columns, _, _>(&mut builder, Some(patch))?;
+    assert_eq!(builder.may_get_column_ref::<animals::name>().map(String::as_str), Some("Buddy"));
+    assert_eq!(
+        builder.may_get_column::<animals::description>(),
+        Some(Some("A good dog".to_owned()))
+    );
+
+    // Invalid present values are rejected.
+    let patch = AnimalPatch { name: Some(String::new()), description: None };
+    assert!(
+        may_set_from_option::<NewAnimalError, Columns, _, _>(&mut builder, Some(patch)).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_find_opt() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+
+    // Without the table, the query fails with an error other than `NotFound`.
+    assert!(matches!(
+        Animal::find_opt(&1, &mut conn),
+        Err(diesel::result::Error::DatabaseError(..))
+    ));
+
+    setup_animal_tables(&mut conn)?;
+    let animal = animals::table::builder().try_name("Buddy")?.insert(&mut conn)?;
+
+    assert_eq!(Animal::find_opt(animal.id(), &mut conn)?, Some(animal.clone()));
+    assert_eq!(Animal::find_opt(&(animal.id() + 1), &mut conn)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_missing_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let buddy = animals::table::builder().try_name("Buddy")?.insert(&mut conn)?;
+    let max = animals::table::builder().try_name("Max")?.insert(&mut conn)?;
+    let absent = [max.id() + 1, max.id() + 2];
+
+    assert_eq!(
+        Animal::missing_ids(&[absent[1], *buddy.id(), absent[0], *max.id()], &mut conn)?,
+        vec![absent[1], absent[0]]
+    );
+    assert!(Animal::exists_all(&[*buddy.id(), *max.id()], &mut conn)?);
+    assert!(!Animal::exists_all(&[*buddy.id(), absent[0]], &mut conn)?);
+    assert!(Animal::exists_all(&[], &mut conn)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_column_type_mismatch() {
+    let dyn_mistyped_name = MistypedNameColumn.into();
+    let mut animal_builder = animals::table::builder();
+
+    let error = animal_builder.try_set_dynamic_column_ref(dyn_mistyped_name, &42).unwrap_err();
+    assert!(matches!(
+        error,
+        DynamicColumnError::TypeMismatch {
+            column: "name",
+            expected: "alloc::string::String",
+            actual: "i32"
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Type mismatch for column `name`: expected `alloc::string::String`, found `i32`"
+    );
+    assert_eq!(animal_builder.may_get_column_ref::<animals::name>(), None);
+}
+
+/// Caller-defined error type wrapping the validation errors of the animals.
+#[derive(Debug, PartialEq, thiserror::Error)]
+enum RegistrationError {
+    /// The animal is invalid.
+    #[error("Invalid animal: {0}")]
+    Animal(NewAnimalError),
+}
+
+/// Builds an animal, reporting its validation errors as `RegistrationError`.
+fn animal_registration(
+    name: &str,
+    description: &str,
+) -> Result<TableBuilder<animals::table>, RegistrationError> {
+    Ok(animals::table::builder()
+        .try_map_err(RegistrationError::Animal)
+        .try_name(name)?
+        .try_description(Some(description.to_owned()))?
+        .into_inner())
+}
+
+#[test]
+fn test_try_map_err() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    assert_eq!(
+        animal_registration("", "A good dog").err(),
+        Some(RegistrationError::Animal(NewAnimalError::NameEmpty))
+    );
+    assert_eq!(
+        animal_registration("Buddy", "").err(),
+        Some(RegistrationError::Animal(NewAnimalError::DescriptionEmpty))
+    );
+
+    let animal = animal_registration("Buddy", "A good dog")?.insert(&mut conn)?;
+    assert_eq!(animal.name(), "Buddy");
+
+    // The wrapper inserts as well, mapping the validation errors of the insertion.
+    let animal = animals::table::builder()
+        .try_map_err(RegistrationError::Animal)
+        .try_name("Max")?
+        .insert(&mut conn)?;
+    assert!(animal.description().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_try_set_columns_from() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let builder = animals::table::builder()
+        .try_set_columns_from(("Buddy".to_owned(), Some("A good dog".to_owned())))?;
+    assert_eq!(builder.may_get_column_ref::<animals::name>(), Some(&"Buddy".to_owned()));
+
+    let animal = builder.insert(&mut conn)?;
+    assert_eq!(animal.name(), "Buddy");
+    assert_eq!(animal.description().as_deref(), Some("A good dog"));
+
+    let result =
+        animals::table::builder().try_set_columns_from(("Max".to_owned(), Some(String::new())));
+    assert_eq!(result.err(), Some(NewAnimalError::DescriptionEmpty));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn test_find_by_iexact() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let rex = animals::table::builder().try_name("Rex")?.insert(&mut conn)?;
+    animals::table::builder().try_name("Rexy")?.insert(&mut conn)?;
+
+    assert_eq!(animals::name::find_by_iexact("rex", &mut conn)?, vec![rex.clone()]);
+    assert_eq!(animals::name::find_by_iexact("REX", &mut conn)?, vec![rex]);
+    // Pattern characters are matched literally.
+    assert!(animals::name::find_by_iexact("rex%", &mut conn)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_upsert_excluding() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+    // Logs every statement assigning the description of an animal.
+    diesel::sql_query("CREATE TABLE description_writes (animal_id INTEGER NOT NULL)")
+        .execute(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TRIGGER log_description_writes AFTER UPDATE OF description ON animals
+        BEGIN INSERT INTO description_writes (animal_id) VALUES (NEW.id); END",
+    )
+    .execute(&mut conn)?;
+
+    let mut animal = animals::table::builder()
+        .try_name("Buddy")?
+        .try_description("A good dog")?
+        .insert(&mut conn)?;
+    diesel::update(animals::table.find(animal.id()))
+        .set(animals::description.eq("A very good dog"))
+        .execute(&mut conn)?;
+    diesel::delete(description_writes::table).execute(&mut conn)?;
+
+    animal.set_name("Buddy Junior".to_owned());
+    let upserted = animal.upsert_excluding::<(animals::description,)>(&mut conn)?;
+    assert_eq!(upserted.name(), "Buddy Junior");
+    assert_eq!(upserted.description().as_deref(), Some("A very good dog"));
+
+    // The excluded column is not part of the `SET` clause at all.
+    let writes: i64 = description_writes::table.count().get_result(&mut conn)?;
+    assert_eq!(writes, 0);
+
+    Ok(())
+}
+
+diesel::table! {
+    /// Log of the statements assigning the description of an animal.
+    description_writes (animal_id) {
+        /// The animal whose description was assigned.
+        animal_id -> Integer,
+    }
+}
+
+#[test]
+fn test_upsert_batch_deduplicates_by_primary_key() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let mut first = animals::table::builder().try_name("Buddy")?.insert(&mut conn)?;
+    let mut second = first.clone();
+    first.set_name("Buddy (first)".to_owned());
+    second.set_name("Buddy (second)".to_owned());
+
+    // The last model sharing a primary key wins.
+    let upserted = Animal::upsert_batch(&[first, second.clone()], &mut conn)?;
+    assert_eq!(upserted, vec![second.clone()]);
+
+    let reloaded: Vec<Animal> = animals::table.load(&mut conn)?;
+    assert_eq!(reloaded, vec![second]);
+
+    Ok(())
+}
+
+#[test]
+fn test_append_column() -> Result<(), Box<dyn std::error::Error>> {
+    let mut builder = animals::table::builder().try_name("Buddy")?;
+    builder.append_column::<animals::name, _>(" Junior")?;
+    assert_eq!(
+        builder.may_get_column_ref::<animals::name>().map(String::as_str),
+        Some("Buddy Junior")
+    );
+
+    // The validators run on the appended value.
+    let too_long = "a".repeat(100);
+    assert_eq!(
+        builder.append_column::<animals::name, _>(too_long.as_str()).unwrap_err(),
+        NewAnimalError::NameTooLong
+    );
+
+    Ok(())
+}
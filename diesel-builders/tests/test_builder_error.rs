@@ -3,7 +3,9 @@
 use std::{error::Error, num::ParseIntError};
 
 use diesel::result::{DatabaseErrorInformation, DatabaseErrorKind};
-use diesel_builders::{BuilderError, IncompleteBuilderError};
+use diesel_builders::{
+    BuilderError, ForeignModelMissing, IncompleteBuilderError, ValidationErrors,
+};
 
 #[test]
 fn test_builder_error_diesel_display() {
@@ -19,12 +21,16 @@ fn test_builder_error_incomplete_display() {
     let incomplete_error = IncompleteBuilderError::MissingMandatoryTriangularField {
         table_name: "mock_table",
         field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
     };
     let builder_error: BuilderError<IncompleteBuilderError> =
         BuilderError::Incomplete(incomplete_error);
 
     let display_string = format!("{builder_error}");
-    assert_eq!(display_string, "Missing mandatory triangular builder field: `mock_table.c_id`");
+    assert_eq!(
+        display_string,
+        "Missing mandatory triangular builder field: `mock_table.c_id` referencing `mock_referenced_table`"
+    );
 }
 
 #[test]
@@ -51,6 +57,7 @@ fn test_builder_error_incomplete_source() {
     let incomplete_error = IncompleteBuilderError::MissingMandatoryTriangularField {
         table_name: "mock_table",
         field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
     };
     let builder_error: BuilderError<ParseIntError> = BuilderError::Incomplete(incomplete_error);
 
@@ -58,7 +65,7 @@ fn test_builder_error_incomplete_source() {
     assert!(source.is_some());
     assert_eq!(
         source.unwrap().to_string(),
-        "Missing mandatory triangular builder field: `mock_table.c_id`"
+        "Missing mandatory triangular builder field: `mock_table.c_id` referencing `mock_referenced_table`"
     );
 }
 
@@ -77,9 +84,13 @@ fn test_incomplete_builder_error_display() {
     let error = IncompleteBuilderError::MissingMandatoryTriangularField {
         table_name: "mock_table",
         field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
     };
     let display_string = format!("{error}");
-    assert_eq!(display_string, "Missing mandatory triangular builder field: `mock_table.c_id`");
+    assert_eq!(
+        display_string,
+        "Missing mandatory triangular builder field: `mock_table.c_id` referencing `mock_referenced_table`"
+    );
 
     let error = IncompleteBuilderError::MissingMandatoryField {
         table_name: "mock_table",
@@ -94,6 +105,7 @@ fn test_incomplete_builder_error_database_error_information() {
     let error = IncompleteBuilderError::MissingMandatoryTriangularField {
         table_name: "mock_table",
         field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
     };
     assert_eq!(error.message(), "Missing mandatory triangular builder field");
     assert_eq!(error.details(), None);
@@ -116,6 +128,33 @@ fn test_incomplete_builder_error_database_error_information() {
     assert_eq!(error.statement_position(), None);
 }
 
+#[test]
+fn test_incomplete_builder_error_iteration() {
+    let error = IncompleteBuilderError::MissingMandatoryFields {
+        table_name: "mock_table",
+        field_names: vec!["name", "email"],
+    };
+    assert_eq!(error.to_string(), "Missing mandatory fields of `mock_table`: `name`, `email`");
+    assert_eq!(error.len(), 2);
+    assert!(!error.is_empty());
+    assert_eq!(error.column_name(), Some("name"));
+
+    let mut missing = Vec::new();
+    for column_name in &error {
+        missing.push(column_name);
+    }
+    assert_eq!(missing, vec!["name", "email"]);
+    assert_eq!(error.into_iter().collect::<Vec<_>>(), vec!["name", "email"]);
+
+    let error = IncompleteBuilderError::MissingMandatoryTriangularField {
+        table_name: "mock_table",
+        field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
+    };
+    assert_eq!(error.len(), 1);
+    assert_eq!(error.missing_columns(), ["c_id"]);
+}
+
 #[test]
 fn test_from_diesel_error() {
     let diesel_error = diesel::result::Error::NotFound;
@@ -133,6 +172,29 @@ fn test_from_incomplete_builder_error() {
     assert!(matches!(builder_error, BuilderError::Incomplete(_)));
 }
 
+#[test]
+fn test_builder_error_foreign_model_missing() {
+    let missing = ForeignModelMissing {
+        table_name: "mock_table",
+        field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
+    };
+    let builder_error: BuilderError<IncompleteBuilderError> = missing.into();
+    assert!(matches!(builder_error, BuilderError::ForeignModelMissing(_)));
+    assert_eq!(
+        format!("{builder_error}"),
+        "Model referenced by `mock_table.c_id` is missing from `mock_referenced_table`"
+    );
+    assert!(!builder_error.is_retryable());
+
+    let diesel_error: diesel::result::Error = builder_error.into();
+    assert!(matches!(
+        &diesel_error,
+        diesel::result::Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, info)
+            if info.table_name() == Some("mock_table") && info.column_name() == Some("c_id")
+    ));
+}
+
 #[test]
 fn test_builder_error_from_diesel_error_conversion() {
     // Create a mock DatabaseErrorInformation
@@ -213,6 +275,7 @@ fn test_incomplete_builder_error_debug() {
     let error = IncompleteBuilderError::MissingMandatoryTriangularField {
         table_name: "mock_table",
         field_name: "c_id",
+        referenced_table_name: "mock_referenced_table",
     };
     let debug_string = format!("{error:?}");
     assert!(debug_string.contains("MissingMandatoryTriangularField"));
@@ -246,6 +309,7 @@ fn test_incomplete_builder_error_partial_eq() {
     let error4 = IncompleteBuilderError::MissingMandatoryTriangularField {
         table_name: "mock_table",
         field_name: "name",
+        referenced_table_name: "mock_referenced_table",
     };
     assert_ne!(error1, error4);
 }
@@ -274,3 +338,70 @@ fn test_incomplete_builder_error_hash() {
 
     assert_eq!(set.len(), 2); // error1 and error2 should be the same, error3 different
 }
+
+#[test]
+fn test_validation_errors_display() {
+    let errors = ValidationErrors::new(vec![
+        "abc".parse::<i32>().unwrap_err(),
+        String::new().parse::<i32>().unwrap_err(),
+    ]);
+
+    assert_eq!(errors.len(), 2);
+    assert!(!errors.is_empty());
+    assert_eq!(
+        errors.to_string(),
+        "2 validation error(s); invalid digit found in string; cannot parse integer from empty string"
+    );
+    assert_eq!((&errors).into_iter().count(), 2);
+    assert_eq!(errors.into_iter().count(), 2);
+}
+
+#[test]
+fn test_builder_error_as_validation() {
+    let validation_error: ParseIntError = "abc".parse::<i32>().unwrap_err();
+    let builder_error = BuilderError::Validation(validation_error.clone());
+    assert_eq!(builder_error.as_validation(), Some(&validation_error));
+    assert!(builder_error.as_database().is_none());
+
+    let builder_error: BuilderError<ParseIntError> = diesel::result::Error::NotFound.into();
+    assert_eq!(builder_error.as_validation(), None);
+}
+
+#[test]
+fn test_builder_error_as_database() {
+    let builder_error: BuilderError<ParseIntError> = diesel::result::Error::NotFound.into();
+    assert!(matches!(builder_error.as_database(), Some(diesel::result::Error::NotFound)));
+    assert!(builder_error.as_validation().is_none());
+
+    let builder_error: BuilderError<ParseIntError> =
+        IncompleteBuilderError::MissingMandatoryField {
+            table_name: "mock_table",
+            field_name: "name",
+        }
+        .into();
+    assert!(builder_error.as_database().is_none());
+    assert!(builder_error.as_validation().is_none());
+}
+
+#[test]
+fn test_builder_error_is_retryable() {
+    let builder_error: BuilderError<ParseIntError> = diesel::result::Error::DatabaseError(
+        DatabaseErrorKind::SerializationFailure,
+        Box::new("could not serialize access due to concurrent update".to_owned()),
+    )
+    .into();
+    assert!(builder_error.is_retryable());
+
+    let builder_error: BuilderError<ParseIntError> = diesel::result::Error::DatabaseError(
+        DatabaseErrorKind::UniqueViolation,
+        Box::new("UNIQUE constraint failed: animals.name".to_owned()),
+    )
+    .into();
+    assert!(!builder_error.is_retryable());
+
+    let builder_error: BuilderError<ParseIntError> = diesel::result::Error::NotFound.into();
+    assert!(!builder_error.is_retryable());
+
+    let builder_error = BuilderError::Validation("abc".parse::<i32>().unwrap_err());
+    assert!(!builder_error.is_retryable());
+}
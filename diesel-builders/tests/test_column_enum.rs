@@ -0,0 +1,39 @@
+//! Test for the enum of the columns of a table generated with
+//! `#[table_model(column_enum)]`.
+
+use diesel_builders::{ColumnMeta, prelude::*};
+
+/// Animal model.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = animals)]
+#[table_model(surrogate_key, column_enum)]
+pub struct Animal {
+    /// Primary key.
+    id: i32,
+    /// Name of the animal.
+    name: String,
+    /// Optional description of the animal.
+    description: Option<String>,
+}
+
+#[test]
+fn test_column_enum() {
+    assert_eq!(
+        AnimalColumn::ALL,
+        [AnimalColumn::Id, AnimalColumn::Name, AnimalColumn::Description]
+    );
+
+    for column in AnimalColumn::ALL {
+        let expected = match column {
+            AnimalColumn::Id => "id",
+            AnimalColumn::Name => "name",
+            AnimalColumn::Description => "description",
+        };
+        assert_eq!(column.name(), expected);
+    }
+
+    let meta: ColumnMeta = AnimalColumn::Description.into();
+    assert_eq!(meta, animals::description::META);
+    assert_eq!(AnimalColumn::Id.meta().table, "animals");
+    assert!(AnimalColumn::Id.meta().is_primary_key);
+}
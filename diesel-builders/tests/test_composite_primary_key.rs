@@ -21,6 +21,35 @@ pub struct UserRole {
     assigned_at: String,
 }
 
+#[derive(Debug, Queryable, Clone, Selectable, PartialEq, TableModel)]
+#[diesel(table_name = role_grants)]
+#[table_model(primary_key(user_id, role_id))]
+/// A role grant model declaring its composite key through `table_model`.
+pub struct RoleGrant {
+    /// The ID of the user.
+    user_id: i32,
+    /// The ID of the role.
+    role_id: i32,
+    /// Who granted the role.
+    granted_by: String,
+}
+
+#[derive(Debug, Queryable, Clone, Selectable, Identifiable, PartialEq, TableModel)]
+#[diesel(table_name = role_audits)]
+#[table_model(surrogate_key)]
+#[table_model(foreign_key((user_id, role_id), (user_roles::user_id, user_roles::role_id)))]
+/// An audit entry referencing a user role through a composite foreign key.
+pub struct RoleAudit {
+    /// The ID of the audit entry.
+    id: i32,
+    /// The ID of the audited user.
+    user_id: i32,
+    /// The ID of the audited role.
+    role_id: i32,
+    /// The audit note.
+    note: String,
+}
+
 #[test]
 fn test_composite_primary_key_table() -> Result<(), Box<dyn std::error::Error>> {
     let mut conn = shared::establish_connection()?;
@@ -253,3 +282,117 @@ fn test_upsert_composite() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_upsert_report_composite() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::UpsertOutcome;
+
+    let mut conn = shared::establish_connection()?;
+
+    diesel::sql_query(
+        "CREATE TABLE user_roles (
+			user_id INTEGER NOT NULL,
+			role_id INTEGER NOT NULL,
+			assigned_at TEXT NOT NULL,
+			PRIMARY KEY (user_id, role_id)
+		)",
+    )
+    .execute(&mut conn)?;
+
+    let new_role = UserRole { user_id: 1, role_id: 10, assigned_at: "2025-01-01".to_string() };
+    let (inserted_role, outcome) = new_role.upsert_report(&mut conn)?;
+    assert_eq!(outcome, UpsertOutcome::Inserted);
+    assert_eq!(inserted_role, new_role);
+
+    let changed_role = UserRole { assigned_at: "2025-01-02".to_string(), ..new_role };
+    let (updated_role, outcome) = changed_role.upsert_report(&mut conn)?;
+    assert_eq!(outcome, UpsertOutcome::Updated);
+    assert_eq!(updated_role, changed_role);
+
+    let queried_role: UserRole = UserRole::find(updated_role.id(), &mut conn)?;
+    assert_eq!(queried_role.assigned_at, "2025-01-02");
+
+    Ok(())
+}
+
+#[test]
+fn test_table_model_primary_key_alias() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+
+    diesel::sql_query(
+        "CREATE TABLE role_grants (
+			user_id INTEGER NOT NULL,
+			role_id INTEGER NOT NULL,
+			granted_by TEXT NOT NULL,
+			PRIMARY KEY (user_id, role_id)
+		)",
+    )
+    .execute(&mut conn)?;
+
+    let grant = role_grants::table::builder()
+        .user_id(1)
+        .role_id(10)
+        .granted_by("admin")
+        .insert(&mut conn)?;
+    role_grants::table::builder().user_id(1).role_id(20).granted_by("admin").insert(&mut conn)?;
+
+    // The generated table uses both columns as its primary key.
+    let queried_grant: RoleGrant = role_grants::table.find((1, 10)).first(&mut conn)?;
+    assert_eq!(queried_grant, grant);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_foreign_composite_foreign_key() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+
+    diesel::sql_query(
+        "CREATE TABLE user_roles (
+			user_id INTEGER NOT NULL,
+			role_id INTEGER NOT NULL,
+			assigned_at TEXT NOT NULL,
+			PRIMARY KEY (user_id, role_id)
+		)",
+    )
+    .execute(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TABLE role_audits (
+			id INTEGER PRIMARY KEY NOT NULL,
+			user_id INTEGER NOT NULL,
+			role_id INTEGER NOT NULL,
+			note TEXT NOT NULL,
+			FOREIGN KEY (user_id, role_id) REFERENCES user_roles(user_id, role_id)
+		)",
+    )
+    .execute(&mut conn)?;
+
+    // Roles sharing either part of the key must not match.
+    let user_role = user_roles::table::builder()
+        .user_id(1)
+        .role_id(10)
+        .assigned_at("2025-01-01")
+        .insert(&mut conn)?;
+    user_roles::table::builder()
+        .user_id(1)
+        .role_id(20)
+        .assigned_at("2025-01-02")
+        .insert(&mut conn)?;
+    user_roles::table::builder()
+        .user_id(2)
+        .role_id(10)
+        .assigned_at("2025-01-03")
+        .insert(&mut conn)?;
+
+    let audit =
+        role_audits::table::builder().user_id(1).role_id(10).note("Granted").insert(&mut conn)?;
+
+    let referenced: UserRole = audit
+        .foreign::<(role_audits::user_id, role_audits::role_id), (
+            user_roles::user_id,
+            user_roles::role_id,
+        )>(&mut conn)?;
+    assert_eq!(referenced, user_role);
+
+    Ok(())
+}
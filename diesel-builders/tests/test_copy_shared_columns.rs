@@ -0,0 +1,80 @@
+//! Test for copying the columns a builder shares by name with a model of
+//! another table.
+
+mod shared;
+use diesel_builders::{CopySharedColumns, prelude::*};
+
+/// Product staged for review before being published.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = staged_products)]
+#[table_model(surrogate_key)]
+pub struct StagedProduct {
+    /// Primary key.
+    id: i32,
+    /// Name of the product.
+    name: String,
+    /// Price of the product, in cents.
+    price: i32,
+    /// Optional notes about the product.
+    notes: Option<String>,
+    /// Supplier code, stored as text while staged.
+    code: String,
+    /// Import batch the product was staged in.
+    batch: i32,
+}
+
+/// Published product.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = products)]
+#[table_model(surrogate_key)]
+pub struct Product {
+    /// Primary key.
+    id: i32,
+    /// Name of the product.
+    name: String,
+    /// Price of the product, in cents.
+    price: i32,
+    /// Optional notes about the product.
+    notes: Option<String>,
+    /// Numeric supplier code.
+    code: i32,
+    /// Units in stock.
+    stock: i32,
+}
+
+#[test]
+fn test_copy_shared_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE staged_products (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, price INTEGER NOT NULL, notes TEXT, code TEXT NOT NULL, batch INTEGER NOT NULL)",
+    )
+    .execute(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TABLE products (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, price INTEGER NOT NULL, notes TEXT, code INTEGER NOT NULL, stock INTEGER NOT NULL)",
+    )
+    .execute(&mut conn)?;
+
+    let staged = staged_products::table::builder()
+        .name("Lamp")
+        .price(1999)
+        .notes(Some("Fragile".to_owned()))
+        .code("0042")
+        .batch(7)
+        .insert(&mut conn)?;
+
+    let mut builder = products::table::builder();
+    let copied = builder.copy_shared_columns_ref(&staged)?;
+
+    // The surrogate key is not settable, and `code` has a different type in
+    // each table, so neither is copied.
+    assert_eq!(copied, ["name", "price", "notes"]);
+    assert_eq!(builder.may_get_column_ref::<products::code>(), None);
+
+    let product = builder.code(42).stock(3).insert(&mut conn)?;
+    assert_eq!(product.name(), "Lamp");
+    assert_eq!(product.price(), &1999);
+    assert_eq!(product.notes(), &Some("Fragile".to_owned()));
+    assert_eq!(product.stock(), &3);
+
+    Ok(())
+}
@@ -418,3 +418,18 @@ fn test_load_nested_many_variants_dag() -> Result<(), Box<dyn std::error::Error>
 
     Ok(())
 }
+
+#[test]
+fn test_insert_levels_dag() {
+    assert_eq!(<animals::table as Descendant>::INSERT_LEVEL, 0);
+    assert_eq!(<dogs::table as Descendant>::INSERT_LEVEL, 1);
+    assert_eq!(<cats::table as Descendant>::INSERT_LEVEL, 1);
+    assert_eq!(<pets::table as Descendant>::INSERT_LEVEL, 2);
+
+    // The two branches of the diamond are independent, so they share a level.
+    assert_eq!(
+        TableBuilder::<pets::table>::insert_levels(),
+        vec![vec!["animals"], vec!["dogs", "cats"], vec!["pets"]]
+    );
+    assert_eq!(TableBuilder::<animals::table>::insert_levels(), vec![vec!["animals"]]);
+}
@@ -101,3 +101,94 @@ fn test_defaults() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_apply_defaults() -> Result<(), Box<dyn std::error::Error>> {
+    let builder = users::table::builder().name("Alice");
+
+    // A `Some(None)` default serializes to `null`, which deserializes back as
+    // unset; the role is cleared from the serialized insertable model, laid
+    // out as `[name, [role, [active, [bio, [email]]]]]`.
+    let mut serialized = serde_json::to_value(&builder)?;
+    serialized[0]["insertable_model"][1][0] = serde_json::Value::Null;
+    let mut builder: diesel_builders::TableBuilder<users::table> =
+        serde_json::from_value(serialized)?;
+    assert_eq!(builder.may_get_column::<users::role>(), None);
+    assert_eq!(builder.may_get_column::<users::bio>(), None);
+
+    builder.apply_defaults();
+
+    assert_eq!(builder.may_get_column::<users::role>(), Some("User".to_string()));
+    assert_eq!(builder.may_get_column::<users::bio>(), Some(None));
+    // Columns already set are untouched, and columns without a default stay
+    // unset.
+    assert_eq!(builder.may_get_column::<users::name>(), Some("Alice".to_string()));
+    assert_eq!(builder.may_get_column::<users::active>(), Some(true));
+    assert_eq!(builder.may_get_column::<users::email>(), None);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_insert_reports_every_missing_column() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+
+    // The role is cleared from the serialized insertable model, as in
+    // `test_apply_defaults`, and the email has no default.
+    let mut serialized = serde_json::to_value(users::table::builder())?;
+    serialized[0]["insertable_model"][1][0] = serde_json::Value::Null;
+    let builder: diesel_builders::TableBuilder<users::table> = serde_json::from_value(serialized)?;
+
+    let error = match builder.insert(&mut conn) {
+        Err(diesel_builders::BuilderError::Incomplete(error)) => error,
+        other => return Err(format!("Expected an Incomplete error, got: {other:?}").into()),
+    };
+    assert_eq!(error.to_string(), "Missing mandatory fields of `users`: `role`, `email`");
+    assert_eq!(error.into_iter().collect::<Vec<_>>(), vec!["role", "email"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_try_set_nested_columns_counted() -> Result<(), Box<dyn std::error::Error>> {
+    use std::convert::Infallible;
+
+    use diesel_builders::TrySetNestedColumnsCounted;
+
+    let mut builder = users::table::builder();
+
+    // `name` already holds its default value, `role` changes and `email` was
+    // unset.
+    let changed = TrySetNestedColumnsCounted::<
+        Infallible,
+        (users::name, (users::role, (users::email,))),
+    >::try_set_nested_columns_counted(
+        &mut builder,
+        ("Guest".to_owned(), ("Admin".to_owned(), ("guest@example.com".to_owned(),))),
+    )?;
+    assert_eq!(changed, 2);
+    assert_eq!(builder.may_get_column_ref::<users::role>().map(String::as_str), Some("Admin"));
+
+    Ok(())
+}
+
+#[test]
+fn test_clear_all() {
+    let mut builder = users::table::builder()
+        .name("Alice")
+        .role("Admin")
+        .active(false)
+        .bio(Some("Hello".to_owned()))
+        .email("alice@example.com");
+
+    builder.clear_all();
+
+    assert_eq!(builder, users::table::builder());
+    assert_eq!(builder.may_get_column::<users::name>(), Some("Guest".to_string()));
+    assert_eq!(builder.may_get_column::<users::role>(), Some("User".to_string()));
+    assert_eq!(builder.may_get_column::<users::active>(), Some(true));
+    assert_eq!(builder.may_get_column::<users::bio>(), Some(None));
+    assert_eq!(builder.may_get_column::<users::email>(), None);
+}
@@ -325,3 +325,88 @@ fn test_discretionary_triangular_relation_simple() -> Result<(), Box<dyn std::er
 
     Ok(())
 }
+
+#[test]
+fn test_set_shared_key_from_model() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_triangular_tables(&mut conn)?;
+
+    let parent = parent_table::table::builder().parent_field("Tenant").insert(&mut conn)?;
+    let satellite = satellite_table::table::builder()
+        .parent_id(parent.get_column::<parent_table::id>())
+        .field("Shared field")
+        .another_field("Shared another field".to_owned())
+        .insert(&mut conn)?;
+
+    // The satellite is already loaded, so its shared values are propagated
+    // without providing a builder for it.
+    let builder =
+        child_with_satellite_table::table::builder()
+            .set_shared_key_from_model::<child_with_satellite_table::discretionary_id>(&satellite);
+
+    assert_eq!(
+        builder.may_get_column::<child_with_satellite_table::id>(),
+        Some(parent.get_column::<parent_table::id>())
+    );
+    assert_eq!(
+        builder.may_get_column::<child_with_satellite_table::remote_field>(),
+        Some(Some("Shared field".to_owned()))
+    );
+    assert_eq!(
+        builder.may_get_column::<child_with_satellite_table::another_remote_column>(),
+        Some(Some("Shared another field".to_owned()))
+    );
+    // The foreign key itself is left untouched.
+    assert_eq!(builder.may_get_column::<child_with_satellite_table::discretionary_id>(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_try_set_discretionary_model_verified() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{BuilderError, ForeignModelMissing};
+    type Key = simple_child_with_satellite_table::discretionary_id;
+
+    let mut conn = shared::establish_connection()?;
+    setup_triangular_tables(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TABLE simple_child_with_satellite_table (
+            id INTEGER PRIMARY KEY NOT NULL REFERENCES parent_table(id),
+            discretionary_id INTEGER NOT NULL REFERENCES satellite_table(id),
+            FOREIGN KEY (discretionary_id, id) REFERENCES satellite_table(id, parent_id)
+        )",
+    )
+    .execute(&mut conn)?;
+
+    let parent = parent_table::table::builder().parent_field("Value A").insert(&mut conn)?;
+    let satellite = satellite_table::table::builder()
+        .parent_id(parent.get_column::<parent_table::id>())
+        .field("Value C")
+        .insert(&mut conn)?;
+
+    // A persisted model is linked as by `try_set_discretionary_model`.
+    let builder = simple_child_with_satellite_table::table::builder()
+        .parent_field("Value A for B")
+        .try_set_discretionary_model_verified::<Key, _>(&satellite, &mut conn)?;
+    assert_eq!(
+        builder.may_get_column::<Key>(),
+        Some(satellite.get_column::<satellite_table::id>())
+    );
+
+    // Once deleted, the model is reported as missing before any insertion.
+    diesel::delete(satellite_table::table.find(satellite.get_column::<satellite_table::id>()))
+        .execute(&mut conn)?;
+    let result = simple_child_with_satellite_table::table::builder()
+        .parent_field("Value A for B")
+        .try_set_discretionary_model_verified::<Key, _>(&satellite, &mut conn);
+    assert!(matches!(
+        result,
+        Err(BuilderError::ForeignModelMissing(ForeignModelMissing {
+            table_name: "simple_child_with_satellite_table",
+            field_name: "discretionary_id",
+            referenced_table_name: "satellite_table",
+        }))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! Test matching a text column regardless of case with
+//! `LoadManyWhereIlike::find_by_iexact` on Postgres.
+//!
+//! The test requires the `postgres` feature and a `DATABASE_URL` environment
+//! variable pointing to a Postgres database in which it may create and drop
+//! the `named_colors` table.
+#![cfg(feature = "postgres")]
+
+use diesel::{PgConnection, pg::Pg};
+use diesel_builders::{load_query_builder::CaseInsensitiveEq, prelude::*};
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = named_colors)]
+#[table_model(surrogate_key)]
+/// Model for the named colors table.
+pub struct NamedColor {
+    /// Primary key.
+    id: i32,
+    /// Name of the color.
+    name: String,
+}
+
+fn establish_connection() -> Result<PgConnection, Box<dyn std::error::Error>> {
+    let database_url = std::env::var("DATABASE_URL")?;
+    Ok(PgConnection::establish(&database_url)?)
+}
+
+#[test]
+fn test_find_by_iexact_uses_ilike() -> Result<(), Box<dyn std::error::Error>> {
+    let query = named_colors::table.filter(CaseInsensitiveEq::new(named_colors::name, "50%_off"));
+    let sql = diesel::debug_query::<Pg, _>(&query).to_string();
+    assert!(sql.contains(r#""named_colors"."name" ILIKE $1"#), "{sql}");
+    assert!(sql.contains(r#"binds: ["50\\%\\_off"]"#), "{sql}");
+
+    let mut conn = establish_connection()?;
+    diesel::sql_query("DROP TABLE IF EXISTS named_colors").execute(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TABLE named_colors (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    let red = named_colors::table::builder().name("Red").insert(&mut conn)?;
+    named_colors::table::builder().name("Reddish").insert(&mut conn)?;
+
+    assert_eq!(named_colors::name::find_by_iexact("red", &mut conn)?, vec![red]);
+    // Pattern characters are matched literally.
+    assert!(named_colors::name::find_by_iexact("red%", &mut conn)?.is_empty());
+    assert!(named_colors::name::find_by_iexact("r_d", &mut conn)?.is_empty());
+
+    diesel::sql_query("DROP TABLE named_colors").execute(&mut conn)?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Test locking a row with `ModelFind::find_for_update` on Postgres.
+//!
+//! The test requires the `postgres` feature and a `DATABASE_URL` environment
+//! variable pointing to a Postgres database in which it may create and drop
+//! the `locked_counters` table.
+#![cfg(feature = "postgres")]
+
+use diesel::PgConnection;
+use diesel_builders::prelude::*;
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = locked_counters)]
+#[table_model(surrogate_key)]
+/// Model for the locked counters table.
+pub struct LockedCounter {
+    /// Primary key.
+    id: i32,
+    /// Value of the counter.
+    value: i32,
+}
+
+fn establish_connection() -> Result<PgConnection, Box<dyn std::error::Error>> {
+    let database_url = std::env::var("DATABASE_URL")?;
+    Ok(PgConnection::establish(&database_url)?)
+}
+
+#[test]
+fn test_find_for_update_blocks_concurrent_update() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = establish_connection()?;
+    diesel::sql_query("DROP TABLE IF EXISTS locked_counters").execute(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TABLE locked_counters (
+            id SERIAL PRIMARY KEY,
+            value INTEGER NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    let counter = locked_counters::table::builder().value(0).insert(&mut conn)?;
+    let id = *counter.id();
+
+    // A second session giving up on locks it cannot acquire quickly.
+    let mut other = establish_connection()?;
+    diesel::sql_query("SET lock_timeout = '100ms'").execute(&mut other)?;
+
+    conn.transaction(|conn| {
+        let locked = LockedCounter::find_for_update(&id, conn)?;
+        assert_eq!(locked, counter);
+
+        // The row is locked by the transaction, so the update times out.
+        let result = diesel::update(locked_counters::table.find(id))
+            .set(locked_counters::value.eq(1))
+            .execute(&mut other);
+        assert!(result.is_err());
+
+        diesel::QueryResult::Ok(())
+    })?;
+
+    // Once the transaction commits, the lock is released.
+    diesel::update(locked_counters::table.find(id))
+        .set(locked_counters::value.eq(1))
+        .execute(&mut other)?;
+    assert_eq!(*LockedCounter::find(&id, &mut conn)?.value(), 1);
+
+    diesel::sql_query("DROP TABLE locked_counters").execute(&mut conn)?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Test case for `GetForeignChain`, following two consecutive foreign keys
+//! from a city to the continent of its country.
+
+mod shared;
+use diesel_builders::prelude::*;
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = continents)]
+#[table_model(surrogate_key)]
+/// Model for the continents table.
+pub struct Continent {
+    /// Primary key.
+    id: i32,
+    /// Name of the continent.
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = countries)]
+#[table_model(surrogate_key)]
+#[table_model(foreign_key(continent_id, (continents::id)))]
+/// Model for the countries table.
+pub struct Country {
+    /// Primary key.
+    id: i32,
+    /// Name of the country.
+    name: String,
+    /// Continent of the country.
+    continent_id: i32,
+}
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = cities)]
+#[table_model(surrogate_key)]
+#[table_model(foreign_key(country_id, (countries::id)))]
+/// Model for the cities table.
+pub struct City {
+    /// Primary key.
+    id: i32,
+    /// Name of the city.
+    name: String,
+    /// Country of the city.
+    country_id: i32,
+}
+
+fn create_tables(conn: &mut SqliteConnection) -> Result<(), Box<dyn std::error::Error>> {
+    diesel::sql_query(
+        "CREATE TABLE continents (
+            id INTEGER PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL
+        )",
+    )
+    .execute(conn)?;
+    diesel::sql_query(
+        "CREATE TABLE countries (
+            id INTEGER PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            continent_id INTEGER NOT NULL REFERENCES continents(id)
+        )",
+    )
+    .execute(conn)?;
+    diesel::sql_query(
+        "CREATE TABLE cities (
+            id INTEGER PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            country_id INTEGER NOT NULL REFERENCES countries(id)
+        )",
+    )
+    .execute(conn)?;
+    Ok(())
+}
+
+#[test]
+fn test_get_foreign_2() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    let _other = continents::table::builder().name("Asia").insert(&mut conn)?;
+    let europe = continents::table::builder().name("Europe").insert(&mut conn)?;
+    let switzerland = countries::table::builder()
+        .name("Switzerland")
+        .continent_id(*europe.id())
+        .insert(&mut conn)?;
+    let zurich =
+        cities::table::builder().name("Zurich").country_id(*switzerland.id()).insert(&mut conn)?;
+
+    let continent: Continent =
+        zurich.get_foreign_2::<cities::country_id, countries::continent_id>(&mut conn)?;
+    assert_eq!(continent, europe);
+
+    // A dangling first hop is reported as not found.
+    let dangling = City { id: 0, name: "Atlantis".to_owned(), country_id: 42 };
+    let result = dangling.get_foreign_2::<cities::country_id, countries::continent_id>(&mut conn);
+    assert!(matches!(result, Err(diesel::result::Error::NotFound)));
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Test case for horizontal same-as relationships through a foreign key
+//! referencing a unique column which is not the primary key, where cities
+//! reference their country by code and share its currency.
+
+mod shared;
+use diesel_builders::prelude::*;
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = countries)]
+#[table_model(surrogate_key)]
+/// Model for the countries table.
+pub struct Country {
+    /// Primary key.
+    id: i32,
+    /// Unique code of the country.
+    code: String,
+    /// Currency of the country.
+    currency: String,
+}
+
+unique_index!(countries::code);
+
+#[derive(Debug, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = cities)]
+#[table_model(surrogate_key)]
+#[table_model(foreign_key(country_code, (countries::code)))]
+/// Model for the cities table.
+pub struct City {
+    /// Primary key.
+    id: i32,
+    /// Name of the city.
+    name: String,
+    /// Code of the country of the city.
+    country_code: String,
+    /// Currency of the city, the same as the currency of its country.
+    currency: String,
+}
+
+impl HorizontalUniqueKey for cities::country_code {
+    type ReferencedColumn = countries::code;
+    type HostColumns = (cities::currency,);
+    type ForeignColumns = (countries::currency,);
+}
+
+fn create_tables(conn: &mut SqliteConnection) -> Result<(), Box<dyn std::error::Error>> {
+    diesel::sql_query(
+        "CREATE TABLE countries (
+            id INTEGER PRIMARY KEY NOT NULL,
+            code TEXT NOT NULL UNIQUE,
+            currency TEXT NOT NULL
+        )",
+    )
+    .execute(conn)?;
+    diesel::sql_query(
+        "CREATE TABLE cities (
+            id INTEGER PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            country_code TEXT NOT NULL REFERENCES countries(code),
+            currency TEXT NOT NULL
+        )",
+    )
+    .execute(conn)?;
+    Ok(())
+}
+
+#[test]
+fn test_set_unique_shared_key_from_model() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    let _other = countries::table::builder().code("FR").currency("EUR").insert(&mut conn)?;
+    let country = countries::table::builder().code("CH").currency("CHF").insert(&mut conn)?;
+
+    let builder = cities::table::builder()
+        .name("Zurich")
+        .set_unique_shared_key_from_model::<cities::country_code>(&country);
+
+    // The key holds the referenced unique column rather than the primary key.
+    assert_eq!(builder.may_get_column::<cities::country_code>(), Some("CH".to_owned()));
+    assert_eq!(builder.may_get_column::<cities::currency>(), Some("CHF".to_owned()));
+
+    let city = builder.insert(&mut conn)?;
+    assert_eq!(city.country_code(), country.code());
+    assert_eq!(city.currency(), country.currency());
+
+    let loaded_country: Country =
+        <(countries::code,)>::load_first((city.country_code().clone(),), &mut conn)?;
+    assert_eq!(loaded_country, country);
+
+    Ok(())
+}
@@ -8,6 +8,20 @@ use diesel::prelude::*;
 use diesel_builders::prelude::*;
 use shared_animals::*;
 
+/// Model for the walks of the dogs.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = walks)]
+#[table_model(surrogate_key)]
+#[table_model(foreign_key(dog_id, (dogs::id)))]
+pub struct Walk {
+    /// Primary key.
+    id: i32,
+    /// The walked dog.
+    dog_id: i32,
+    /// Length of the walk in minutes.
+    minutes: i32,
+}
+
 #[test]
 fn test_dog_inheritance() -> Result<(), Box<dyn std::error::Error>> {
     let mut conn = shared::establish_connection()?;
@@ -196,3 +210,283 @@ fn test_get_model_ext_inheritance() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_builder_diff_inheritance() -> Result<(), Box<dyn std::error::Error>> {
+    let template = dogs::table::builder().try_name("Max")?.breed("Labrador");
+    assert!(template.diff(&template.clone()).is_empty());
+
+    // The name is set only in the template, while the breed differs in value.
+    let applied = dogs::table::builder().breed("Poodle");
+    assert_eq!(template.diff(&applied), vec!["name", "breed"]);
+    assert_eq!(applied.diff(&template), vec!["name", "breed"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_model_is_descendant() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    let dog = dogs::table::builder().try_name("Rex")?.breed("Labrador").insert(&mut conn)?;
+    let animal: Animal = dog.ancestor(&mut conn)?;
+    assert!(animal.is::<dogs::table>(&mut conn)?);
+    assert!(!animal.is::<cats::table>(&mut conn)?);
+
+    // An animal without any descendant record is neither.
+    let generic = animals::table::builder().try_name("Generic")?.insert(&mut conn)?;
+    assert!(!generic.is::<dogs::table>(&mut conn)?);
+    assert!(!generic.is::<cats::table>(&mut conn)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_table_is_a() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    // Only the primary key is needed, not a model of an ancestor table.
+    let dog = dogs::table::builder().try_name("Rex")?.breed("Labrador").insert(&mut conn)?;
+    let dog_id = dog.get_column::<dogs::id>();
+    assert!(dogs::table::is_a((dog_id,), &mut conn)?);
+    assert!(!cats::table::is_a((dog_id,), &mut conn)?);
+    assert!(!puppies::table::is_a((dog_id,), &mut conn)?);
+    assert!(!dogs::table::is_a((dog_id + 1,), &mut conn)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_load_grouped_count_dog_breeds() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    for (name, breed) in
+        [("Rex", "Labrador"), ("Max", "Poodle"), ("Buddy", "Labrador"), ("Rocky", "Labrador")]
+    {
+        dogs::table::builder().try_name(name)?.breed(breed).insert(&mut conn)?;
+    }
+
+    let mut counts = <dogs::breed as LoadGroupedCount<_>>::load_grouped_count(&mut conn)?;
+    counts.sort();
+    assert_eq!(counts, vec![("Labrador".to_owned(), 3), ("Poodle".to_owned(), 1)]);
+
+    // Only the breeds with more than one dog are returned.
+    let popular = <dogs::breed as LoadGroupedCount<_>>::load_grouped_count_having_gt(1, &mut conn)?;
+    assert_eq!(popular, vec![("Labrador".to_owned(), 3)]);
+    let none = <dogs::breed as LoadGroupedCount<_>>::load_grouped_count_having_gt(3, &mut conn)?;
+    assert!(none.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_load_distinct_dog_breeds() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    for (name, breed) in
+        [("Rex", "Labrador"), ("Max", "Poodle"), ("Buddy", "Labrador"), ("Rocky", "Labrador")]
+    {
+        dogs::table::builder().try_name(name)?.breed(breed).insert(&mut conn)?;
+    }
+
+    let mut breeds = <(dogs::breed,) as LoadManyDistinct<_>>::load_distinct(&mut conn)?;
+    breeds.sort();
+    assert_eq!(breeds, vec![("Labrador".to_owned(),), ("Poodle".to_owned(),)]);
+
+    Ok(())
+}
+
+// The tables compared at the same depth by `CommonAncestor` which are not
+// ancestors of one another, as a schema generated by the Diesel CLI would
+// declare them.
+allow_tables_to_appear_in_same_query!(dogs, cats);
+allow_tables_to_appear_in_same_query!(puppies, cats);
+
+/// Asserts at compile time that the nearest common ancestor of `A` and `B`
+/// is `Ancestor`.
+fn assert_common_ancestor<A, B, Ancestor>()
+where
+    A: diesel_builders::CommonAncestor<B, Ancestor = Ancestor>,
+    B: diesel_builders::DescendantWithSelf,
+{
+}
+
+#[test]
+fn test_common_ancestor() {
+    assert_common_ancestor::<dogs::table, cats::table, animals::table>();
+    assert_common_ancestor::<cats::table, dogs::table, animals::table>();
+    assert_common_ancestor::<puppies::table, dogs::table, dogs::table>();
+    assert_common_ancestor::<animals::table, puppies::table, animals::table>();
+    assert_common_ancestor::<puppies::table, pets::table, dogs::table>();
+    assert_common_ancestor::<dogs::table, dogs::table, dogs::table>();
+}
+
+#[test]
+#[cfg(feature = "pretty-debug")]
+fn test_pretty_debug_builders() -> Result<(), Box<dyn std::error::Error>> {
+    let animal = animals::table::builder().try_name("Buddy")?;
+    assert_eq!(format!("{animal:?}"), r#"animals { name: "Buddy", description: None }"#);
+    assert_eq!(
+        format!("{:?}", animals::table::builder()),
+        "animals { name: <unset>, description: None }"
+    );
+
+    // Descendant builders list the ancestor columns as well.
+    let dog = dogs::table::builder().try_name("Rex")?;
+    let debug = format!("{dog:?}");
+    assert!(debug.starts_with(r#"dogs { name: "Rex", description: None, "#));
+    assert!(debug.contains("breed: <unset>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_with_ancestor() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    // The closure receives the animal part of the dog builder, including the
+    // dog-specific default description.
+    let dog = dogs::table::builder()
+        .try_with_ancestor(|animal: TableBuilder<animals::table>| animal.try_name("Rex"))?
+        .breed("Beagle")
+        .insert(&mut conn)?;
+    let animal: Animal = dog.ancestor(&mut conn)?;
+    assert_eq!(animal.name(), "Rex");
+    assert_eq!(animal.description().as_deref(), Some("A generic dog"));
+    assert_eq!(dog.breed(), "Beagle");
+
+    let replacement = animals::table::builder().try_name("Fido")?;
+    let builder = dogs::table::builder()
+        .breed("Poodle")
+        .with_ancestor(|_: TableBuilder<animals::table>| replacement);
+    assert_eq!(builder.may_get_column_ref::<animals::name>().map(String::as_str), Some("Fido"));
+    assert_eq!(builder.may_get_column_ref::<dogs::breed>().map(String::as_str), Some("Poodle"));
+
+    // Errors of the closure are propagated.
+    assert!(matches!(
+        dogs::table::builder()
+            .try_with_ancestor(|animal: TableBuilder<animals::table>| animal.try_name("")),
+        Err(NewAnimalError::NameEmpty)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_load_by_foreign() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+    diesel::sql_query(
+        "CREATE TABLE walks (id INTEGER PRIMARY KEY NOT NULL, dog_id INTEGER NOT NULL REFERENCES dogs(id), minutes INTEGER NOT NULL)",
+    )
+    .execute(&mut conn)?;
+
+    let max = dogs::table::builder().try_name("Max")?.breed("Beagle").insert(&mut conn)?;
+    let rex = dogs::table::builder().try_name("Rex")?.breed("Boxer").insert(&mut conn)?;
+    let first = walks::table::builder().dog_id(*max.id()).minutes(30).insert(&mut conn)?;
+    let second = walks::table::builder().dog_id(*max.id()).minutes(45).insert(&mut conn)?;
+    walks::table::builder().dog_id(*rex.id()).minutes(10).insert(&mut conn)?;
+
+    // The primary key of a dog is the one of its animal record.
+    let mut walks = walks::dog_id::load_by_foreign(*max.id(), &mut conn)?;
+    walks.sort_by_key(|walk| walk.id);
+    assert_eq!(walks, vec![first, second]);
+
+    let unknown = animals::table::builder().try_name("Tom")?.insert(&mut conn)?;
+    assert!(walks::dog_id::load_by_foreign(*unknown.id(), &mut conn)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_insert_with_id() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let animal = animals::table::builder().try_name("Migrated")?.insert_with_id(42, &mut conn)?;
+    assert_eq!(animal.id(), &42);
+    assert_eq!(Animal::find(&42, &mut conn)?, animal);
+
+    // The provided primary key is propagated to the descendant tables.
+    let dog = dogs::table::builder()
+        .try_name("Migrated Dog")?
+        .breed("Beagle")
+        .insert_with_id(7, &mut conn)?;
+    assert_eq!(dog.id(), &7);
+    assert_eq!(Dog::find(&7, &mut conn)?, dog);
+    assert_eq!(Animal::find(&7, &mut conn)?.name(), "Migrated Dog");
+
+    // Reusing a primary key violates the primary key constraint.
+    assert!(
+        animals::table::builder().try_name("Duplicate")?.insert_with_id(42, &mut conn).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_load_with_ancestor() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    let dog = dogs::table::builder().try_name("Rex")?.breed("Labrador").insert(&mut conn)?;
+    let _other = dogs::table::builder().try_name("Max")?.breed("Beagle").insert(&mut conn)?;
+    let dog_id = dog.get_column::<dogs::id>();
+
+    let (loaded_dog, animal) =
+        <dogs::table as LoadJoined<animals::table, _>>::load_with_ancestor(dog_id, &mut conn)?;
+    assert_eq!(loaded_dog, dog);
+    assert_eq!(animal.get_column::<animals::id>(), dog_id);
+    assert_eq!(animal.name(), "Rex");
+
+    // An animal which is not a dog has no record to join with.
+    let generic = animals::table::builder().try_name("Generic")?.insert(&mut conn)?;
+    let result = <dogs::table as LoadJoined<animals::table, _>>::load_with_ancestor(
+        generic.get_column::<animals::id>(),
+        &mut conn,
+    );
+    assert_eq!(result, Err(diesel::result::Error::NotFound));
+
+    Ok(())
+}
+
+#[test]
+fn test_count_children() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    shared_animals::setup_animal_tables(&mut conn)?;
+
+    let dog = dogs::table::builder().try_name("Rex")?.breed("Labrador").insert(&mut conn)?;
+    let animal: Animal = dog.ancestor(&mut conn)?;
+    assert_eq!(animal.count_children::<dogs::table>(&mut conn)?, 1);
+    assert_eq!(animal.count_children::<cats::table>(&mut conn)?, 0);
+
+    // An animal without any descendant record has no children.
+    let generic = animals::table::builder().try_name("Generic")?.insert(&mut conn)?;
+    assert_eq!(generic.count_children::<dogs::table>(&mut conn)?, 0);
+    assert_eq!(generic.count_children::<cats::table>(&mut conn)?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_completed_builder_ancestor_columns() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::table_builder::RecursiveTableBuilder;
+
+    let builder = dogs::table::builder().try_name("Max")?.breed("Golden Retriever");
+    let completed = RecursiveTableBuilder::<
+        dogs::table,
+        typenum::U0,
+        <dogs::table as BuildableTable>::NestedCompletedAncestorBuilders,
+    >::try_from(builder)?;
+
+    // The columns of the ancestors are read from their completed bundles.
+    assert_eq!(completed.may_get_column_ref::<animals::name>().map(String::as_str), Some("Max"));
+    assert_eq!(completed.may_get_column::<dogs::breed>(), Some("Golden Retriever".to_owned()));
+
+    Ok(())
+}
@@ -210,3 +210,140 @@ fn test_load_nested_traits_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_bind_form_inheritance_chain() -> Result<(), Box<dyn std::error::Error>> {
+    use std::collections::HashMap;
+
+    use diesel_builders::{bind_form, builder_error::DynamicColumnError};
+
+    let mut builder = puppies::table::builder();
+    let form: HashMap<String, String> = [("name", "Rex"), ("breed", "Beagle"), ("age_months", "3")]
+        .into_iter()
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .collect();
+    bind_form(&mut builder, &form)?;
+    assert_eq!(builder.may_get_column_ref::<animals::name>().map(String::as_str), Some("Rex"));
+    assert_eq!(builder.may_get_column_ref::<dogs::breed>().map(String::as_str), Some("Beagle"));
+    assert_eq!(builder.may_get_column::<puppies::age_months>(), Some(3));
+
+    // A valid field alongside an unparsable one leaves the builder unchanged.
+    let snapshot = builder.clone();
+    let form: HashMap<String, String> = [("name", "Fido"), ("age_months", "three")]
+        .into_iter()
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .collect();
+    let result = bind_form(&mut builder, &form);
+    assert!(matches!(
+        result,
+        Err(DynamicColumnError::Parse { table_name: "puppies", column_name: "age_months", .. })
+    ));
+    assert_eq!(builder, snapshot);
+
+    // Values failing validation are reported as such.
+    let form: HashMap<String, String> =
+        [("age_months".to_owned(), "-1".to_owned())].into_iter().collect();
+    let result = builder.try_bind_form_ref(&form);
+    assert!(matches!(result, Err(DynamicColumnError::Validation(_))));
+
+    // Keys matching no column are reported distinctly from parse failures.
+    let form: HashMap<String, String> =
+        [("color".to_owned(), "Brown".to_owned())].into_iter().collect();
+    let result = builder.try_bind_form_ref(&form);
+    assert!(matches!(result, Err(DynamicColumnError::UnknownField(key)) if key == "color"));
+
+    Ok(())
+}
+
+#[test]
+fn test_load_hierarchy() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{LoadHierarchyError, MissingHierarchyRecordError};
+
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let puppy = puppies::table::builder()
+        .try_name("Buddy")?
+        .breed("Labrador")
+        .try_age_months(3)?
+        .insert(&mut conn)?;
+    let puppy_id = puppy.get_column::<puppies::id>();
+
+    let (animal, (dog, (loaded_puppy,))) = puppies::table::load_hierarchy((puppy_id,), &mut conn)?;
+    assert_eq!(animal.get_column::<animals::id>(), puppy_id);
+    assert_eq!(animal.name(), "Buddy");
+    assert_eq!(dog.get_column::<dogs::id>(), puppy_id);
+    assert_eq!(dog.breed(), "Labrador");
+    assert_eq!(loaded_puppy, puppy);
+
+    // A dog which is not a puppy lacks the leaf record.
+    let dog =
+        dogs::table::builder().try_name("Max")?.breed("Golden Retriever").insert(&mut conn)?;
+    let result = puppies::table::load_hierarchy((dog.get_column::<dogs::id>(),), &mut conn);
+    assert!(matches!(
+        result,
+        Err(LoadHierarchyError::MissingRecord(MissingHierarchyRecordError {
+            table_name: "puppies"
+        }))
+    ));
+
+    // A missing root record is reported against the root table.
+    let result = puppies::table::load_hierarchy((puppy_id + 100,), &mut conn);
+    assert!(matches!(
+        result,
+        Err(LoadHierarchyError::MissingRecord(MissingHierarchyRecordError {
+            table_name: "animals"
+        }))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_hierarchy_depth() {
+    assert_eq!(<animals::table as Descendant>::HIERARCHY_DEPTH, 1);
+    assert_eq!(<dogs::table as Descendant>::HIERARCHY_DEPTH, 2);
+    assert_eq!(<puppies::table as Descendant>::HIERARCHY_DEPTH, 3);
+}
+
+#[test]
+fn test_reparent() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_animal_tables(&mut conn)?;
+
+    let puppy = puppies::table::builder()
+        .try_name("Buddy")?
+        .breed("Labrador")
+        .try_age_months(3)?
+        .insert(&mut conn)?;
+    let puppy_id = puppy.get_column::<puppies::id>();
+
+    // The new root must not be a dog already, as the dog record of the puppy
+    // moves alongside it.
+    let dog =
+        dogs::table::builder().try_name("Max")?.breed("Golden Retriever").insert(&mut conn)?;
+    assert!(puppy.reparent(dog.get_column::<dogs::id>(), &mut conn).is_err());
+    assert!(Puppy::exists(&puppy_id, &mut conn)?);
+
+    // The foreign keys of the chain are not declared with `ON UPDATE CASCADE`,
+    // so each level is moved on its own.
+    let animal = animals::table::builder().try_name("Generic Animal")?.insert(&mut conn)?;
+    let animal_id = animal.get_column::<animals::id>();
+    assert!(!animal.is::<dogs::table>(&mut conn)?);
+    assert_eq!(puppy.reparent(animal_id, &mut conn)?, 2);
+    assert!(animal.is::<puppies::table>(&mut conn)?);
+
+    let (new_animal, (new_dog, (new_puppy,))) =
+        puppies::table::load_hierarchy((animal_id,), &mut conn)?;
+    assert_eq!(new_animal.name(), "Generic Animal");
+    assert_eq!(new_dog.breed(), "Labrador");
+    assert_eq!(new_puppy.age_months(), puppy.age_months());
+
+    // The previous root keeps only its animal record.
+    assert!(!Puppy::exists(&puppy_id, &mut conn)?);
+    assert!(!Dog::exists(&puppy_id, &mut conn)?);
+    let previous_animal: Animal = Animal::find(&puppy_id, &mut conn)?;
+    assert_eq!(previous_animal.name(), "Buddy");
+
+    Ok(())
+}
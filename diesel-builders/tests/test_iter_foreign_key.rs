@@ -193,3 +193,15 @@ fn test_iter_foreign_key_columns() {
     let mixed_full: Vec<_> = mixed_tuple.iter_match_full::<(nodes::id,)>().collect();
     assert_eq!(mixed_full.len(), 3);
 }
+
+#[test]
+fn test_join_across_explicit_foreign_key() {
+    // Compiles only because the derive emits
+    // `allow_tables_to_appear_in_same_query!` for the explicitly declared
+    // foreign keys.
+    let query = edges::table
+        .inner_join(nodes::table.on(edges::source_id.eq(nodes::id)))
+        .select((edges::id, nodes::name));
+    let sql = diesel::debug_query::<diesel::sqlite::Sqlite, _>(&query).to_string();
+    assert!(sql.contains("INNER JOIN"));
+}
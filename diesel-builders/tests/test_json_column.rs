@@ -0,0 +1,94 @@
+//! Test for columns stored as their JSON serialization.
+#![cfg(feature = "serde")]
+
+mod shared;
+use diesel::{
+    deserialize::FromSql,
+    serialize::{IsNull, Output, ToSql},
+    sql_types::Text,
+    sqlite::{Sqlite, SqliteValue},
+};
+use diesel_builders::{JsonColumn, ValueTyped, prelude::*};
+
+/// Preferences stored as a JSON document.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    diesel::expression::AsExpression,
+    diesel::deserialize::FromSqlRow,
+)]
+#[diesel(sql_type = Text)]
+pub struct Preferences {
+    /// The preferred theme.
+    pub theme: String,
+    /// The preferred font size.
+    pub font_size: i32,
+}
+
+impl ToSql<Text, Sqlite> for Preferences {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> diesel::serialize::Result {
+        out.set_value(<profiles::preferences as JsonColumn>::to_json_text(self)?);
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Sqlite> for Preferences {
+    fn from_sql(bytes: SqliteValue) -> diesel::deserialize::Result<Self> {
+        let text = <String as FromSql<Text, Sqlite>>::from_sql(bytes)?;
+        Ok(<profiles::preferences as JsonColumn>::from_json_text(&text)?)
+    }
+}
+
+/// Profile model.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = profiles)]
+#[table_model(surrogate_key)]
+pub struct Profile {
+    /// Primary key.
+    id: i32,
+    /// Preferences stored as JSON text.
+    #[table_model(json)]
+    preferences: Preferences,
+    /// Optional preferences stored as JSON text.
+    #[table_model(json)]
+    fallback_preferences: Option<Preferences>,
+}
+
+/// Asserts at compile time that the column's value type is `Preferences`.
+fn assert_preferences_column<C: JsonColumn + ValueTyped<ValueType = Preferences>>() {}
+
+#[test]
+fn test_json_column_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    assert_preferences_column::<profiles::preferences>();
+    assert_preferences_column::<profiles::fallback_preferences>();
+
+    let preferences = Preferences { theme: "dark".to_owned(), font_size: 14 };
+
+    let builder = profiles::table::builder().preferences(preferences.clone());
+    assert_eq!(builder.may_get_column_ref::<profiles::preferences>(), Some(&preferences));
+    assert_eq!(builder.may_get_column_ref::<profiles::fallback_preferences>(), Some(&None));
+
+    let serialized = serde_json::to_string(&builder)?;
+    let deserialized: diesel_builders::TableBuilder<profiles::table> =
+        serde_json::from_str(&serialized)?;
+    assert_eq!(deserialized.may_get_column_ref::<profiles::preferences>(), Some(&preferences));
+
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE profiles (id INTEGER PRIMARY KEY NOT NULL, preferences TEXT NOT NULL, fallback_preferences TEXT)",
+    )
+    .execute(&mut conn)?;
+
+    let profile = deserialized.insert(&mut conn)?;
+    assert_eq!(profile.preferences(), &preferences);
+    assert_eq!(profile.fallback_preferences(), &None);
+
+    let loaded: Profile =
+        profiles::table.find(profile.get_column::<profiles::id>()).first(&mut conn)?;
+    assert_eq!(loaded, profile);
+
+    Ok(())
+}
@@ -54,6 +54,27 @@ fn test_load_many() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_load_map() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    let item1 = items::table::builder().category(1).val(10).insert(&mut conn)?;
+    let _item2 = items::table::builder().category(2).val(20).insert(&mut conn)?;
+    let item3 = items::table::builder().category(1).val(30).insert(&mut conn)?;
+
+    let loaded_items = <(items::category,)>::load_map((1,), &mut conn)?;
+    assert_eq!(loaded_items.len(), 2);
+    assert_eq!(loaded_items.get(item3.id()), Some(&item3));
+
+    // The records are iterated in the order of their primary keys.
+    let ids: Vec<i32> = loaded_items.keys().copied().collect();
+    assert_eq!(ids, vec![*item1.id(), *item3.id()]);
+    assert_eq!(loaded_items.into_values().collect::<Vec<_>>(), vec![item1, item3]);
+
+    Ok(())
+}
+
 #[test]
 fn test_load_sorted() -> Result<(), Box<dyn std::error::Error>> {
     let mut conn = shared::establish_connection()?;
@@ -121,6 +142,54 @@ fn test_load_many_paginated() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_load_chunked() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    for val in 1..=10 {
+        items::table::builder().category(1).val(val).insert(&mut conn)?;
+    }
+    items::table::builder().category(2).val(100).insert(&mut conn)?;
+
+    let full: Vec<Item> = <(items::category,)>::load_sorted((1,), &mut conn)?;
+
+    let mut chunk_sizes = Vec::new();
+    let mut chunked_sum = 0;
+    let mut chunked = Vec::new();
+    <(items::category,)>::load_chunked(
+        (1,),
+        std::num::NonZeroUsize::new(3).ok_or("3 is not zero")?,
+        &mut conn,
+        |chunk| {
+            chunk_sizes.push(chunk.len());
+            chunked_sum += chunk.iter().map(Item::val).sum::<i32>();
+            chunked.extend(chunk);
+            Ok(())
+        },
+    )?;
+
+    assert_eq!(chunk_sizes, vec![3, 3, 3, 1]);
+    assert_eq!(chunked_sum, full.iter().map(Item::val).sum::<i32>());
+    assert_eq!(chunked, full);
+
+    // An error returned by the callback stops the loading.
+    let mut calls = 0;
+    let result = <(items::category,)>::load_chunked(
+        (1,),
+        std::num::NonZeroUsize::new(4).ok_or("4 is not zero")?,
+        &mut conn,
+        |_| {
+            calls += 1;
+            Err(diesel::result::Error::RollbackTransaction)
+        },
+    );
+    assert!(matches!(result, Err(diesel::result::Error::RollbackTransaction)));
+    assert_eq!(calls, 1);
+
+    Ok(())
+}
+
 #[test]
 fn test_load_nested_first() -> Result<(), Box<dyn std::error::Error>> {
     let mut conn = shared::establish_connection()?;
@@ -246,3 +315,165 @@ fn test_get_model_ext() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_map_same_type() {
+    use diesel_builders::columns::HomogeneouslyTypedNestedColumns;
+
+    let doubled =
+        <(items::category, (items::val,)) as HomogeneouslyTypedNestedColumns<i32>>::map_same_type(
+            (3, (21,)),
+            |value| value * 2,
+        );
+    assert_eq!(doubled, (6, (42,)));
+
+    // The mapped values may be of a different type.
+    let labels =
+        <(items::category, (items::val,)) as HomogeneouslyTypedNestedColumns<i32>>::map_same_type(
+            (3, (21,)),
+            |value| value.to_string(),
+        );
+    assert_eq!(labels, ("3".to_owned(), ("21".to_owned(),)));
+}
+
+#[test]
+fn test_homogeneous_values_into_vec() {
+    use diesel_builders::{HomogeneousNestedValues, TypedNestedTuple};
+
+    let values: <(items::id, (items::category, (items::val,))) as TypedNestedTuple>::NestedTupleValueType =
+        (1, (2, (3,)));
+    assert_eq!(values.into_vec(), vec![1, 2, 3]);
+    assert_eq!(HomogeneousNestedValues::<i32>::into_vec(()), Vec::<i32>::new());
+}
+
+#[cfg(feature = "sync-connection-wrapper")]
+#[tokio::test]
+async fn test_load_stream() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+    use diesel_builders::LoadStream;
+    use futures_util::TryStreamExt;
+
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    items::table::builder().category(1).val(10).insert(&mut conn)?;
+    items::table::builder().category(2).val(20).insert(&mut conn)?;
+    items::table::builder().category(1).val(30).insert(&mut conn)?;
+
+    let loaded: Vec<Item> = <(items::category,)>::load_many((1,), &mut conn)?;
+
+    let mut conn: SyncConnectionWrapper<SqliteConnection> = SyncConnectionWrapper::new(conn);
+    let streamed: Vec<Item> =
+        <(items::category,)>::load_stream((1,), &mut conn).await?.try_collect().await?;
+    assert_eq!(streamed, loaded);
+
+    Ok(())
+}
+
+#[test]
+fn test_set_columns_from() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    // The values follow the order of the new record, here `(category, val)`.
+    let item = items::table::builder().set_columns_from((1, 10)).insert(&mut conn)?;
+    assert_eq!(*item.category(), 1);
+    assert_eq!(*item.val(), 10);
+
+    Ok(())
+}
+
+#[test]
+fn test_projection_nth() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::typenum::{U0, U1, U2};
+
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    let item = items::table::builder().category(7).val(42).insert(&mut conn)?;
+
+    let rows = <(items::id, items::category, items::val) as LoadManyDistinct<_>>::load_distinct(
+        &mut conn,
+    )?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].nth::<U0>(), item.id());
+    assert_eq!(rows[0].nth::<U1>(), item.category());
+    assert_eq!(rows[0].nth::<U2>(), item.val());
+
+    Ok(())
+}
+
+#[test]
+fn test_dry_run_insert() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    // Unlike the builder, the database rejects negative values.
+    diesel::sql_query(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY NOT NULL, category INTEGER NOT NULL, val INTEGER NOT NULL CHECK (val >= 0))"
+    )
+    .execute(&mut conn)?;
+
+    items::table::builder().category(1).val(10).dry_run_insert(&mut conn)?;
+
+    let result = items::table::builder().category(1).val(-10).dry_run_insert(&mut conn);
+    assert!(matches!(
+        result,
+        Err(diesel_builders::BuilderError::Diesel(diesel::result::Error::DatabaseError(_, _)))
+    ));
+
+    // Neither dry run persisted a record.
+    let count: i64 = items::table.count().get_result(&mut conn)?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_column_value() {
+    use diesel_builders::{ParseColumnValue, builder_error::DynamicColumnError};
+
+    assert_eq!(items::category::parse_value("42").ok(), Some(42));
+    assert!(matches!(
+        items::category::parse_value("forty-two"),
+        Err(DynamicColumnError::Parse { table_name: "items", column_name: "category", .. })
+    ));
+}
+
+#[test]
+fn test_increment_column() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    let mut builder = items::table::builder().val(10);
+    builder.increment_column::<items::val>(5)?.increment_column::<items::val>(7)?;
+    assert_eq!(builder.may_get_column::<items::val>(), Some(22));
+
+    // An unset column starts from zero.
+    builder.increment_column::<items::category>(3)?;
+    assert_eq!(builder.may_get_column::<items::category>(), Some(3));
+
+    let item = builder.insert(&mut conn)?;
+    assert_eq!(*item.val(), 22);
+
+    Ok(())
+}
+
+#[test]
+fn test_load_random() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    create_tables(&mut conn)?;
+
+    for val in 0..5 {
+        items::table::builder().category(1).val(val).insert(&mut conn)?;
+    }
+
+    let sample: Vec<Item> = items::table::load_random(3, &mut conn)?;
+    assert_eq!(sample.len(), 3);
+    let ids: std::collections::HashSet<i32> = sample.iter().map(|item| *item.id()).collect();
+    assert_eq!(ids.len(), 3);
+
+    // A limit larger than the table returns every record.
+    let sample: Vec<Item> = items::table::load_random(10, &mut conn)?;
+    assert_eq!(sample.len(), 5);
+
+    Ok(())
+}
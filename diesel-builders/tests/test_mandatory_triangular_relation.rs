@@ -337,11 +337,58 @@ fn test_mandatory_triangular_relation_missing_builder_error() {
         err,
         IncompleteBuilderError::MissingMandatoryTriangularField {
             table_name: "child_with_satellite_table",
-            field_name: "mandatory_id"
+            field_name: "mandatory_id",
+            referenced_table_name: "satellite_table",
         }
     );
 }
 
+#[test]
+fn test_completed_bundle_mandatory_builder() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::CompletedTableBuilderBundle;
+
+    let mut bundle = TableBuilderBundle::<child_with_satellite_table::table>::default();
+    bundle.try_set_mandatory_builder_ref::<child_with_satellite_table::mandatory_id>(
+        satellite_table::table::builder().field("Value C"),
+    )?;
+    let completed = CompletedTableBuilderBundle::try_from(bundle)?;
+
+    // A completed bundle always holds its mandatory associated builders.
+    let mandatory_builder =
+        completed.mandatory_builder::<child_with_satellite_table::mandatory_id>();
+    assert_eq!(
+        mandatory_builder.may_get_column_ref::<satellite_table::field>().map(String::as_str),
+        Some("Value C")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_insert_without_mandatory_builder_error() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    setup_triangular_tables(&mut conn)?;
+
+    // The ancestor columns are set, but the mandatory `satellite_table`
+    // builder is not, so the insertion must fail before issuing any query.
+    let result = simple_child_with_satellite_table::table::builder()
+        .parent_field("Parent of an orphan")
+        .insert(&mut conn);
+
+    assert!(matches!(
+        result.err(),
+        Some(diesel_builders::BuilderError::Incomplete(
+            IncompleteBuilderError::MissingMandatoryTriangularField {
+                table_name: "simple_child_with_satellite_table",
+                field_name: "mandatory_id",
+                referenced_table_name: "satellite_table",
+            }
+        ))
+    ));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "serde")]
 fn test_builder_serde_serialization() -> Result<(), Box<dyn std::error::Error>> {
@@ -369,3 +416,77 @@ fn test_builder_serde_serialization() -> Result<(), Box<dyn std::error::Error>>
 
     Ok(())
 }
+
+#[test]
+fn test_foreign_primary_key_referenced_table_name() {
+    use diesel_builders::ForeignPrimaryKey;
+
+    assert_eq!(satellite_table::parent_id::referenced_table_name(), "parent_table");
+    assert_eq!(
+        child_with_satellite_table::mandatory_id::referenced_table_name(),
+        "satellite_table"
+    );
+    assert_eq!(
+        simple_child_with_satellite_table::mandatory_id::referenced_table_name(),
+        "satellite_table"
+    );
+}
+
+#[test]
+#[cfg(feature = "postcard")]
+fn test_bundle_postcard_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::CompletedTableBuilderBundle;
+
+    let mut bundle = TableBuilderBundle::<child_with_satellite_table::table>::default();
+    bundle
+        .try_mandatory_ref(
+            satellite_table::table::builder()
+                .field("Value C")
+                .another_field("Another remote field".to_owned()),
+        )?
+        .type_ref("Value B");
+
+    let bytes = bundle.to_bytes()?;
+    let deserialized = TableBuilderBundle::<child_with_satellite_table::table>::from_bytes(&bytes)?;
+    assert_eq!(deserialized, bundle);
+
+    let completed = CompletedTableBuilderBundle::try_from(bundle)?;
+    let deserialized_completed = CompletedTableBuilderBundle::try_from(deserialized)?;
+    assert_eq!(format!("{deserialized_completed:?}"), format!("{completed:?}"));
+
+    let builder = child_with_satellite_table::table::builder().r#type("Serialized B");
+    let deserialized_builder =
+        TableBuilder::<child_with_satellite_table::table>::from_bytes(&builder.to_bytes()?)?;
+    assert_eq!(deserialized_builder, builder);
+
+    Ok(())
+}
+
+#[test]
+fn test_set_mandatory_builder_once() -> Result<(), Box<dyn std::error::Error>> {
+    use diesel_builders::{HasMandatoryBuilder, MandatoryBuilderAlreadySet};
+    use simple_child_with_satellite_table::mandatory_id;
+
+    let mut builder = simple_child_with_satellite_table::table::builder();
+    assert!(!HasMandatoryBuilder::<mandatory_id>::has_mandatory_builder(&builder));
+
+    builder.try_set_mandatory_builder_once_ref::<mandatory_id>(
+        satellite_table::table::builder().field("First"),
+    )?;
+    assert!(HasMandatoryBuilder::<mandatory_id>::has_mandatory_builder(&builder));
+
+    // The second builder is rejected instead of replacing the first one.
+    let result = builder.try_set_mandatory_builder_once::<mandatory_id>(
+        satellite_table::table::builder().field("Second"),
+    );
+    assert_eq!(
+        result.map(|_| ()),
+        Err(MandatoryBuilderAlreadySet {
+            table_name: "simple_child_with_satellite_table",
+            field_name: "mandatory_id",
+            referenced_table_name: "satellite_table",
+        })
+    );
+
+    Ok(())
+}
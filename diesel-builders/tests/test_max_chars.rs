@@ -0,0 +1,95 @@
+//! Test for text columns limited to a number of characters rather than bytes.
+
+mod shared;
+use diesel_builders::{MaxCharsExceeded, char_len, prelude::*};
+
+/// Post model.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = posts)]
+#[table_model(error = PostError, surrogate_key)]
+pub struct Post {
+    /// Primary key.
+    id: i32,
+    /// Title of the post, at most 5 characters.
+    #[table_model(max_chars = 5)]
+    title: String,
+    /// Optional subtitle of the post, at most 3 characters.
+    #[table_model(max_chars = 3)]
+    subtitle: Option<String>,
+}
+
+/// Error variants for `NewPost` validation.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PostError {
+    /// A text column holds too many characters.
+    #[error(transparent)]
+    TooLong(#[from] MaxCharsExceeded),
+}
+
+impl From<std::convert::Infallible> for PostError {
+    fn from(inf: std::convert::Infallible) -> Self {
+        match inf {}
+    }
+}
+
+#[test]
+fn test_char_len_counts_characters() {
+    assert_eq!(char_len(""), 0);
+    assert_eq!(char_len("crème"), 5);
+    assert_eq!("crème".len(), 6);
+    assert_eq!(char_len("🦀🦀"), 2);
+    assert_eq!("🦀🦀".len(), 8);
+}
+
+#[test]
+#[cfg(feature = "unicode-segmentation")]
+fn test_grapheme_len_counts_perceived_characters() {
+    use diesel_builders::grapheme_len;
+
+    // An `e` followed by a combining acute accent.
+    assert_eq!(grapheme_len("e\u{301}"), 1);
+    assert_eq!(char_len("e\u{301}"), 2);
+    // A flag is made of two regional indicator symbols.
+    assert_eq!(grapheme_len("🇮🇹"), 1);
+    assert_eq!(char_len("🇮🇹"), 2);
+}
+
+#[test]
+fn test_max_chars_validation() -> Result<(), Box<dyn std::error::Error>> {
+    // Five multibyte characters are accepted even though they take more than
+    // five bytes.
+    let builder =
+        posts::table::builder().try_title("épées")?.try_subtitle(Some("🦀🦀🦀".to_owned()))?;
+    assert_eq!(builder.may_get_column_ref::<posts::title>().map(String::as_str), Some("épées"));
+
+    assert_eq!(
+        posts::table::builder().try_title("crèmes").map(|_| ()),
+        Err(PostError::TooLong(MaxCharsExceeded {
+            table_name: "posts",
+            column_name: "title",
+            max: 5,
+            actual: 6,
+        }))
+    );
+    assert_eq!(
+        posts::table::builder().try_subtitle(Some("🦀🦀🦀🦀".to_owned())).map(|_| ()),
+        Err(PostError::TooLong(MaxCharsExceeded {
+            table_name: "posts",
+            column_name: "subtitle",
+            max: 3,
+            actual: 4,
+        }))
+    );
+
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY NOT NULL, title TEXT NOT NULL, subtitle TEXT)",
+    )
+    .execute(&mut conn)?;
+
+    let post = builder.insert(&mut conn)?;
+    assert_eq!(post.title(), "épées");
+    assert_eq!(post.subtitle(), &Some("🦀🦀🦀".to_owned()));
+
+    Ok(())
+}
@@ -38,3 +38,49 @@ fn test_nullable_foreign_key_not_found() -> Result<(), Box<dyn std::error::Error
 
     Ok(())
 }
+
+#[test]
+fn test_foreign_cache_reuses_loaded_parent() -> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use diesel::connection::{Connection, InstrumentationEvent};
+    use diesel_builders::ForeignCache;
+
+    let mut conn = shared::establish_connection()?;
+
+    diesel::sql_query("CREATE TABLE parent_table (id INTEGER PRIMARY KEY NOT NULL)")
+        .execute(&mut conn)?;
+    diesel::sql_query("CREATE TABLE child_table (id INTEGER PRIMARY KEY NOT NULL, parent_id INTEGER REFERENCES parent_table(id))").execute(&mut conn)?;
+
+    let parent = parent_table::table::builder().id(1).insert(&mut conn)?;
+    let first = child_table::table::builder().parent_id(Some(*parent.id())).insert(&mut conn)?;
+    let second = child_table::table::builder().parent_id(Some(*parent.id())).insert(&mut conn)?;
+
+    let queries = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&queries);
+    conn.set_instrumentation(move |event: InstrumentationEvent<'_>| {
+        if matches!(event, InstrumentationEvent::StartQuery { .. }) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    let mut cache = ForeignCache::<(child_table::parent_id,), (parent_table::id,)>::new();
+    assert!(cache.is_empty());
+
+    assert_eq!(cache.get_foreign(&first, &mut conn)?.id(), parent.id());
+    assert_eq!(queries.load(Ordering::Relaxed), 1);
+
+    // The second child references the same parent, so no query is issued.
+    assert_eq!(cache.get_foreign(&second, &mut conn)?.id(), parent.id());
+    assert_eq!(queries.load(Ordering::Relaxed), 1);
+    assert_eq!(cache.len(), 1);
+
+    cache.clear();
+    assert_eq!(cache.get_foreign(&second, &mut conn)?.id(), parent.id());
+    assert_eq!(queries.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Test for columns whose values are limited to a range.
+
+mod shared;
+use diesel_builders::{OutOfRange, RangeViolation, prelude::*};
+
+/// Person model.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = people)]
+#[table_model(error = PersonError, surrogate_key)]
+pub struct Person {
+    /// Primary key.
+    id: i32,
+    /// Age of the person, between 0 and 120 included.
+    #[table_model(range(0..=120))]
+    age: i32,
+    /// Optional height of the person in meters, strictly below 3.
+    #[table_model(range(..3.0))]
+    height: Option<f64>,
+}
+
+/// Error variants for `NewPerson` validation.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PersonError {
+    /// A column holds a value outside of its range.
+    #[error(transparent)]
+    OutOfRange(#[from] OutOfRange),
+}
+
+impl From<std::convert::Infallible> for PersonError {
+    fn from(inf: std::convert::Infallible) -> Self {
+        match inf {}
+    }
+}
+
+#[test]
+fn test_inclusive_range_validation() -> Result<(), Box<dyn std::error::Error>> {
+    // Both bounds of an inclusive range are accepted.
+    assert!(people::table::builder().try_age(0).is_ok());
+    assert!(people::table::builder().try_age(120).is_ok());
+    let builder = people::table::builder().try_age(30)?;
+    assert_eq!(builder.may_get_column_ref::<people::age>(), Some(&30));
+
+    assert_eq!(
+        people::table::builder().try_age(-1).map(|_| ()),
+        Err(PersonError::OutOfRange(OutOfRange {
+            table_name: "people",
+            column_name: "age",
+            violation: RangeViolation::Below { inclusive: true },
+            bound: "0".to_owned(),
+        }))
+    );
+    assert_eq!(
+        people::table::builder().try_age(121).map(|_| ()),
+        Err(PersonError::OutOfRange(OutOfRange {
+            table_name: "people",
+            column_name: "age",
+            violation: RangeViolation::Above { inclusive: true },
+            bound: "120".to_owned(),
+        }))
+    );
+
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE people (id INTEGER PRIMARY KEY NOT NULL, age INTEGER NOT NULL, height DOUBLE)",
+    )
+    .execute(&mut conn)?;
+
+    let person = builder.insert(&mut conn)?;
+    assert_eq!(person.age(), &30);
+    assert_eq!(person.height(), &None);
+
+    Ok(())
+}
+
+#[test]
+fn test_open_ended_exclusive_range_validation() {
+    assert!(people::table::builder().try_height(Some(-1.0)).is_ok());
+    assert!(people::table::builder().try_height(Some(2.99)).is_ok());
+    // Missing values are not checked against the range.
+    assert!(people::table::builder().try_height(None).is_ok());
+
+    let error = people::table::builder().try_height(Some(3.0)).map(|_| ());
+    assert!(matches!(
+        &error,
+        Err(PersonError::OutOfRange(OutOfRange {
+            violation: RangeViolation::Above { inclusive: false },
+            ..
+        }))
+    ));
+    // Values which cannot be compared to the bound are rejected.
+    assert!(people::table::builder().try_height(Some(f64::NAN)).is_err());
+}
@@ -0,0 +1,135 @@
+//! Test for columns holding timestamps managed by the table.
+#![cfg(feature = "chrono")]
+
+mod shared;
+use diesel_builders::prelude::*;
+
+/// Event model.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = events)]
+#[table_model(surrogate_key)]
+pub struct Event {
+    /// Primary key.
+    id: i32,
+    /// Name of the event.
+    name: String,
+    /// Time at which the event was created.
+    #[diesel(sql_type = diesel::sql_types::Timestamp)]
+    #[table_model(timestamp(created))]
+    created_at: chrono::NaiveDateTime,
+    /// Time at which the event was last updated.
+    #[diesel(sql_type = diesel::sql_types::Timestamp)]
+    #[table_model(timestamp(updated))]
+    updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[test]
+fn test_created_at_is_set_on_insert() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(<events::created_at as HasColumnMeta>::META.has_default);
+
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, created_at TIMESTAMP NOT NULL, updated_at TIMESTAMP)",
+    )
+    .execute(&mut conn)?;
+
+    let before = chrono::Utc::now().naive_utc();
+    let event = events::table::builder().name("Launch").insert(&mut conn)?;
+    let after = chrono::Utc::now().naive_utc();
+
+    assert_eq!(event.name(), "Launch");
+    assert!(before <= *event.created_at() && *event.created_at() <= after);
+    let updated_at = (*event.updated_at()).ok_or("updated_at should be set on insert")?;
+    assert!(before <= updated_at && updated_at <= after);
+
+    // The stored values are the ones returned by the insertion.
+    let loaded: Event = events::table.find(event.id).first(&mut conn)?;
+    assert_eq!(loaded, event);
+
+    Ok(())
+}
+
+#[test]
+fn test_upsert_excluding_preserves_created_at() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, created_at TIMESTAMP NOT NULL, updated_at TIMESTAMP)",
+    )
+    .execute(&mut conn)?;
+
+    let event = events::table::builder().name("Launch").insert(&mut conn)?;
+    let later = *event.created_at() + chrono::Duration::hours(1);
+    let changed = Event {
+        id: event.id,
+        name: "Relaunch".to_owned(),
+        created_at: later,
+        updated_at: Some(later),
+    };
+
+    let before = chrono::Utc::now().naive_utc();
+    let upserted = changed.upsert_excluding::<(events::created_at,)>(&mut conn)?;
+    let after = chrono::Utc::now().naive_utc();
+    assert_eq!(upserted.name(), "Relaunch");
+    assert_eq!(upserted.created_at(), event.created_at());
+    // The updated timestamp is refreshed rather than taken from the model.
+    let updated_at = (*upserted.updated_at()).ok_or("updated_at should be refreshed")?;
+    assert!(before <= updated_at && updated_at <= after);
+
+    let loaded: Event = events::table.find(event.id).first(&mut conn)?;
+    assert_eq!(loaded, upserted);
+
+    // Without a conflicting record, every column is inserted as provided.
+    let fresh = Event { id: event.id + 1, ..changed };
+    let inserted = fresh.upsert_excluding::<(events::created_at,)>(&mut conn)?;
+    assert_eq!(inserted, fresh);
+
+    Ok(())
+}
+
+#[test]
+fn test_updated_at_is_refreshed_on_update() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, created_at TIMESTAMP NOT NULL, updated_at TIMESTAMP)",
+    )
+    .execute(&mut conn)?;
+
+    let event = events::table::builder().name("Launch").insert(&mut conn)?;
+    let long_ago = chrono::NaiveDate::from_ymd_opt(2000, 1, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or("invalid date")?;
+    let stale =
+        || diesel::update(events::table.find(event.id)).set(events::updated_at.eq(Some(long_ago)));
+
+    // Through a changeset.
+    stale().execute(&mut conn)?;
+    let before = chrono::Utc::now().naive_utc();
+    let updated = event.update(events::name.eq("Relaunch"), &mut conn)?;
+    let after = chrono::Utc::now().naive_utc();
+    assert_eq!(updated.name(), "Relaunch");
+    assert_eq!(updated.created_at(), event.created_at());
+    let updated_at = (*updated.updated_at()).ok_or("updated_at should be refreshed")?;
+    assert!(before <= updated_at && updated_at <= after);
+
+    // Through an upsert of a model holding a stale timestamp.
+    let stale_model: Event = stale().get_result(&mut conn)?;
+    assert_eq!(stale_model.updated_at(), &Some(long_ago));
+    let before = chrono::Utc::now().naive_utc();
+    let upserted = stale_model.upsert(&mut conn)?;
+    let after = chrono::Utc::now().naive_utc();
+    let updated_at = (*upserted.updated_at()).ok_or("updated_at should be refreshed")?;
+    assert!(before <= updated_at && updated_at <= after);
+
+    // Through a batch upsert.
+    let stale_model: Event = stale().get_result(&mut conn)?;
+    let before = chrono::Utc::now().naive_utc();
+    let upserted = Event::upsert_batch(&[stale_model], &mut conn)?;
+    let after = chrono::Utc::now().naive_utc();
+    let updated_at = upserted[0].updated_at().ok_or("updated_at should be refreshed")?;
+    assert!(before <= updated_at && updated_at <= after);
+
+    let loaded: Event = events::table.find(event.id).first(&mut conn)?;
+    assert_eq!(loaded, upserted[0]);
+
+    Ok(())
+}
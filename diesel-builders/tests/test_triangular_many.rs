@@ -161,3 +161,27 @@ fn test_triangular_many() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_bundle_mandatory_builders_set() {
+    use diesel_builders::TableBuilderBundle;
+
+    // `d1` is set from a model id, so only `d2` holds a builder.
+    let mut bundle = TableBuilderBundle::<child_table::table>::default()
+        .set_discretionary_builder::<child_table::d2_id>(
+            satellite_table::table::builder().field("D2 for Child"),
+        );
+    bundle.set_column_ref::<child_table::d1_id>(1);
+
+    assert_eq!(
+        bundle.mandatory_builders_set(),
+        vec![
+            ("m1_id", false),
+            ("m2_id", false),
+            ("m3_id", false),
+            ("d1_id", false),
+            ("d2_id", true),
+            ("d3_id", false),
+        ]
+    );
+}
@@ -0,0 +1,75 @@
+//! Test for setting a column to the value produced by a fallible closure.
+
+mod shared;
+use diesel_builders::prelude::*;
+
+/// Sensor model.
+#[derive(Debug, Clone, PartialEq, Queryable, Selectable, Identifiable, TableModel)]
+#[diesel(table_name = sensors)]
+#[table_model(error = SensorError, surrogate_key)]
+pub struct Sensor {
+    /// Primary key.
+    id: i32,
+    /// Reading of the sensor, which must not be negative.
+    reading: i32,
+}
+
+/// Error variants for `NewSensor` validation.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SensorError {
+    /// The reading is negative.
+    #[error("The reading must not be negative")]
+    NegativeReading,
+    /// The reading could not be parsed.
+    #[error(transparent)]
+    Parse(#[from] std::num::ParseIntError),
+}
+
+impl From<std::convert::Infallible> for SensorError {
+    fn from(inf: std::convert::Infallible) -> Self {
+        match inf {}
+    }
+}
+
+impl ValidateColumn<sensors::reading> for <sensors::table as TableExt>::NewValues {
+    type Error = SensorError;
+
+    fn validate_column(value: &i32) -> Result<(), Self::Error> {
+        if *value < 0 {
+            return Err(SensorError::NegativeReading);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_try_set_with() -> Result<(), Box<dyn std::error::Error>> {
+    let builder =
+        sensors::table::builder().try_set_with::<sensors::reading, _>(|| "42".parse::<i32>())?;
+    assert_eq!(builder.may_get_column::<sensors::reading>(), Some(42));
+
+    let mut conn = shared::establish_connection()?;
+    diesel::sql_query(
+        "CREATE TABLE sensors (id INTEGER PRIMARY KEY NOT NULL, reading INTEGER NOT NULL)",
+    )
+    .execute(&mut conn)?;
+    let sensor = builder.insert(&mut conn)?;
+    assert_eq!(sensor.reading(), &42);
+
+    Ok(())
+}
+
+#[test]
+fn test_try_set_with_propagates_errors() {
+    // The error of the closure is converted into the error of the column.
+    assert!(matches!(
+        sensors::table::builder().try_set_with::<sensors::reading, _>(|| "forty".parse::<i32>()),
+        Err(SensorError::Parse(_))
+    ));
+
+    // The produced value is still validated.
+    assert!(matches!(
+        sensors::table::builder().try_set_with::<sensors::reading, _>(|| "-1".parse::<i32>()),
+        Err(SensorError::NegativeReading)
+    ));
+}
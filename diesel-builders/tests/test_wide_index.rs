@@ -0,0 +1,65 @@
+//! Test for composite indices spanning many columns, which are bounded only
+//! by the tuple size feature in use.
+
+use diesel_builders::{TableIndex, UniqueTableIndex, prelude::*};
+
+#[derive(Debug, Queryable, Selectable, Identifiable, PartialEq, TableModel)]
+#[diesel(table_name = wide_table)]
+#[table_model(surrogate_key)]
+/// Model for a table with a ten-column unique index.
+pub struct Wide {
+    /// Primary key.
+    id: i32,
+    /// First indexed column.
+    c1: i32,
+    /// Second indexed column.
+    c2: i32,
+    /// Third indexed column.
+    c3: i32,
+    /// Fourth indexed column.
+    c4: i32,
+    /// Fifth indexed column.
+    c5: i32,
+    /// Sixth indexed column.
+    c6: i32,
+    /// Seventh indexed column.
+    c7: i32,
+    /// Eighth indexed column.
+    c8: i32,
+    /// Ninth indexed column.
+    c9: i32,
+    /// Tenth indexed column.
+    c10: i32,
+}
+
+unique_index!(
+    wide_table::c1,
+    wide_table::c2,
+    wide_table::c3,
+    wide_table::c4,
+    wide_table::c5,
+    wide_table::c6,
+    wide_table::c7,
+    wide_table::c8,
+    wide_table::c9,
+    wide_table::c10
+);
+
+/// Asserts at compile time that the columns form a unique index.
+fn assert_unique_index<I: UniqueTableIndex + TableIndex>() {}
+
+#[test]
+fn test_ten_column_unique_index() {
+    assert_unique_index::<(
+        wide_table::c1,
+        wide_table::c2,
+        wide_table::c3,
+        wide_table::c4,
+        wide_table::c5,
+        wide_table::c6,
+        wide_table::c7,
+        wide_table::c8,
+        wide_table::c9,
+        wide_table::c10,
+    )>();
+}
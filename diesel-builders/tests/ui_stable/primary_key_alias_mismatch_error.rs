@@ -0,0 +1,12 @@
+use diesel_builders::prelude::*;
+
+#[derive(TableModel)]
+#[diesel(table_name = user_roles)]
+#[diesel(primary_key(user_id, role_id))]
+#[table_model(primary_key(role_id, user_id))]
+pub struct UserRole {
+    user_id: i32,
+    role_id: i32,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use diesel_builders::prelude::*;
+
+#[derive(Debug, Queryable, Clone, Selectable, Identifiable, PartialEq, PartialOrd, TableModel)]
+#[diesel(table_name = animals)]
+pub struct Animal {
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Queryable, Clone, Selectable, Identifiable, PartialEq, PartialOrd, TableModel)]
+#[diesel(table_name = dogs)]
+#[table_model(ancestors(animals))]
+pub struct Dog {
+    id: i32,
+    breed: String,
+}
+
+#[derive(Debug, Queryable, Clone, Selectable, Identifiable, PartialEq, PartialOrd, TableModel)]
+#[diesel(table_name = puppies)]
+#[table_model(ancestors(dogs, animals))]
+pub struct Puppy {
+    id: i32,
+    age_months: i32,
+}
+
+fn main() {}
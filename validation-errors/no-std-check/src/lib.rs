@@ -0,0 +1,45 @@
+#![no_std]
+//! Compile check ensuring `validation-errors` builds under `no_std` with
+//! `alloc`.
+
+extern crate alloc;
+
+use alloc::{boxed::Box, vec};
+
+use validation_errors::{ValidationError, ValidationErrorKind};
+
+/// Error used as the source of a generic validation error.
+#[derive(Debug)]
+pub struct OutOfStock;
+
+impl core::fmt::Display for OutOfStock {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("out of stock")
+    }
+}
+
+impl core::error::Error for OutOfStock {}
+
+/// Builds a validation error for an empty field.
+#[must_use]
+pub fn empty_name() -> ValidationError {
+    ValidationError::empty("users", "name")
+}
+
+/// Builds a generic validation error wrapping a custom error.
+#[must_use]
+pub fn out_of_stock() -> ValidationError {
+    ValidationError::generic("products", vec!["stock"], Box::new(OutOfStock))
+}
+
+/// Returns the static message of the error kind.
+#[must_use]
+pub fn message(error: &ValidationError) -> &str {
+    error.kind().as_ref()
+}
+
+/// Returns whether the error reports an empty field.
+#[must_use]
+pub fn is_empty_error(error: &ValidationError) -> bool {
+    matches!(error.kind(), ValidationErrorKind::MustNotBeEmpty(_))
+}
@@ -1,7 +1,16 @@
 //! Crate providing common validation errors.
+//!
+//! Without the default `std` feature the crate is `no_std` and only requires
+//! `alloc`; the conversions into `diesel` and `diesel-builders` errors are
+//! then unavailable.
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::{boxed::Box, vec::Vec};
 use core::convert::Infallible;
 
+#[cfg(feature = "std")]
 use diesel::result::DatabaseErrorInformation;
 
 #[derive(Debug, thiserror::Error)]
@@ -40,6 +49,9 @@ pub enum ValidationErrorKind {
     /// The scalar is not greater than the expected amount.
     #[error("Field `{0}` must be greater than or equal to {1}")]
     MustBeGreaterThanScalar(&'static str, f64),
+    /// The provided value is not one of the allowed values.
+    #[error("Field `{0}` must be one of: {}", .1.join(", "))]
+    MustBeInSet(&'static str, &'static [&'static str]),
     /// Some third-party validation error.
     #[error("Fields {fields:?}: {error}")]
     Generic {
@@ -82,6 +94,7 @@ impl AsRef<str> for ValidationErrorKind {
             ValidationErrorKind::MustBeGreaterThanScalar(_, _) => {
                 "Field must be greater than or equal to value"
             }
+            ValidationErrorKind::MustBeInSet(_, _) => "Field must be one of the allowed values",
             ValidationErrorKind::Generic { .. } => "Generic validation error",
         }
     }
@@ -93,12 +106,45 @@ impl From<Infallible> for ValidationError {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<ValidationError> for diesel_builders::BuilderError<ValidationError> {
     fn from(error: ValidationError) -> Self {
         diesel_builders::BuilderError::Validation(error)
     }
 }
 
+#[cfg(feature = "std")]
+impl From<diesel_builders::MaxCharsExceeded> for ValidationError {
+    fn from(error: diesel_builders::MaxCharsExceeded) -> Self {
+        ValidationError::exceeds_max_length(error.table_name, error.column_name, error.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<diesel_builders::OutOfRange> for ValidationError {
+    fn from(error: diesel_builders::OutOfRange) -> Self {
+        use diesel_builders::RangeViolation;
+        let (table, field) = (error.table_name, error.column_name);
+        match (error.violation, error.bound.parse::<f64>()) {
+            (RangeViolation::Below { inclusive: true }, Ok(bound)) => {
+                ValidationError::greater_than_value(table, field, bound)
+            }
+            (RangeViolation::Below { inclusive: false }, Ok(bound)) => {
+                ValidationError::strictly_greater_than_value(table, field, bound)
+            }
+            (RangeViolation::Above { inclusive: true }, Ok(bound)) => {
+                ValidationError::smaller_than_value(table, field, bound)
+            }
+            (RangeViolation::Above { inclusive: false }, Ok(bound)) => {
+                ValidationError::strictly_smaller_than_value(table, field, bound)
+            }
+            // Non-numeric bounds, such as dates, are kept as the source error.
+            (_, Err(_)) => ValidationError::generic(table, vec![field], Box::new(error)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 impl From<ValidationError> for diesel::result::Error {
     fn from(error: ValidationError) -> Self {
         diesel::result::Error::DatabaseError(
@@ -108,6 +154,7 @@ impl From<ValidationError> for diesel::result::Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl DatabaseErrorInformation for ValidationError {
     fn message(&self) -> &str {
         // Use the AsRef<str> implementation of the kind
@@ -133,7 +180,8 @@ impl DatabaseErrorInformation for ValidationError {
             | ValidationErrorKind::MustBeSmallerThanScalar(field, _)
             | ValidationErrorKind::MustBeStrictlyGreaterThanScalar(field, _)
             | ValidationErrorKind::MustNotExceedMaxLength(field, _)
-            | ValidationErrorKind::MustBeGreaterThanScalar(field, _) => Some(*field),
+            | ValidationErrorKind::MustBeGreaterThanScalar(field, _)
+            | ValidationErrorKind::MustBeInSet(field, _) => Some(*field),
             ValidationErrorKind::MustBeDistinct(field1, _)
             | ValidationErrorKind::MustBeStrictlySmallerThan(field1, _)
             | ValidationErrorKind::MustBeSmallerThan(field1, _)
@@ -144,7 +192,7 @@ impl DatabaseErrorInformation for ValidationError {
     }
 
     fn constraint_name(&self) -> Option<&str> {
-        None
+        self.constraint
     }
 
     fn statement_position(&self) -> Option<i32> {
@@ -161,6 +209,8 @@ pub struct ValidationError {
     /// The kind of validation error.
     #[source]
     kind: ValidationErrorKind,
+    /// The name of the database constraint the error corresponds to, if any.
+    constraint: Option<&'static str>,
 }
 
 impl ValidationError {
@@ -194,6 +244,46 @@ impl ValidationError {
         self.table
     }
 
+    /// Returns the name of the database constraint the error corresponds to,
+    /// if one was attached with
+    /// [`with_constraint_name`](Self::with_constraint_name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validation_errors::ValidationError;
+    ///
+    /// let error = ValidationError::empty("users", "username");
+    /// assert_eq!(error.constraint(), None);
+    /// ```
+    #[must_use]
+    pub fn constraint(&self) -> Option<&'static str> {
+        self.constraint
+    }
+
+    /// Attaches the name of the database constraint the error corresponds
+    /// to, such as the `CHECK` constraint enforcing the same rule, which is
+    /// then reported by `DatabaseErrorInformation::constraint_name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `constraint` - The name of the constraint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validation_errors::ValidationError;
+    ///
+    /// let error = ValidationError::empty("users", "username")
+    ///     .with_constraint_name("users_username_not_empty");
+    /// assert_eq!(error.constraint(), Some("users_username_not_empty"));
+    /// ```
+    #[must_use]
+    pub fn with_constraint_name(mut self, constraint: &'static str) -> Self {
+        self.constraint = Some(constraint);
+        self
+    }
+
     /// Creates a new validation error for an empty field.
     ///
     /// # Arguments
@@ -215,7 +305,11 @@ impl ValidationError {
     /// ```
     #[must_use]
     pub fn empty(table: &'static str, field: &'static str) -> Self {
-        ValidationError { table, kind: ValidationErrorKind::MustNotBeEmpty(field) }
+        ValidationError {
+            table,
+            kind: ValidationErrorKind::MustNotBeEmpty(field),
+            constraint: None,
+        }
     }
 
     /// Creates a new validation error for a field that exceeds maximum length.
@@ -239,6 +333,7 @@ impl ValidationError {
         ValidationError {
             table,
             kind: ValidationErrorKind::MustNotExceedMaxLength(field, max_length),
+            constraint: None,
         }
     }
 
@@ -266,6 +361,7 @@ impl ValidationError {
         ValidationError {
             table,
             kind: ValidationErrorKind::MustBeDistinct(left_field, right_field),
+            constraint: None,
         }
     }
 
@@ -297,6 +393,7 @@ impl ValidationError {
         ValidationError {
             table,
             kind: ValidationErrorKind::MustBeSmallerThan(smaller_field, greater_field),
+            constraint: None,
         }
     }
 
@@ -322,7 +419,11 @@ impl ValidationError {
     /// ```
     #[must_use]
     pub fn smaller_than_value(table: &'static str, field: &'static str, value: f64) -> Self {
-        ValidationError { table, kind: ValidationErrorKind::MustBeSmallerThanScalar(field, value) }
+        ValidationError {
+            table,
+            kind: ValidationErrorKind::MustBeSmallerThanScalar(field, value),
+            constraint: None,
+        }
     }
 
     /// Creates a new validation error for a field who should be greater than a
@@ -354,6 +455,7 @@ impl ValidationError {
         ValidationError {
             table,
             kind: ValidationErrorKind::MustBeGreaterThan(greater_field, smaller_field),
+            constraint: None,
         }
     }
 
@@ -376,7 +478,11 @@ impl ValidationError {
     /// ```
     #[must_use]
     pub fn greater_than_value(table: &'static str, field: &'static str, value: f64) -> Self {
-        ValidationError { table, kind: ValidationErrorKind::MustBeGreaterThanScalar(field, value) }
+        ValidationError {
+            table,
+            kind: ValidationErrorKind::MustBeGreaterThanScalar(field, value),
+            constraint: None,
+        }
     }
 
     /// Creates a new validation error for a field who should be strictly
@@ -412,6 +518,7 @@ impl ValidationError {
                 smaller_equal_field,
                 greater_field,
             ),
+            constraint: None,
         }
     }
 
@@ -444,6 +551,7 @@ impl ValidationError {
         ValidationError {
             table,
             kind: ValidationErrorKind::MustBeStrictlySmallerThanScalar(field, value),
+            constraint: None,
         }
     }
 
@@ -480,6 +588,7 @@ impl ValidationError {
                 greater_equal_field,
                 smaller_field,
             ),
+            constraint: None,
         }
     }
 
@@ -510,6 +619,40 @@ impl ValidationError {
         ValidationError {
             table,
             kind: ValidationErrorKind::MustBeStrictlyGreaterThanScalar(field, value),
+            constraint: None,
+        }
+    }
+
+    /// Creates a new validation error for a field whose value is not one of
+    /// the allowed values.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table where the error occurred.
+    /// * `field` - The name of the field with the disallowed value.
+    /// * `allowed` - The values the field is allowed to take.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validation_errors::ValidationError;
+    ///
+    /// let error = ValidationError::must_be_in_set("users", "role", &["admin", "editor", "viewer"]);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Table `users`: Field `role` must be one of: admin, editor, viewer"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn must_be_in_set(
+        table: &'static str,
+        field: &'static str,
+        allowed: &'static [&'static str],
+    ) -> Self {
+        ValidationError {
+            table,
+            kind: ValidationErrorKind::MustBeInSet(field, allowed),
+            constraint: None,
         }
     }
 
@@ -541,11 +684,15 @@ impl ValidationError {
         fields: Vec<&'static str>,
         error: Box<dyn core::error::Error + Send + Sync>,
     ) -> Self {
-        ValidationError { table, kind: ValidationErrorKind::Generic { fields, error } }
+        ValidationError {
+            table,
+            kind: ValidationErrorKind::Generic { fields, error },
+            constraint: None,
+        }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use core::error::Error;
 
@@ -620,6 +767,18 @@ mod tests {
         write!(s, "{err}").unwrap();
         assert_eq!(s, "Field `field` must be greater than or equal to 5");
 
+        // Test MustBeInSet
+        let err = ValidationErrorKind::MustBeInSet("field", &["a", "b", "c"]);
+        s.clear();
+        write!(s, "{err}").unwrap();
+        assert_eq!(s, "Field `field` must be one of: a, b, c");
+
+        // Test MustBeInSet with an empty allowed set
+        let err = ValidationErrorKind::MustBeInSet("field", &[]);
+        s.clear();
+        write!(s, "{err}").unwrap();
+        assert_eq!(s, "Field `field` must be one of: ");
+
         // Test Generic
         let dummy = DummyError;
         let err = ValidationErrorKind::Generic {
@@ -662,7 +821,7 @@ mod tests {
         // Generic kind should chain source
         let dummy = DummyError;
         let kind = ValidationErrorKind::Generic { fields: vec!["field"], error: Box::new(dummy) };
-        let err = ValidationError { table: "table", kind };
+        let err = ValidationError { table: "table", kind, constraint: None };
         assert!(err.source().is_some());
     }
 
@@ -730,6 +889,16 @@ mod tests {
             matches!(err.kind(), ValidationErrorKind::MustBeStrictlyGreaterThanScalar("field", v) if (*v - 10.0).abs() < f64::EPSILON)
         );
 
+        // Test must_be_in_set
+        let err = ValidationError::must_be_in_set("table", "field", &["a", "b"]);
+        assert!(matches!(err.kind(), ValidationErrorKind::MustBeInSet("field", ["a", "b"])));
+
+        // Test must_be_in_set with an empty allowed set
+        let err = ValidationError::must_be_in_set("table", "field", &[]);
+        assert!(
+            matches!(err.kind(), ValidationErrorKind::MustBeInSet("field", allowed) if allowed.is_empty())
+        );
+
         // Test generic
         let dummy = DummyError;
         let err = ValidationError::generic("table", vec!["field1", "field2"], Box::new(dummy));
@@ -758,6 +927,10 @@ mod tests {
         let err = ValidationError::smaller_than_value("table", "field", 10.0);
         assert_eq!(err.column_name(), Some("field"));
 
+        // Test set membership
+        let err = ValidationError::must_be_in_set("table", "field", &["a", "b"]);
+        assert_eq!(err.column_name(), Some("field"));
+
         // Test two-field comparison
         let err = ValidationError::smaller_than("table", "small", "big");
         assert_eq!(err.column_name(), Some("small"));
@@ -766,6 +939,16 @@ mod tests {
         let dummy = DummyError;
         let err = ValidationError::generic("table", vec!["field1", "field2"], Box::new(dummy));
         assert_eq!(err.column_name(), Some("field1"));
+
+        // Test constraint name
+        let err = ValidationError::empty("mytable", "myfield")
+            .with_constraint_name("mytable_myfield_not_empty");
+        assert_eq!(err.constraint_name(), Some("mytable_myfield_not_empty"));
+        let diesel_err: diesel::result::Error = err.into();
+        assert!(matches!(diesel_err, diesel::result::Error::DatabaseError(_, _)));
+        if let diesel::result::Error::DatabaseError(_, info) = diesel_err {
+            assert_eq!(info.constraint_name(), Some("mytable_myfield_not_empty"));
+        }
     }
 
     #[test]
@@ -807,11 +990,47 @@ mod tests {
         let err = ValidationErrorKind::MustBeGreaterThanScalar("field", 1.0);
         assert_eq!(err.as_ref(), "Field must be greater than or equal to value");
 
+        let err = ValidationErrorKind::MustBeInSet("field", &["a"]);
+        assert_eq!(err.as_ref(), "Field must be one of the allowed values");
+
         let dummy = DummyError;
         let err = ValidationErrorKind::Generic { fields: vec!["field"], error: Box::new(dummy) };
         assert_eq!(err.as_ref(), "Generic validation error");
     }
 
+    #[test]
+    fn test_from_out_of_range() {
+        use diesel_builders::{OutOfRange, RangeViolation};
+
+        let out_of_range = |violation, bound: &str| {
+            OutOfRange {
+                table_name: "people",
+                column_name: "age",
+                violation,
+                bound: bound.to_owned(),
+            }
+        };
+
+        let err: ValidationError =
+            out_of_range(RangeViolation::Below { inclusive: true }, "0").into();
+        assert!(
+            matches!(err.kind(), ValidationErrorKind::MustBeGreaterThanScalar("age", v) if v.abs() < f64::EPSILON)
+        );
+
+        let err: ValidationError =
+            out_of_range(RangeViolation::Above { inclusive: false }, "120").into();
+        assert!(
+            matches!(err.kind(), ValidationErrorKind::MustBeStrictlySmallerThanScalar("age", v) if (*v - 120.0).abs() < f64::EPSILON)
+        );
+
+        let err: ValidationError =
+            out_of_range(RangeViolation::Above { inclusive: true }, "2000-01-01").into();
+        assert!(
+            matches!(err.kind(), ValidationErrorKind::Generic { fields, .. } if fields == &["age"])
+        );
+        assert_eq!(err.table(), "people");
+    }
+
     #[test]
     fn test_from_diesel_error() {
         let validation_err = ValidationError::empty("table", "field");